@@ -4,21 +4,21 @@ use iocraft::prelude::*;
 struct FormFieldProps {
     label: String,
     value: Option<State<String>>,
-    has_focus: bool,
     multiline: bool,
 }
 
 #[component]
-fn FormField(props: &FormFieldProps) -> impl Into<AnyElement<'static>> {
+fn FormField(mut hooks: Hooks, props: &FormFieldProps) -> impl Into<AnyElement<'static>> {
     let Some(mut value) = props.value else {
         panic!("value is required");
     };
+    let has_focus = hooks.use_focus().is_focused();
 
     element! {
         View(
-            border_style: if props.has_focus { BorderStyle::Round } else { BorderStyle::None },
+            border_style: if has_focus { BorderStyle::Round } else { BorderStyle::None },
             border_color: Color::Blue,
-            padding: if props.has_focus { 0 } else { 1 },
+            padding: if has_focus { 0 } else { 1 },
         ) {
             View(width: 15) {
                 Text(content: format!("{}: ", props.label))
@@ -29,7 +29,7 @@ fn FormField(props: &FormFieldProps) -> impl Into<AnyElement<'static>> {
                 height: if props.multiline { 5 } else { 1 },
             ) {
                 TextInput(
-                    has_focus: props.has_focus,
+                    has_focus,
                     value: value.to_string(),
                     on_change: move |new_value| value.set(new_value),
                     multiline: props.multiline,
@@ -39,6 +39,28 @@ fn FormField(props: &FormFieldProps) -> impl Into<AnyElement<'static>> {
     }
 }
 
+#[derive(Default, Props)]
+struct SubmitButtonProps {
+    on_submit: HandlerMut<'static, ()>,
+}
+
+#[component]
+fn SubmitButton(mut hooks: Hooks, props: &mut SubmitButtonProps) -> impl Into<AnyElement<'static>> {
+    let has_focus = hooks.use_focus().is_focused();
+
+    element! {
+        Button(handler: props.on_submit.take(), has_focus) {
+            View(
+                border_style: if has_focus { BorderStyle::Round } else { BorderStyle::None },
+                border_color: Color::Green,
+                padding: if has_focus { 0 } else { 1 },
+            ) {
+                Text(content: "Submit", color: Color::White, weight: Weight::Bold)
+            }
+        }
+    }
+}
+
 #[derive(Default, Props)]
 struct FormProps<'a> {
     first_name_out: Option<&'a mut String>,
@@ -52,25 +74,8 @@ fn Form<'a>(props: &mut FormProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement
     let first_name = hooks.use_state(|| "".to_string());
     let last_name = hooks.use_state(|| "".to_string());
     let life_story = hooks.use_state(|| "".to_string());
-    let mut focus = hooks.use_state(|| 0);
     let mut should_submit = hooks.use_state(|| false);
 
-    hooks.use_terminal_events(move |event| match event {
-        TerminalEvent::Key(KeyEvent { code, kind, .. }) if kind != KeyEventKind::Release => {
-            match code {
-                KeyCode::Enter | KeyCode::Char(' ') => {
-                    if focus == 3 {
-                        should_submit.set(true)
-                    }
-                }
-                KeyCode::BackTab => focus.set((focus + 3) % 4),
-                KeyCode::Tab => focus.set((focus + 1) % 4),
-                _ => {}
-            }
-        }
-        _ => {}
-    });
-
     if should_submit.get() {
         if let Some(first_name_out) = props.first_name_out.as_mut() {
             **first_name_out = first_name.to_string();
@@ -95,16 +100,10 @@ fn Form<'a>(props: &mut FormProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement
                     Text(content: "What's your name?", color: Color::White, weight: Weight::Bold)
                     Text(content: "Press tab to cycle through fields.", color: Color::Grey, align: TextAlign::Center)
                 }
-                FormField(label: "First Name", value: first_name, has_focus: focus == 0)
-                FormField(label: "Last Name", value: last_name, has_focus: focus == 1)
-                FormField(label: "Life Story", value: life_story, has_focus: focus == 2, multiline: true)
-                View(
-                    border_style: if focus == 3 { BorderStyle::Round } else { BorderStyle::None },
-                    border_color: Color::Green,
-                    padding: if focus == 3 { 0 } else { 1 },
-                ) {
-                    Text(content: "Submit", color: Color::White, weight: Weight::Bold)
-                }
+                FormField(label: "First Name", value: first_name)
+                FormField(label: "Last Name", value: last_name)
+                FormField(label: "Life Story", value: life_story, multiline: true)
+                SubmitButton(on_submit: move |_| should_submit.set(true))
             }
         }
     }
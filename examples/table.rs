@@ -24,44 +24,32 @@ struct UsersTableProps<'a> {
 
 #[component]
 fn UsersTable<'a>(props: &UsersTableProps<'a>) -> impl Into<AnyElement<'a>> {
-    element! {
-        View(
-            margin_top: 1,
-            margin_bottom: 1,
-            flex_direction: FlexDirection::Column,
-            width: 60,
-            border_style: BorderStyle::Round,
-            border_color: Color::Cyan,
-        ) {
-            View(border_style: BorderStyle::Single, border_edges: Edges::Bottom, border_color: Color::Grey) {
-                View(width: 10pct, justify_content: JustifyContent::End, padding_right: 2) {
-                    Text(content: "Id", weight: Weight::Bold, decoration: TextDecoration::Underline)
-                }
-
-                View(width: 40pct) {
-                    Text(content: "Name", weight: Weight::Bold, decoration: TextDecoration::Underline)
-                }
-
-                View(width: 50pct) {
-                    Text(content: "Email", weight: Weight::Bold, decoration: TextDecoration::Underline)
-                }
-            }
+    let rows: Vec<Vec<String>> = props
+        .users
+        .map(|users| {
+            users
+                .iter()
+                .map(|user| vec![user.id.to_string(), user.name.clone(), user.email.clone()])
+                .collect()
+        })
+        .unwrap_or_default();
 
-            #(props.users.map(|users| users.iter().enumerate().map(|(i, user)| element! {
-                View(background_color: if i % 2 == 0 { None } else { Some(Color::DarkGrey) }) {
-                    View(width: 10pct, justify_content: JustifyContent::End, padding_right: 2) {
-                        Text(content: user.id.to_string())
-                    }
-
-                    View(width: 40pct) {
-                        Text(content: user.name.clone())
-                    }
-
-                    View(width: 50pct) {
-                        Text(content: user.email.clone())
-                    }
-                }
-            })).into_iter().flatten())
+    element! {
+        View(margin_top: 1, margin_bottom: 1, width: 60) {
+            Table(
+                columns: vec![
+                    TableColumn::new("Id").width(TableColumnWidth::Fixed(4)).align(TextAlign::Right),
+                    TableColumn::new("Name").width(TableColumnWidth::Fixed(21)),
+                    TableColumn::new("Email").width(TableColumnWidth::Flex(1.0)),
+                ],
+                rows,
+                column_gap: 2,
+                border_style: BorderStyle::Round,
+                border_color: Color::Cyan,
+                header_decoration: TextDecoration::Underline,
+                separator_color: Color::Grey,
+                stripe_color: Color::DarkGrey,
+            )
         }
     }
 }
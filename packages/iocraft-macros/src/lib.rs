@@ -854,6 +854,66 @@ pub fn with_layout_style_props(_attr: TokenStream, item: TokenStream) -> TokenSt
             /// See [the MDN documentation for justify-content](https://developer.mozilla.org/en-US/docs/Web/CSS/justify-content).
             pub justify_content: Option<::iocraft::JustifyContent>
         },
+        quote! {
+            /// Overrides the parent's `align_items` value for this element.
+            ///
+            /// See [the MDN documentation for align-self](https://developer.mozilla.org/en-US/docs/Web/CSS/align-self).
+            pub align_self: Option<::iocraft::AlignSelf>
+        },
+        quote! {
+            /// Controls the alignment of grid items within their grid areas along the inline (row) axis.
+            ///
+            /// See [the MDN documentation for justify-items](https://developer.mozilla.org/en-US/docs/Web/CSS/justify-items).
+            pub justify_items: Option<::iocraft::JustifyItems>
+        },
+        quote! {
+            /// Overrides the parent's `justify_items` value for this element.
+            ///
+            /// See [the MDN documentation for justify-self](https://developer.mozilla.org/en-US/docs/Web/CSS/justify-self).
+            pub justify_self: Option<::iocraft::JustifySelf>
+        },
+        quote! {
+            /// Defines the rows of a grid container. This only has an effect if `display` is [`Display::Grid`](::iocraft::Display::Grid).
+            ///
+            /// See [the MDN documentation for grid-template-rows](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-template-rows).
+            pub grid_template_rows: Vec<::iocraft::GridTrack>
+        },
+        quote! {
+            /// Defines the columns of a grid container. This only has an effect if `display` is [`Display::Grid`](::iocraft::Display::Grid).
+            ///
+            /// See [the MDN documentation for grid-template-columns](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-template-columns).
+            pub grid_template_columns: Vec<::iocraft::GridTrack>
+        },
+        quote! {
+            /// Defines the size of implicitly created grid rows.
+            ///
+            /// See [the MDN documentation for grid-auto-rows](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-auto-rows).
+            pub grid_auto_rows: Vec<::iocraft::GridTrack>
+        },
+        quote! {
+            /// Defines the size of implicitly created grid columns.
+            ///
+            /// See [the MDN documentation for grid-auto-columns](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-auto-columns).
+            pub grid_auto_columns: Vec<::iocraft::GridTrack>
+        },
+        quote! {
+            /// Controls how automatically placed items are flowed into a grid.
+            ///
+            /// See [the MDN documentation for grid-auto-flow](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-auto-flow).
+            pub grid_auto_flow: ::iocraft::GridAutoFlow
+        },
+        quote! {
+            /// Defines which rows of the parent grid the element occupies.
+            ///
+            /// See [the MDN documentation for grid-row](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-row).
+            pub grid_row: ::iocraft::GridPlacement
+        },
+        quote! {
+            /// Defines which columns of the parent grid the element occupies.
+            ///
+            /// See [the MDN documentation for grid-column](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-column).
+            pub grid_column: ::iocraft::GridPlacement
+        },
         quote! {
             /// Defines the behavior when content does not fit within the element's padding box.
             ///
@@ -885,7 +945,7 @@ pub fn with_layout_style_props(_attr: TokenStream, item: TokenStream) -> TokenSt
             let struct_name = &ast.ident;
             let field_assignments = layout_style_fields.iter().map(|field| {
                 let field_name = &field.ident;
-                quote! { ret.#field_name = self.#field_name; }
+                quote! { ret.#field_name = self.#field_name.clone(); }
             });
 
             let where_clause = &ast.generics.where_clause;
@@ -917,6 +977,7 @@ pub fn with_layout_style_props(_attr: TokenStream, item: TokenStream) -> TokenSt
 
                 impl #generics #struct_name #bracketed_generic_names #where_clause {
                     /// Returns the layout style based on the layout-related fields of this struct.
+                    #[allow(clippy::clone_on_copy)]
                     pub fn layout_style(&self) -> ::iocraft::LayoutStyle {
                         let mut ret: ::iocraft::LayoutStyle = Default::default();
                         #(#field_assignments)*
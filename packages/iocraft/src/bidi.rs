@@ -0,0 +1,206 @@
+use std::{borrow::Cow, ops::Range};
+use unicode_bidi::ParagraphBidiInfo;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+mod mirroring;
+
+/// A line of text in visual order.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct VisualLine<'a> {
+    /// The runs of text making up the line, in visual order, along with the index of the segment
+    /// each run came from.
+    pub runs: Vec<(usize, Cow<'a, str>)>,
+
+    /// Whether the base direction of the line is right-to-left.
+    pub is_rtl: bool,
+}
+
+/// A run of text with a single direction.
+struct DirectionalRun {
+    range: Range<usize>,
+    is_rtl: bool,
+}
+
+/// Determines the base direction of a line and the directional runs within it, in visual order.
+///
+/// The base direction is determined by the first strong directional character in the line. If the
+/// line contains no right-to-left text, `None` is returned.
+fn directional_runs(text: &str) -> Option<(bool, Vec<DirectionalRun>)> {
+    let info = ParagraphBidiInfo::new(text, None);
+    if info.is_pure_ltr || !info.has_rtl() {
+        return None;
+    }
+    let (levels, runs) = info.visual_runs(0..text.len());
+    let runs = runs
+        .into_iter()
+        .map(|range| DirectionalRun {
+            is_rtl: levels[range.start].is_rtl(),
+            range,
+        })
+        .collect();
+    Some((info.paragraph_level.is_rtl(), runs))
+}
+
+/// Returns the mirrored form of a character, if it has one, for display in right-to-left text.
+fn mirror(c: char) -> char {
+    mirroring::BIDI_MIRRORING_GLYPHS
+        .binary_search_by_key(&c, |&(from, _)| from)
+        .map_or(c, |i| mirroring::BIDI_MIRRORING_GLYPHS[i].1)
+}
+
+fn reverse_graphemes(text: &str) -> String {
+    text.graphemes(true)
+        .rev()
+        .flat_map(|g| {
+            let mut chars = g.chars();
+            let first = chars.next().map(mirror);
+            first.into_iter().chain(chars)
+        })
+        .collect()
+}
+
+/// Reorders a single line of text from logical order to visual order, using the Unicode
+/// Bidirectional Algorithm.
+///
+/// The line may be made up of multiple segments, which are returned as separate runs so that they
+/// can be styled independently. The line is treated as its own paragraph, so its base direction
+/// is determined by its first strong directional character.
+pub(crate) fn reorder_line<'a>(segments: &[&'a str]) -> VisualLine<'a> {
+    let text: Cow<'_, str> = match segments {
+        [segment] => Cow::Borrowed(segment),
+        _ => Cow::Owned(segments.concat()),
+    };
+    let Some((is_rtl, runs)) = directional_runs(&text) else {
+        return VisualLine {
+            runs: segments
+                .iter()
+                .enumerate()
+                .map(|(i, s)| (i, Cow::Borrowed(*s)))
+                .collect(),
+            is_rtl: false,
+        };
+    };
+
+    let mut segment_ranges = Vec::with_capacity(segments.len());
+    let mut offset = 0;
+    for segment in segments {
+        segment_ranges.push(offset..offset + segment.len());
+        offset += segment.len();
+    }
+
+    let mut ret = Vec::new();
+    for run in runs {
+        let range = run.range;
+        let pieces = segment_ranges
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| r.start < range.end && range.start < r.end)
+            .map(|(i, r)| {
+                let start = range.start.max(r.start) - r.start;
+                let end = range.end.min(r.end) - r.start;
+                (i, &segments[i][start..end])
+            })
+            .collect::<Vec<_>>();
+        if run.is_rtl {
+            for (i, piece) in pieces.into_iter().rev() {
+                ret.push((i, Cow::Owned(reverse_graphemes(piece))));
+            }
+        } else {
+            for (i, piece) in pieces {
+                ret.push((i, Cow::Borrowed(piece)));
+            }
+        }
+    }
+    VisualLine { runs: ret, is_rtl }
+}
+
+/// Returns the visual column of each grapheme cluster in a line, as pairs of the grapheme
+/// cluster's byte offset and its column, sorted by byte offset.
+pub(crate) fn visual_columns(text: &str) -> Vec<(usize, usize)> {
+    let mut ret = Vec::new();
+    let mut col = 0;
+    match directional_runs(text) {
+        None => {
+            for (idx, g) in text.grapheme_indices(true) {
+                ret.push((idx, col));
+                col += g.width();
+            }
+        }
+        Some((_, runs)) => {
+            for run in runs {
+                let graphemes = text[run.range.clone()]
+                    .grapheme_indices(true)
+                    .map(|(idx, g)| (run.range.start + idx, g))
+                    .collect::<Vec<_>>();
+                let graphemes: Box<dyn Iterator<Item = _>> = if run.is_rtl {
+                    Box::new(graphemes.into_iter().rev())
+                } else {
+                    Box::new(graphemes.into_iter())
+                };
+                for (idx, g) in graphemes {
+                    ret.push((idx, col));
+                    col += g.width();
+                }
+            }
+            ret.sort_unstable();
+        }
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn visual(segments: &[&str]) -> (String, bool) {
+        let line = reorder_line(segments);
+        (
+            line.runs.iter().map(|(_, s)| s.as_ref()).collect(),
+            line.is_rtl,
+        )
+    }
+
+    #[test]
+    fn test_reorder_line() {
+        assert_eq!(visual(&[]), ("".to_string(), false));
+        assert_eq!(visual(&["hello"]), ("hello".to_string(), false));
+        assert_eq!(visual(&["שלום"]), ("םולש".to_string(), true));
+        assert_eq!(
+            visual(&["abc שלום def"]),
+            ("abc םולש def".to_string(), false)
+        );
+        assert_eq!(
+            visual(&["שלום abc עולם"]),
+            ("םלוע abc םולש".to_string(), true)
+        );
+        assert_eq!(visual(&["(שלום)"]), ("(םולש)".to_string(), true));
+        assert_eq!(visual(&["مرحبا 123"]), ("123 ابحرم".to_string(), true));
+
+        // Segments are kept separate so they can be styled independently.
+        let line = reorder_line(&["שלו", "ם abc"]);
+        assert_eq!(
+            line.runs,
+            vec![
+                (1, Cow::Borrowed("abc")),
+                (1, Cow::Owned(" ם".to_string())),
+                (0, Cow::Owned("ולש".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mirror() {
+        assert_eq!(mirror('('), ')');
+        assert_eq!(mirror('≤'), '≥');
+        assert_eq!(mirror('⟦'), '⟧');
+        assert_eq!(mirror('a'), 'a');
+        assert_eq!(visual(&["שלום ≤ עולם"]), ("םלוע ≥ םולש".to_string(), true));
+    }
+
+    #[test]
+    fn test_visual_columns() {
+        assert_eq!(visual_columns("ab"), vec![(0, 0), (1, 1)]);
+        assert_eq!(visual_columns("aשל"), vec![(0, 0), (1, 2), (3, 1)]);
+    }
+}
@@ -1,4 +1,8 @@
-use crate::style::{Color, Weight};
+use crate::{
+    color_depth::ColorDepth,
+    style::{Color, UnderlineStyle, Weight},
+    tabs::{expand_tabs, DEFAULT_TAB_WIDTH},
+};
 use crossterm::{
     csi,
     style::{Attribute, Colored},
@@ -7,14 +11,16 @@ use std::{
     env,
     fmt::{self, Display},
     io::{self, Write},
-    sync::Once,
+    sync::{Arc, Once},
 };
-use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 #[derive(Clone, Debug, PartialEq)]
 struct Character {
     value: String,
     style: CanvasTextStyle,
+    link: Option<Arc<str>>,
 }
 
 static mut HANDLES_VS16_INCORRECTLY: bool = false;
@@ -64,14 +70,37 @@ pub struct CanvasTextStyle {
     /// The weight of the text.
     pub weight: Weight,
 
-    /// Whether the text is underlined.
-    pub underline: bool,
+    /// The style of the underline, or `None` if the text isn't underlined. Terminals which don't
+    /// support underline styles generally fall back to a single underline.
+    pub underline_style: Option<UnderlineStyle>,
+
+    /// The color of the underline. If not set, the underline is the same color as the text.
+    pub underline_color: Option<Color>,
 
     /// Whether the text is italicized.
     pub italic: bool,
 
     /// Whether the foreground and background colors should be inverted.
     pub invert: bool,
+
+    /// Whether the text has a line through it.
+    pub strikethrough: bool,
+
+    /// Whether the text has a line above it.
+    pub overline: bool,
+
+    /// Whether the text blinks.
+    pub blink: bool,
+
+    /// Whether the text is hidden. Hidden text still occupies space, but isn't visible.
+    pub hidden: bool,
+}
+
+impl CanvasTextStyle {
+    /// Returns whether the text is underlined.
+    pub fn is_underlined(&self) -> bool {
+        self.underline_style.is_some()
+    }
 }
 
 /// A single cell on a [`Canvas`], containing optional text and background color.
@@ -94,6 +123,11 @@ impl CanvasCell {
         self.character.as_ref().map(|ch| &ch.style)
     }
 
+    /// Returns the URL that the text of this cell links to, if any.
+    pub fn link(&self) -> Option<&str> {
+        self.character.as_ref().and_then(|ch| ch.link.as_deref())
+    }
+
     /// Returns `true` if the cell has no content and no background color.
     pub fn is_empty(&self) -> bool {
         self.background_color.is_none() && self.character.is_none()
@@ -111,6 +145,7 @@ impl CanvasCell {
 pub struct Canvas {
     width: usize,
     cells: Vec<Vec<CanvasCell>>,
+    tab_width: usize,
 }
 
 impl Canvas {
@@ -119,9 +154,20 @@ impl Canvas {
         Self {
             width,
             cells: vec![vec![CanvasCell::default(); width]; height],
+            tab_width: DEFAULT_TAB_WIDTH,
         }
     }
 
+    /// Returns the number of columns between tab stops used when writing text. Defaults to 8.
+    pub fn tab_width(&self) -> usize {
+        self.tab_width
+    }
+
+    /// Sets the number of columns between tab stops used when writing text.
+    pub fn set_tab_width(&mut self, tab_width: usize) {
+        self.tab_width = tab_width;
+    }
+
     /// Returns the width of the canvas.
     pub fn width(&self) -> usize {
         self.width
@@ -200,30 +246,38 @@ impl Canvas {
         }
     }
 
-    fn set_text_row_chars<I>(&mut self, mut x: usize, y: usize, chars: I, style: CanvasTextStyle)
-    where
-        I: IntoIterator<Item = char>,
+    fn set_text_row_graphemes<'a, I>(
+        &mut self,
+        mut x: usize,
+        y: usize,
+        graphemes: I,
+        style: CanvasTextStyle,
+        link: Option<&Arc<str>>,
+    ) where
+        I: IntoIterator<Item = &'a str>,
     {
-        // Divide the string up into characters, which may consist of multiple Unicode code points.
+        // Each extended grapheme cluster occupies a single cell, regardless of how many Unicode
+        // code points it consists of. Zero-width clusters are attached to the preceding cell.
         let row = &mut self.cells[y];
-        let mut buf = String::new();
-        for c in chars.into_iter() {
+        let mut prev_x = None;
+        for grapheme in graphemes {
+            let width = grapheme.width();
+            if width == 0 {
+                if let Some(Some(character)) = prev_x.map(|x: usize| &mut row[x].character) {
+                    character.value.push_str(grapheme);
+                    continue;
+                }
+            }
             if x >= row.len() {
                 break;
             }
-            let width = c.width().unwrap_or(0);
-            if width > 0 && !buf.is_empty() {
-                row[x].character = Some(Character {
-                    value: buf.clone(),
-                    style,
-                });
-                x += buf.width().max(1);
-                buf.clear();
-            }
-            buf.push(c);
-        }
-        if !buf.is_empty() && x < row.len() {
-            row[x].character = Some(Character { value: buf, style });
+            row[x].character = Some(Character {
+                value: grapheme.to_string(),
+                style,
+                link: link.cloned(),
+            });
+            prev_x = Some(x);
+            x += width.max(1);
         }
     }
 
@@ -267,11 +321,21 @@ impl Canvas {
     /// leading reset of its own. It always leaves SGR state reset on return,
     /// so consecutive calls (or any subsequent writer use) start from a clean
     /// state.
-    fn write_row_impl<W: Write>(&self, y: usize, mut w: W, ansi: bool) -> io::Result<()> {
+    ///
+    /// If `color_depth` is `None`, the row is written as unstyled text.
+    fn write_row_impl<W: Write>(
+        &self,
+        y: usize,
+        mut w: W,
+        color_depth: Option<ColorDepth>,
+    ) -> io::Result<()> {
         let row = self.row(y);
+        let ansi = color_depth.is_some();
+        let color_depth = color_depth.unwrap_or(ColorDepth::TrueColor);
 
         let mut background_color = None;
         let mut text_style = CanvasTextStyle::default();
+        let mut link: Option<&str> = None;
         let mut col = 0;
         let mut did_clear_line = false;
         while col < row.len() {
@@ -283,7 +347,7 @@ impl Canvas {
                     if c.style.weight != text_style.weight && c.style.weight == Weight::Normal {
                         needs_reset = true;
                     }
-                    if !c.style.underline && text_style.underline {
+                    if !c.style.is_underlined() && text_style.is_underlined() {
                         needs_reset = true;
                     }
                     if !c.style.italic && text_style.italic {
@@ -292,7 +356,7 @@ impl Canvas {
                     if !c.style.invert && text_style.invert {
                         needs_reset = true;
                     }
-                } else if text_style.underline || text_style.invert {
+                } else if text_style.is_underlined() || text_style.invert {
                     needs_reset = true;
                 }
                 if needs_reset {
@@ -302,11 +366,12 @@ impl Canvas {
                 }
 
                 if let Some(c) = &cell.character {
-                    if c.style.color != text_style.color {
+                    let color = c.style.color.and_then(|color| color_depth.adapt(color));
+                    if color != text_style.color {
                         write!(
                             w,
                             csi!("{}m"),
-                            Colored::ForegroundColor(c.style.color.unwrap_or(Color::Reset))
+                            Colored::ForegroundColor(color.unwrap_or(Color::Reset))
                         )?;
                     }
 
@@ -318,8 +383,31 @@ impl Canvas {
                         }
                     }
 
-                    if c.style.underline && !text_style.underline {
-                        write!(w, csi!("{}m"), Attribute::Underlined.sgr())?;
+                    if let Some(underline_style) = c
+                        .style
+                        .underline_style
+                        .filter(|&style| text_style.underline_style != Some(style))
+                    {
+                        let attr = match underline_style {
+                            UnderlineStyle::Single => Attribute::Underlined,
+                            UnderlineStyle::Double => Attribute::DoubleUnderlined,
+                            UnderlineStyle::Curly => Attribute::Undercurled,
+                            UnderlineStyle::Dotted => Attribute::Underdotted,
+                            UnderlineStyle::Dashed => Attribute::Underdashed,
+                        };
+                        write!(w, csi!("{}m"), attr.sgr())?;
+                    }
+
+                    let underline_color = c
+                        .style
+                        .underline_color
+                        .and_then(|color| color_depth.adapt(color));
+                    if underline_color != text_style.underline_color {
+                        write!(
+                            w,
+                            csi!("{}m"),
+                            Colored::UnderlineColor(underline_color.unwrap_or(Color::Reset))
+                        )?;
                     }
 
                     if c.style.italic && !text_style.italic {
@@ -330,7 +418,54 @@ impl Canvas {
                         write!(w, csi!("{}m"), Attribute::Reverse.sgr())?;
                     }
 
-                    text_style = c.style;
+                    // These attributes have dedicated "off" codes, so they never require a reset.
+                    for (enabled, was_enabled, on, off) in [
+                        (
+                            c.style.strikethrough,
+                            text_style.strikethrough,
+                            Attribute::CrossedOut,
+                            Attribute::NotCrossedOut,
+                        ),
+                        (
+                            c.style.overline,
+                            text_style.overline,
+                            Attribute::OverLined,
+                            Attribute::NotOverLined,
+                        ),
+                        (
+                            c.style.blink,
+                            text_style.blink,
+                            Attribute::SlowBlink,
+                            Attribute::NoBlink,
+                        ),
+                        (
+                            c.style.hidden,
+                            text_style.hidden,
+                            Attribute::Hidden,
+                            Attribute::NoHidden,
+                        ),
+                    ] {
+                        if enabled != was_enabled {
+                            let attr = if enabled { on } else { off };
+                            write!(w, csi!("{}m"), attr.sgr())?;
+                        }
+                    }
+
+                    text_style = CanvasTextStyle {
+                        color,
+                        underline_color,
+                        ..c.style
+                    };
+                } else {
+                    // Lines through or above the text would be visible in empty cells too.
+                    if text_style.strikethrough {
+                        write!(w, csi!("{}m"), Attribute::NotCrossedOut.sgr())?;
+                        text_style.strikethrough = false;
+                    }
+                    if text_style.overline {
+                        write!(w, csi!("{}m"), Attribute::NotOverLined.sgr())?;
+                        text_style.overline = false;
+                    }
                 }
             }
 
@@ -350,13 +485,29 @@ impl Canvas {
                 did_clear_line = true;
             }
 
-            if ansi && cell.background_color != background_color {
+            let cell_background_color = cell
+                .background_color
+                .and_then(|color| color_depth.adapt(color));
+            if ansi && cell_background_color != background_color {
                 write!(
                     w,
                     csi!("{}m"),
-                    Colored::BackgroundColor(cell.background_color.unwrap_or(Color::Reset))
+                    Colored::BackgroundColor(cell_background_color.unwrap_or(Color::Reset))
                 )?;
-                background_color = cell.background_color;
+                background_color = cell_background_color;
+            }
+
+            if ansi {
+                let cell_link = cell.character.as_ref().and_then(|c| c.link.as_deref());
+                if cell_link != link {
+                    if link.is_some() {
+                        write_hyperlink(&mut w, "")?;
+                    }
+                    if let Some(url) = cell_link {
+                        write_hyperlink(&mut w, url)?;
+                    }
+                    link = cell_link;
+                }
             }
 
             if let Some(c) = &cell.character {
@@ -366,6 +517,9 @@ impl Canvas {
             }
         }
         if ansi {
+            if link.is_some() {
+                write_hyperlink(&mut w, "")?;
+            }
             if background_color.is_some() {
                 write!(w, csi!("{}m"), Colored::BackgroundColor(Color::Reset))?;
             }
@@ -387,23 +541,25 @@ impl Canvas {
         &self,
         y: usize,
         w: W,
+        color_depth: ColorDepth,
     ) -> io::Result<()> {
-        self.write_row_impl(y, w, true)
+        self.write_row_impl(y, w, Some(color_depth))
     }
 
     fn write_impl<W: Write>(
         &self,
         mut w: W,
-        ansi: bool,
+        color_depth: Option<ColorDepth>,
         omit_final_newline: bool,
     ) -> io::Result<()> {
+        let ansi = color_depth.is_some();
         if ansi {
             // Seed clean SGR state for the first row. Subsequent rows rely on
             // the trailing reset of the previous row.
             write!(w, csi!("0m"))?;
         }
         for y in 0..self.cells.len() {
-            self.write_row_impl(y, &mut w, ansi)?;
+            self.write_row_impl(y, &mut w, color_depth)?;
             let is_final_line = y == self.cells.len() - 1;
             if !omit_final_newline || !is_final_line {
                 if ansi {
@@ -419,20 +575,48 @@ impl Canvas {
     }
 
     /// Writes the canvas to the given writer with ANSI escape codes.
+    ///
+    /// Colors are written as-is. To convert them to colors which the destination can display, use
+    /// [`write_ansi_with_color_depth`](Self::write_ansi_with_color_depth) instead.
     pub fn write_ansi<W: Write>(&self, w: W) -> io::Result<()> {
-        self.write_impl(w, true, false)
+        self.write_impl(w, Some(ColorDepth::TrueColor), false)
+    }
+
+    /// Writes the canvas to the given writer with ANSI escape codes, converting each color to the
+    /// closest color which can be displayed with the given color depth.
+    pub fn write_ansi_with_color_depth<W: Write>(
+        &self,
+        w: W,
+        color_depth: ColorDepth,
+    ) -> io::Result<()> {
+        self.write_impl(w, Some(color_depth), false)
     }
 
-    pub(crate) fn write_ansi_without_final_newline<W: Write>(&self, w: W) -> io::Result<()> {
-        self.write_impl(w, true, true)
+    pub(crate) fn write_ansi_without_final_newline<W: Write>(
+        &self,
+        w: W,
+        color_depth: ColorDepth,
+    ) -> io::Result<()> {
+        self.write_impl(w, Some(color_depth), true)
     }
 
     /// Writes the canvas to the given writer as unstyled text, without ANSI escape codes.
     pub fn write<W: Write>(&self, w: W) -> io::Result<()> {
-        self.write_impl(w, false, false)
+        self.write_impl(w, None, false)
     }
 }
 
+/// Writes an OSC 8 sequence which starts a hyperlink to the given URL, or ends the current
+/// hyperlink if the URL is empty. Control characters are removed from the URL so that it can't
+/// terminate the sequence early.
+fn write_hyperlink<W: Write>(mut w: W, url: &str) -> io::Result<()> {
+    w.write_all(b"\x1b]8;;")?;
+    for c in url.chars().filter(|c| !c.is_control()) {
+        write!(w, "{}", c)?;
+    }
+    w.write_all(b"\x1b\\")
+}
+
 impl Display for Canvas {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut buf = Vec::with_capacity(self.width * self.cells.len());
@@ -537,7 +721,38 @@ impl CanvasSubviewMut<'_> {
     }
 
     /// Writes text to the region.
+    ///
+    /// Tab characters are replaced with spaces, with tab stops relative to `x` as determined by
+    /// [`Canvas::tab_width`].
     pub fn set_text(&mut self, x: isize, y: isize, text: &str, style: CanvasTextStyle) {
+        self.set_text_with_link(x, y, text, style, None);
+    }
+
+    /// Writes text which links to the given URL to the region. Otherwise identical to
+    /// [`set_text`](Self::set_text).
+    ///
+    /// When written with ANSI escape codes, linked text is wrapped in OSC 8 hyperlink sequences,
+    /// which most modern terminals render as clickable links.
+    pub fn set_linked_text(
+        &mut self,
+        x: isize,
+        y: isize,
+        text: &str,
+        style: CanvasTextStyle,
+        url: &str,
+    ) {
+        self.set_text_with_link(x, y, text, style, Some(&Arc::from(url)));
+    }
+
+    pub(crate) fn set_text_with_link(
+        &mut self,
+        x: isize,
+        y: isize,
+        text: &str,
+        style: CanvasTextStyle,
+        link: Option<&Arc<str>>,
+    ) {
+        let text = expand_tabs(text, self.canvas.tab_width, &mut 0);
         let mut x = self.x + x;
         let min_x = self.clip_x.max(0);
         let mut to_skip = 0;
@@ -549,35 +764,34 @@ impl CanvasSubviewMut<'_> {
         let horizontal_space = max_x - x + 1;
         let min_y = self.clip_y.max(0);
         let max_y = (self.clip_y + self.clip_height as isize).min(self.canvas.height() as _) - 1;
-        let mut y = self.y + y;
-        for line in text.lines() {
+        for (y, line) in (self.y + y..).zip(text.lines()) {
             if y >= min_y && y <= max_y {
                 let mut skipped_width = 0;
                 let mut taken_width = 0;
-                self.canvas.set_text_row_chars(
+                self.canvas.set_text_row_graphemes(
                     x as usize,
                     y as usize,
-                    line.chars()
-                        .skip_while(|c| {
+                    line.graphemes(true)
+                        .skip_while(|g| {
                             if skipped_width < to_skip {
-                                skipped_width += c.width().unwrap_or(0) as isize;
+                                skipped_width += g.width() as isize;
                                 true
                             } else {
                                 false
                             }
                         })
-                        .take_while(|c| {
+                        .take_while(|g| {
                             if taken_width < horizontal_space {
-                                taken_width += c.width().unwrap_or(0) as isize;
+                                taken_width += g.width() as isize;
                                 true
                             } else {
                                 false
                             }
                         }),
                     style,
+                    link,
                 );
             }
-            y += 1;
         }
     }
 }
@@ -738,7 +952,7 @@ mod tests {
             CanvasTextStyle {
                 color: Some(Color::Red),
                 weight: Weight::Bold,
-                underline: true,
+                underline_style: Some(UnderlineStyle::Single),
                 ..Default::default()
             },
         );
@@ -885,6 +1099,47 @@ mod tests {
         assert_eq!(actual, "\n\n  ne 2\n  ne 3\n\n");
     }
 
+    #[test]
+    fn test_canvas_grapheme_clusters() {
+        let mut canvas = Canvas::new(10, 1);
+        canvas.subview_mut(0, 0, 0, 0, 10, 1).set_text(
+            0,
+            0,
+            "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}e\u{301}\u{915}\u{94d}\u{937}\u{93f}x",
+            CanvasTextStyle::default(),
+        );
+        assert_eq!(
+            canvas.cell(0, 0).unwrap().character.as_ref().unwrap().value,
+            "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}"
+        );
+        assert_eq!(
+            canvas.cell(2, 0).unwrap().character.as_ref().unwrap().value,
+            "e\u{301}"
+        );
+        assert_eq!(
+            canvas.cell(3, 0).unwrap().character.as_ref().unwrap().value,
+            "\u{915}\u{94d}\u{937}\u{93f}"
+        );
+        assert_eq!(
+            canvas.cell(6, 0).unwrap().character.as_ref().unwrap().value,
+            "x"
+        );
+    }
+
+    #[test]
+    fn test_canvas_text_tabs() {
+        let mut canvas = Canvas::new(20, 2);
+        assert_eq!(canvas.tab_width(), 8);
+        canvas
+            .subview_mut(2, 0, 0, 0, 20, 2)
+            .set_text(0, 0, "a\tb", CanvasTextStyle::default());
+        canvas.set_tab_width(4);
+        canvas
+            .subview_mut(2, 1, 0, 0, 20, 2)
+            .set_text(0, 0, "a\tb\tc", CanvasTextStyle::default());
+        assert_eq!(canvas.to_string(), "  a       b\n  a   b   c\n");
+    }
+
     #[test]
     fn test_canvas_text_clearing() {
         let mut canvas = Canvas::new(10, 1);
@@ -907,7 +1162,7 @@ mod tests {
 
         let mut actual = Vec::new();
         canvas
-            .write_ansi_without_final_newline(&mut actual)
+            .write_ansi_without_final_newline(&mut actual, ColorDepth::TrueColor)
             .unwrap();
 
         let mut expected = Vec::new();
@@ -971,7 +1226,6 @@ mod tests {
         let mut sv = canvas.subview_mut(0, 0, 0, 0, 10, 3);
         sv.set_text(0, 0, "hello", CanvasTextStyle::default());
         sv.set_text(2, 1, "ab", CanvasTextStyle::default());
-        drop(sv);
         assert_eq!(canvas.get_text(0, 0, 10, 1), "hello");
         assert_eq!(canvas.get_text(0, 1, 10, 1), "  ab");
         assert_eq!(canvas.get_text(0, 2, 10, 1), "");
@@ -983,7 +1237,6 @@ mod tests {
         let mut sv = canvas.subview_mut(0, 0, 0, 0, 10, 3);
         sv.set_text(0, 0, "line one", CanvasTextStyle::default());
         sv.set_text(0, 1, "line two", CanvasTextStyle::default());
-        drop(sv);
         assert_eq!(
             canvas.get_text(0, 0, 10, 3),
             "line one
@@ -1116,7 +1369,9 @@ line two
         // Each row renders without a leading reset (caller's contract is to
         // provide clean SGR state) but always leaves SGR state reset on return.
         let mut row0 = Vec::new();
-        canvas.write_ansi_row_without_newline(0, &mut row0).unwrap();
+        canvas
+            .write_ansi_row_without_newline(0, &mut row0, ColorDepth::TrueColor)
+            .unwrap();
 
         let mut expected0 = Vec::new();
         write!(expected0, "hello").unwrap();
@@ -1125,7 +1380,9 @@ line two
         assert_eq!(row0, expected0);
 
         let mut row1 = Vec::new();
-        canvas.write_ansi_row_without_newline(1, &mut row1).unwrap();
+        canvas
+            .write_ansi_row_without_newline(1, &mut row1, ColorDepth::TrueColor)
+            .unwrap();
 
         let mut expected1 = Vec::new();
         write!(expected1, "world").unwrap();
@@ -1133,4 +1390,181 @@ line two
         write!(expected1, csi!("0m")).unwrap();
         assert_eq!(row1, expected1);
     }
+
+    #[test]
+    fn test_canvas_hyperlink() {
+        let mut canvas = Canvas::new(10, 1);
+        canvas
+            .subview_mut(0, 0, 0, 0, 10, 1)
+            .set_text(0, 0, "a", CanvasTextStyle::default());
+        canvas.subview_mut(0, 0, 0, 0, 10, 1).set_linked_text(
+            1,
+            0,
+            "bc",
+            CanvasTextStyle::default(),
+            "https://example.com/\x07",
+        );
+        canvas
+            .subview_mut(0, 0, 0, 0, 10, 1)
+            .set_text(3, 0, "d", CanvasTextStyle::default());
+
+        let mut actual = Vec::new();
+        canvas
+            .write_ansi_row_without_newline(0, &mut actual, ColorDepth::TrueColor)
+            .unwrap();
+
+        // The control character is removed from the URL.
+        let mut expected = Vec::new();
+        write!(expected, "a").unwrap();
+        write!(expected, "\x1b]8;;https://example.com/\x1b\\bc").unwrap();
+        write!(expected, "\x1b]8;;\x1b\\d").unwrap();
+        write!(expected, csi!("K")).unwrap();
+        write!(expected, csi!("0m")).unwrap();
+        assert_eq!(actual, expected);
+
+        // Without ANSI escape codes, only the text is written.
+        assert_eq!(canvas.to_string(), "abcd\n");
+    }
+
+    #[test]
+    fn test_canvas_hyperlink_closed_at_end_of_row() {
+        let mut canvas = Canvas::new(10, 1);
+        canvas.subview_mut(0, 0, 0, 0, 10, 1).set_linked_text(
+            0,
+            0,
+            "ab",
+            CanvasTextStyle::default(),
+            "https://example.com",
+        );
+
+        let mut actual = Vec::new();
+        canvas
+            .write_ansi_row_without_newline(0, &mut actual, ColorDepth::TrueColor)
+            .unwrap();
+
+        let mut expected = Vec::new();
+        write!(expected, "\x1b]8;;https://example.com\x1b\\ab").unwrap();
+        write!(expected, "\x1b]8;;\x1b\\").unwrap();
+        write!(expected, csi!("K")).unwrap();
+        write!(expected, csi!("0m")).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_canvas_extended_attributes() {
+        let mut canvas = Canvas::new(10, 1);
+        let mut subview = canvas.subview_mut(0, 0, 0, 0, 10, 1);
+        subview.set_text(
+            0,
+            0,
+            "ab",
+            CanvasTextStyle {
+                strikethrough: true,
+                ..Default::default()
+            },
+        );
+        subview.set_text(
+            2,
+            0,
+            "c",
+            CanvasTextStyle {
+                strikethrough: true,
+                underline_style: Some(UnderlineStyle::Curly),
+                underline_color: Some(Color::Red),
+                ..Default::default()
+            },
+        );
+        subview.set_text(
+            3,
+            0,
+            "d",
+            CanvasTextStyle {
+                overline: true,
+                ..Default::default()
+            },
+        );
+        subview.set_text(4, 0, "e", CanvasTextStyle::default());
+
+        let mut actual = Vec::new();
+        canvas
+            .write_ansi_row_without_newline(0, &mut actual, ColorDepth::TrueColor)
+            .unwrap();
+
+        // Attributes are only emitted when they change, and overline is turned off without a full
+        // reset.
+        let mut expected = Vec::new();
+        write!(expected, csi!("{}m"), Attribute::CrossedOut.sgr()).unwrap();
+        write!(expected, "ab").unwrap();
+        write!(expected, csi!("{}m"), Attribute::Undercurled.sgr()).unwrap();
+        write!(expected, csi!("{}m"), Colored::UnderlineColor(Color::Red)).unwrap();
+        write!(expected, "c").unwrap();
+        write!(expected, csi!("0m")).unwrap();
+        write!(expected, csi!("{}m"), Attribute::OverLined.sgr()).unwrap();
+        write!(expected, "d").unwrap();
+        write!(expected, csi!("{}m"), Attribute::NotOverLined.sgr()).unwrap();
+        write!(expected, "e").unwrap();
+        write!(expected, csi!("K")).unwrap();
+        write!(expected, csi!("0m")).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_write_ansi_with_color_depth() {
+        let mut canvas = Canvas::new(1, 1);
+        let mut subview = canvas.subview_mut(0, 0, 0, 0, 1, 1);
+        subview.set_background_color(0, 0, 1, 1, Color::AnsiValue(196));
+        subview.set_text(
+            0,
+            0,
+            "a",
+            CanvasTextStyle {
+                color: Some(Color::Rgb {
+                    r: 255,
+                    g: 135,
+                    b: 0,
+                }),
+                ..Default::default()
+            },
+        );
+
+        let mut actual = Vec::new();
+        canvas
+            .write_ansi_with_color_depth(&mut actual, ColorDepth::Ansi256)
+            .unwrap();
+        let actual = String::from_utf8(actual).unwrap();
+        assert!(actual.contains(&format!(
+            csi!("{}m"),
+            Colored::ForegroundColor(Color::AnsiValue(208))
+        )));
+        assert!(actual.contains(&format!(
+            csi!("{}m"),
+            Colored::BackgroundColor(Color::AnsiValue(196))
+        )));
+
+        let mut actual = Vec::new();
+        canvas
+            .write_ansi_with_color_depth(&mut actual, ColorDepth::Ansi16)
+            .unwrap();
+        let actual = String::from_utf8(actual).unwrap();
+        assert!(actual.contains(&format!(
+            csi!("{}m"),
+            Colored::ForegroundColor(Color::DarkYellow)
+        )));
+        assert!(actual.contains(&format!(csi!("{}m"), Colored::BackgroundColor(Color::Red))));
+        assert!(!actual.contains("38;2;"));
+
+        // Without colors, the text is still written with ANSI escape codes, but without any color
+        // codes.
+        let mut actual = Vec::new();
+        canvas
+            .write_ansi_with_color_depth(&mut actual, ColorDepth::NoColor)
+            .unwrap();
+        let mut expected = Vec::new();
+        write!(expected, csi!("0m")).unwrap();
+        write!(expected, csi!("K")).unwrap();
+        write!(expected, "a").unwrap();
+        write!(expected, csi!("0m")).unwrap();
+        write!(expected, "\r\n").unwrap();
+        assert_eq!(actual, expected);
+    }
 }
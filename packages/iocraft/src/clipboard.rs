@@ -0,0 +1,131 @@
+use crate::{hooks::UseContext, ComponentUpdater, Hook, Hooks, SystemContext};
+use core::{
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use std::sync::{Arc, Mutex};
+
+/// Encodes bytes as standard base64 with padding.
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut ret = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, b)| n | (*b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                ret.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                ret.push('=');
+            }
+        }
+    }
+    ret
+}
+
+/// Returns the OSC 52 sequence which sets the system clipboard to the given text.
+fn osc52(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", base64_encode(text.as_bytes()))
+}
+
+/// A crate-private hook which provides access to the clipboard.
+pub(crate) trait UseClipboard {
+    /// Returns a handle which can be used to copy text to and paste text from the clipboard.
+    fn use_clipboard(&mut self) -> ClipboardHandle;
+}
+
+impl UseClipboard for Hooks<'_, '_> {
+    fn use_clipboard(&mut self) -> ClipboardHandle {
+        let register = self
+            .try_use_context::<SystemContext>()
+            .map(|system| system.clipboard().clone())
+            .unwrap_or_default();
+        ClipboardHandle {
+            register,
+            state: self.use_hook(UseClipboardImpl::default).state.clone(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct ClipboardState {
+    pending: Option<String>,
+    waker: Option<Waker>,
+}
+
+/// A handle to the clipboard, obtained from [`UseClipboard::use_clipboard`].
+#[derive(Clone)]
+pub(crate) struct ClipboardHandle {
+    register: Arc<Mutex<String>>,
+    state: Arc<Mutex<ClipboardState>>,
+}
+
+impl ClipboardHandle {
+    /// Copies text to the render loop's register, and queues it to be copied to the system clipboard
+    /// via OSC 52 if rendering to a terminal.
+    pub fn copy(&self, text: &str) {
+        *self.register.lock().unwrap() = text.to_string();
+        let mut state = self.state.lock().unwrap();
+        state.pending = Some(text.to_string());
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// Returns the text most recently copied within the render loop.
+    pub fn paste(&self) -> String {
+        self.register.lock().unwrap().clone()
+    }
+}
+
+#[derive(Default)]
+struct UseClipboardImpl {
+    state: Arc<Mutex<ClipboardState>>,
+}
+
+impl Hook for UseClipboardImpl {
+    fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let mut state = self.state.lock().unwrap();
+        if state.pending.is_some() {
+            Poll::Ready(())
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        let Some(text) = self.state.lock().unwrap().pending.take() else {
+            return;
+        };
+        if let Some(terminal) = updater.terminal_mut() {
+            let output = terminal.render_output();
+            let _ = output.write_all(osc52(&text).as_bytes());
+            let _ = output.flush();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64_encode() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"foob"), "Zm9vYg==");
+        assert_eq!(base64_encode(b"fooba"), "Zm9vYmE=");
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+        assert_eq!(base64_encode(&[0xff, 0xfe, 0x00]), "//4A");
+    }
+
+    #[test]
+    fn test_osc52() {
+        assert_eq!(osc52("hello"), "\x1b]52;c;aGVsbG8=\x07");
+    }
+}
@@ -0,0 +1,315 @@
+use crate::style::Color;
+use std::env;
+
+/// The range of colors that a terminal is able to display.
+///
+/// When writing output with ANSI escape codes, colors which the terminal can't display are
+/// converted to the closest color that it can. For example, [`Color::Rgb`] colors are converted to
+/// entries in the 256-color palette for terminals which only support 256 colors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorDepth {
+    /// No colors are written at all. Other attributes such as bold and underline are unaffected.
+    NoColor,
+    /// Only the 16 standard ANSI colors are supported.
+    Ansi16,
+    /// The 256-color palette is supported.
+    Ansi256,
+    /// Arbitrary 24-bit RGB colors are supported.
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Detects the color depth of the current terminal based on environment variables such as
+    /// `COLORTERM`, `TERM`, and `TERM_PROGRAM`.
+    ///
+    /// This is a best-effort heuristic. If the terminal can't be identified, the most widely
+    /// supported depth, [`ColorDepth::Ansi16`], is assumed.
+    ///
+    /// The [`NO_COLOR`](https://no-color.org) and [`FORCE_COLOR`](https://force-color.org)
+    /// conventions are also honored:
+    ///
+    /// - If `NO_COLOR` is set to a non-empty value, [`ColorDepth::NoColor`] is returned.
+    /// - If `FORCE_COLOR` is `0` or `false`, [`ColorDepth::NoColor`] is returned. If it's `2` or
+    ///   `3`, at least [`ColorDepth::Ansi256`] or [`ColorDepth::TrueColor`] is returned
+    ///   respectively.
+    pub fn detect() -> Self {
+        Self::detect_from_env(|name| env::var(name).ok())
+    }
+
+    fn detect_from_env<F: Fn(&str) -> Option<String>>(var: F) -> Self {
+        if var("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+            return Self::NoColor;
+        }
+        let detected = Self::detect_terminal_from_env(&var);
+        match var("FORCE_COLOR").as_deref() {
+            Some("0" | "false") => Self::NoColor,
+            Some("2") => detected.max(Self::Ansi256),
+            Some("3") => Self::TrueColor,
+            _ => detected,
+        }
+    }
+
+    fn detect_terminal_from_env<F: Fn(&str) -> Option<String>>(var: F) -> Self {
+        if let Some(colorterm) = var("COLORTERM") {
+            if colorterm == "truecolor" || colorterm == "24bit" {
+                return Self::TrueColor;
+            }
+        }
+
+        let term = var("TERM").unwrap_or_default();
+        if term == "dumb" || term == "linux" {
+            return Self::Ansi16;
+        }
+        if term.ends_with("-direct") || term.contains("truecolor") || term.contains("24bit") {
+            return Self::TrueColor;
+        }
+
+        match var("TERM_PROGRAM").as_deref() {
+            Some("iTerm.app" | "WezTerm" | "vscode" | "ghostty" | "Hyper") => {
+                return Self::TrueColor
+            }
+            Some("Apple_Terminal") => return Self::Ansi256,
+            _ => {}
+        }
+
+        // Windows Terminal
+        if var("WT_SESSION").is_some_and(|v| !v.is_empty()) {
+            return Self::TrueColor;
+        }
+
+        if ["kitty", "alacritty", "wezterm", "foot", "ghostty"]
+            .iter()
+            .any(|name| term.contains(name))
+        {
+            return Self::TrueColor;
+        }
+        if term.contains("256") {
+            return Self::Ansi256;
+        }
+
+        // Modern Windows consoles support 24-bit color, but don't advertise it.
+        if term.is_empty() && cfg!(windows) {
+            return Self::TrueColor;
+        }
+
+        Self::Ansi16
+    }
+
+    /// Converts the color to the closest color which can be displayed with this depth, or `None`
+    /// if colors shouldn't be written at all, as is the case for [`ColorDepth::NoColor`].
+    pub fn adapt(self, color: Color) -> Option<Color> {
+        Some(match (self, color) {
+            (Self::NoColor, _) => return None,
+            (Self::TrueColor, _) => color,
+            (Self::Ansi256, Color::Rgb { r, g, b }) => Color::AnsiValue(rgb_to_ansi256(r, g, b)),
+            (Self::Ansi16, Color::Rgb { r, g, b }) => rgb_to_ansi16(r, g, b),
+            (Self::Ansi16, Color::AnsiValue(n)) => {
+                let (r, g, b) = ansi256_to_rgb(n);
+                rgb_to_ansi16(r, g, b)
+            }
+            _ => color,
+        })
+    }
+}
+
+/// Returns `true` if the `FORCE_COLOR` or `CLICOLOR_FORCE` environment variables request ANSI
+/// output even when not writing to a terminal.
+pub(crate) fn is_ansi_forced() -> bool {
+    is_ansi_forced_from_env(|name| env::var(name).ok())
+}
+
+fn is_ansi_forced_from_env<F: Fn(&str) -> Option<String>>(var: F) -> bool {
+    let force_color = var("FORCE_COLOR").is_some_and(|v| !v.is_empty() && v != "0" && v != "false");
+    let clicolor_force = var("CLICOLOR_FORCE").is_some_and(|v| !v.is_empty() && v != "0");
+    force_color || clicolor_force
+}
+
+/// The standard 16 colors, along with the RGB values that xterm uses for them by default.
+const ANSI16_PALETTE: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::DarkRed, (205, 0, 0)),
+    (Color::DarkGreen, (0, 205, 0)),
+    (Color::DarkYellow, (205, 205, 0)),
+    (Color::DarkBlue, (0, 0, 238)),
+    (Color::DarkMagenta, (205, 0, 205)),
+    (Color::DarkCyan, (0, 205, 205)),
+    (Color::Grey, (229, 229, 229)),
+    (Color::DarkGrey, (127, 127, 127)),
+    (Color::Red, (255, 0, 0)),
+    (Color::Green, (0, 255, 0)),
+    (Color::Yellow, (255, 255, 0)),
+    (Color::Blue, (92, 92, 255)),
+    (Color::Magenta, (255, 0, 255)),
+    (Color::Cyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// The channel intensities used by the 6x6x6 color cube in the 256-color palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let dr = r1 as i32 - r2 as i32;
+    let dg = g1 as i32 - g2 as i32;
+    let db = b1 as i32 - b2 as i32;
+    (dr * dr + dg * dg + db * db) as u32
+}
+
+fn ansi256_to_rgb(n: u8) -> (u8, u8, u8) {
+    match n {
+        0..=15 => ANSI16_PALETTE[n as usize].1,
+        16..=231 => {
+            let n = n - 16;
+            (
+                CUBE_LEVELS[(n / 36) as usize],
+                CUBE_LEVELS[(n / 6 % 6) as usize],
+                CUBE_LEVELS[(n % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + 10 * (n - 232);
+            (level, level, level)
+        }
+    }
+}
+
+fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    // The first 16 entries vary between terminals, so only the color cube and grayscale ramp are
+    // considered.
+    let cube_index = |c: u8| {
+        CUBE_LEVELS
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, level)| (c as i32 - **level as i32).abs())
+            .map(|(i, _)| i as u8)
+            .unwrap_or(0)
+    };
+    let (ri, gi, bi) = (cube_index(r), cube_index(g), cube_index(b));
+    let cube = 16 + 36 * ri + 6 * gi + bi;
+
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let gray = 232 + ((average.saturating_sub(3)) / 10).min(23) as u8;
+
+    if distance((r, g, b), ansi256_to_rgb(gray)) < distance((r, g, b), ansi256_to_rgb(cube)) {
+        gray
+    } else {
+        cube
+    }
+}
+
+fn rgb_to_ansi16(r: u8, g: u8, b: u8) -> Color {
+    ANSI16_PALETTE
+        .iter()
+        .min_by_key(|(_, rgb)| distance((r, g, b), *rgb))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::Reset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detect(vars: &[(&str, &str)]) -> ColorDepth {
+        ColorDepth::detect_from_env(|name| {
+            vars.iter()
+                .find(|(k, _)| *k == name)
+                .map(|(_, v)| v.to_string())
+        })
+    }
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(
+            detect(&[("COLORTERM", "truecolor"), ("TERM", "xterm")]),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(detect(&[("TERM", "xterm-256color")]), ColorDepth::Ansi256);
+        assert_eq!(detect(&[("TERM", "linux")]), ColorDepth::Ansi16);
+        assert_eq!(detect(&[("TERM", "xterm-kitty")]), ColorDepth::TrueColor);
+        assert_eq!(detect(&[("TERM", "xterm-direct")]), ColorDepth::TrueColor);
+        assert_eq!(
+            detect(&[("TERM", "xterm-256color"), ("TERM_PROGRAM", "iTerm.app")]),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            detect(&[("TERM", "xterm"), ("TERM_PROGRAM", "Apple_Terminal")]),
+            ColorDepth::Ansi256
+        );
+        assert_eq!(detect(&[("TERM", "xterm")]), ColorDepth::Ansi16);
+    }
+
+    #[test]
+    fn test_detect_no_color_and_force_color() {
+        assert_eq!(
+            detect(&[("TERM", "xterm-256color"), ("NO_COLOR", "1")]),
+            ColorDepth::NoColor
+        );
+        assert_eq!(
+            detect(&[("TERM", "xterm-256color"), ("NO_COLOR", "")]),
+            ColorDepth::Ansi256
+        );
+        assert_eq!(
+            detect(&[("TERM", "xterm-256color"), ("FORCE_COLOR", "0")]),
+            ColorDepth::NoColor
+        );
+        assert_eq!(
+            detect(&[("TERM", "dumb"), ("FORCE_COLOR", "1")]),
+            ColorDepth::Ansi16
+        );
+        assert_eq!(
+            detect(&[("TERM", "dumb"), ("FORCE_COLOR", "2")]),
+            ColorDepth::Ansi256
+        );
+        assert_eq!(
+            detect(&[("TERM", "dumb"), ("FORCE_COLOR", "3")]),
+            ColorDepth::TrueColor
+        );
+    }
+
+    #[test]
+    fn test_is_ansi_forced() {
+        let forced = |vars: &[(&str, &str)]| {
+            is_ansi_forced_from_env(|name| {
+                vars.iter()
+                    .find(|(k, _)| *k == name)
+                    .map(|(_, v)| v.to_string())
+            })
+        };
+        assert!(!forced(&[]));
+        assert!(forced(&[("FORCE_COLOR", "1")]));
+        assert!(forced(&[("FORCE_COLOR", "true")]));
+        assert!(!forced(&[("FORCE_COLOR", "0")]));
+        assert!(!forced(&[("FORCE_COLOR", "")]));
+        assert!(forced(&[("CLICOLOR_FORCE", "1")]));
+        assert!(!forced(&[("CLICOLOR_FORCE", "0")]));
+    }
+
+    #[test]
+    fn test_adapt() {
+        let orange = Color::Rgb {
+            r: 255,
+            g: 135,
+            b: 0,
+        };
+        assert_eq!(ColorDepth::TrueColor.adapt(orange), Some(orange));
+        assert_eq!(
+            ColorDepth::Ansi256.adapt(orange),
+            Some(Color::AnsiValue(208))
+        );
+        assert_eq!(ColorDepth::Ansi16.adapt(orange), Some(Color::DarkYellow));
+        assert_eq!(ColorDepth::NoColor.adapt(orange), None);
+
+        let gray = Color::Rgb {
+            r: 100,
+            g: 100,
+            b: 100,
+        };
+        assert_eq!(ColorDepth::Ansi256.adapt(gray), Some(Color::AnsiValue(241)));
+        assert_eq!(ColorDepth::Ansi16.adapt(gray), Some(Color::DarkGrey));
+
+        assert_eq!(
+            ColorDepth::Ansi16.adapt(Color::AnsiValue(21)),
+            Some(Color::DarkBlue)
+        );
+        assert_eq!(ColorDepth::Ansi16.adapt(Color::Cyan), Some(Color::Cyan));
+    }
+}
@@ -0,0 +1,316 @@
+use crate::{
+    component,
+    components::{MixedText, MixedTextContent, TextAlign, TextDecoration, TextWrap},
+    element,
+    strip_ansi::ANSI_REGEX,
+    AnyElement, Color, Props, Weight,
+};
+
+/// The props which can be passed to the [`AnsiText`] component.
+#[non_exhaustive]
+#[derive(Default, Props)]
+pub struct AnsiTextProps {
+    /// The content of the text, which may contain ANSI escape sequences.
+    pub content: String,
+
+    /// The color to make text which doesn't have a color set by an escape sequence.
+    pub color: Option<Color>,
+
+    /// The text wrapping behavior.
+    pub wrap: TextWrap,
+
+    /// The text alignment.
+    pub align: TextAlign,
+
+    /// The number of columns between tab stops. Defaults to 8.
+    pub tab_width: Option<usize>,
+}
+
+/// `AnsiText` is a component that renders text containing ANSI escape sequences, such as the
+/// colored output of other programs.
+///
+/// SGR sequences are used to style the text. This includes 16-color, 256-color, and RGB
+/// foreground and underline colors, as well as bold, faint, italic, underline, blink, invert,
+/// hidden, strikethrough, and overline attributes. OSC 8 hyperlinks are also supported. Background
+/// colors and all other escape sequences are ignored.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// # fn my_element() -> impl Into<AnyElement<'static>> {
+/// element! {
+///     AnsiText(content: "\x1b[1;32mCompiling\x1b[0m iocraft v0.1.0")
+/// }
+/// # }
+/// ```
+#[component]
+pub fn AnsiText(props: &AnsiTextProps) -> impl Into<AnyElement<'static>> {
+    let mut contents = parse_ansi(&props.content);
+    if let Some(color) = props.color {
+        for content in contents.iter_mut() {
+            content.color = content.color.or(Some(color));
+        }
+    }
+
+    element! {
+        MixedText(contents, wrap: props.wrap, align: props.align, tab_width: props.tab_width)
+    }
+}
+
+/// Splits text into spans, styled according to the SGR and OSC 8 sequences within it.
+fn parse_ansi(text: &str) -> Vec<MixedTextContent> {
+    let mut contents = Vec::new();
+    let mut style = MixedTextContent::default();
+    let mut last_end = 0;
+    for m in ANSI_REGEX.find_iter(text) {
+        push_span(&mut contents, &style, &text[last_end..m.start()]);
+        last_end = m.end();
+
+        let sequence = m.as_str();
+        if let Some(params) = sequence
+            .strip_prefix("\x1b[")
+            .or_else(|| sequence.strip_prefix('\u{9b}'))
+            .and_then(|s| s.strip_suffix('m'))
+        {
+            apply_sgr(&mut style, params);
+        } else if let Some(params) = sequence.strip_prefix("\x1b]8;") {
+            let params = params
+                .strip_suffix("\x1b\\")
+                .or_else(|| params.strip_suffix(['\x07', '\u{9c}']))
+                .unwrap_or(params);
+            let url = params.split_once(';').map_or("", |(_, url)| url);
+            style.link = (!url.is_empty()).then(|| url.to_string());
+        }
+    }
+    push_span(&mut contents, &style, &text[last_end..]);
+    contents
+}
+
+fn push_span(contents: &mut Vec<MixedTextContent>, style: &MixedTextContent, text: &str) {
+    if !text.is_empty() {
+        contents.push(MixedTextContent {
+            text: text.to_string(),
+            ..style.clone()
+        });
+    }
+}
+
+fn apply_sgr(style: &mut MixedTextContent, params: &str) {
+    let mut params = params.split(';');
+    while let Some(param) = params.next() {
+        let mut subparams = param.split(':');
+        let code: u16 = subparams.next().unwrap_or("").parse().unwrap_or(0);
+        let subparams = subparams.collect::<Vec<_>>();
+        match code {
+            0 => {
+                // Hyperlinks are set by OSC 8 rather than SGR, so a reset doesn't end them.
+                let link = style.link.take();
+                *style = MixedTextContent::default();
+                style.link = link;
+            }
+            1 => style.weight = Weight::Bold,
+            2 => style.weight = Weight::Light,
+            3 => style.italic = true,
+            4 => {
+                style.decoration = match subparams.first().copied() {
+                    Some("0") => TextDecoration::None,
+                    Some("2") => TextDecoration::DoubleUnderline,
+                    Some("3") => TextDecoration::CurlyUnderline,
+                    Some("4") => TextDecoration::DottedUnderline,
+                    Some("5") => TextDecoration::DashedUnderline,
+                    _ => TextDecoration::Underline,
+                }
+            }
+            5 | 6 => style.blink = true,
+            7 => style.invert = true,
+            8 => style.hidden = true,
+            9 => style.strikethrough = true,
+            21 => style.decoration = TextDecoration::DoubleUnderline,
+            22 => style.weight = Weight::Normal,
+            23 => style.italic = false,
+            24 => style.decoration = TextDecoration::None,
+            25 => style.blink = false,
+            27 => style.invert = false,
+            28 => style.hidden = false,
+            29 => style.strikethrough = false,
+            30..=37 => style.color = Some(ansi_color(code - 30)),
+            38 => style.color = extended_color(&subparams, &mut params),
+            39 => style.color = None,
+            48 => {
+                extended_color(&subparams, &mut params);
+            }
+            53 => style.overline = true,
+            55 => style.overline = false,
+            58 => style.underline_color = extended_color(&subparams, &mut params),
+            59 => style.underline_color = None,
+            90..=97 => style.color = Some(ansi_color(code - 90 + 8)),
+            _ => {}
+        }
+    }
+}
+
+/// Parses the color following a 38, 48, or 58 SGR code, in either the colon-separated form
+/// (e.g. `38:5:n`) or the semicolon-separated form (e.g. `38;5;n`).
+fn extended_color<'a>(
+    subparams: &[&str],
+    params: &mut impl Iterator<Item = &'a str>,
+) -> Option<Color> {
+    let component = |s: Option<&str>| s.and_then(|s| s.parse::<u8>().ok());
+    if !subparams.is_empty() {
+        return match subparams[0] {
+            "5" => component(subparams.get(1).copied()).map(Color::AnsiValue),
+            "2" => {
+                // The color space identifier is optional.
+                let rgb = &subparams[subparams.len().saturating_sub(3).max(1)..];
+                Some(Color::Rgb {
+                    r: component(rgb.first().copied())?,
+                    g: component(rgb.get(1).copied())?,
+                    b: component(rgb.get(2).copied())?,
+                })
+            }
+            _ => None,
+        };
+    }
+    match params.next() {
+        Some("5") => component(params.next()).map(Color::AnsiValue),
+        Some("2") => {
+            let (r, g, b) = (params.next(), params.next(), params.next());
+            Some(Color::Rgb {
+                r: component(r)?,
+                g: component(g)?,
+                b: component(b)?,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn ansi_color(index: u16) -> Color {
+    match index {
+        0 => Color::Black,
+        1 => Color::DarkRed,
+        2 => Color::DarkGreen,
+        3 => Color::DarkYellow,
+        4 => Color::DarkBlue,
+        5 => Color::DarkMagenta,
+        6 => Color::DarkCyan,
+        7 => Color::Grey,
+        8 => Color::DarkGrey,
+        9 => Color::Red,
+        10 => Color::Green,
+        11 => Color::Yellow,
+        12 => Color::Blue,
+        13 => Color::Magenta,
+        14 => Color::Cyan,
+        _ => Color::White,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn spans(text: &str) -> Vec<(String, Option<Color>, Weight)> {
+        parse_ansi(text)
+            .into_iter()
+            .map(|c| (c.text, c.color, c.weight))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_ansi() {
+        assert_eq!(spans(""), vec![]);
+        assert_eq!(spans("foo"), vec![("foo".into(), None, Weight::Normal)]);
+        assert_eq!(
+            spans("\x1b[1;32mfoo\x1b[0m bar \x1b[91mbaz\x1b[39;22m qux"),
+            vec![
+                ("foo".into(), Some(Color::DarkGreen), Weight::Bold),
+                (" bar ".into(), None, Weight::Normal),
+                ("baz".into(), Some(Color::Red), Weight::Normal),
+                (" qux".into(), None, Weight::Normal),
+            ]
+        );
+        assert_eq!(
+            spans("\x1b[38;5;208ma\x1b[38;2;1;2;3mb\x1b[38:2::4:5:6mc\x1b[38:5:9md\x1b[m"),
+            vec![
+                ("a".into(), Some(Color::AnsiValue(208)), Weight::Normal),
+                (
+                    "b".into(),
+                    Some(Color::Rgb { r: 1, g: 2, b: 3 }),
+                    Weight::Normal
+                ),
+                (
+                    "c".into(),
+                    Some(Color::Rgb { r: 4, g: 5, b: 6 }),
+                    Weight::Normal
+                ),
+                ("d".into(), Some(Color::AnsiValue(9)), Weight::Normal),
+            ]
+        );
+
+        // Background colors are skipped along with their parameters.
+        assert_eq!(
+            spans("\x1b[48;2;1;2;3;31ma\x1b[44;2mb"),
+            vec![
+                ("a".into(), Some(Color::DarkRed), Weight::Normal),
+                ("b".into(), Some(Color::DarkRed), Weight::Light),
+            ]
+        );
+
+        // Other escape sequences are ignored.
+        assert_eq!(
+            spans("\x1b[2Kfoo\x1b]0;title\x07"),
+            vec![("foo".into(), None, Weight::Normal)]
+        );
+
+        let contents = parse_ansi("\x1b[3;4:3;9;53;58;5;1ma\x1b[23;24;29;55;59mb");
+        assert!(contents[0].italic);
+        assert_eq!(contents[0].decoration, TextDecoration::CurlyUnderline);
+        assert!(contents[0].strikethrough);
+        assert!(contents[0].overline);
+        assert_eq!(contents[0].underline_color, Some(Color::AnsiValue(1)));
+        assert!(!contents[1].italic);
+        assert_eq!(contents[1].decoration, TextDecoration::None);
+        assert!(!contents[1].strikethrough);
+        assert!(!contents[1].overline);
+        assert_eq!(contents[1].underline_color, None);
+
+        let contents = parse_ansi("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\ text");
+        assert_eq!(contents[0].text, "link");
+        assert_eq!(contents[0].link.as_deref(), Some("https://example.com"));
+        assert_eq!(contents[1].link, None);
+
+        let contents =
+            parse_ansi("\x1b]8;;https://example.com\x1b\\\x1b[1mfoo\x1b[0m bar\x1b]8;;\x1b\\");
+        assert_eq!(contents[0].text, "foo");
+        assert_eq!(contents[0].weight, Weight::Bold);
+        assert_eq!(contents[1].text, " bar");
+        assert_eq!(contents[1].weight, Weight::Normal);
+        assert_eq!(contents[1].link.as_deref(), Some("https://example.com"));
+    }
+
+    #[test]
+    fn test_ansi_text() {
+        assert_eq!(
+            element! {
+                View(width: 10) {
+                    AnsiText(content: "\x1b[1;32mCompiling\x1b[0m foo v0.1.0")
+                }
+            }
+            .to_string(),
+            "Compiling\nfoo v0.1.0\n"
+        );
+
+        let canvas = element! {
+            AnsiText(content: "\x1b[31mfoo\x1b[0m bar", color: Color::Blue)
+        }
+        .render(None);
+        assert_eq!(canvas.to_string(), "foo bar\n");
+        let style = canvas.cell(0, 0).unwrap().text_style().unwrap();
+        assert_eq!(style.color, Some(Color::DarkRed));
+        let style = canvas.cell(4, 0).unwrap().text_style().unwrap();
+        assert_eq!(style.color, Some(Color::Blue));
+    }
+}
@@ -1,7 +1,10 @@
 use crate::{
-    component, components::View, element, hooks::UseTerminalEvents, AnyElement,
-    FullscreenMouseEvent, HandlerMut, Hooks, KeyCode, KeyEvent, KeyEventKind, MouseEventKind,
-    Props, TerminalEvent,
+    component,
+    components::View,
+    element,
+    hooks::{UseFocus, UseTerminalEvents},
+    AnyElement, FullscreenMouseEvent, HandlerMut, Hooks, KeyCode, KeyEvent, KeyEventKind,
+    MouseEventKind, Props, TerminalEvent,
 };
 
 /// The props which can be passed to the [`Button`] component.
@@ -16,11 +19,16 @@ pub struct ButtonProps<'a> {
     /// The button can be triggered two ways:
     ///
     /// - By clicking on it with the mouse while in fullscreen mode.
-    /// - By pressing the Enter or Space key while [`has_focus`](Self::has_focus) is `true`.
+    /// - By pressing the Enter or Space key while the button has focus.
     pub handler: HandlerMut<'static, ()>,
 
-    /// True if the button has focus and should process keyboard input.
+    /// True if the button has focus and should process keyboard input. Ignored if
+    /// [`focusable`](Self::focusable) is `true`.
     pub has_focus: bool,
+
+    /// If true, the button participates in automatic focus management via
+    /// [`UseFocus`](crate::hooks::UseFocus), and can be focused with the Tab key.
+    pub focusable: bool,
 }
 
 /// `Button` is a component that invokes a handler when clicked or when the Enter or Space key is pressed while it has focus.
@@ -41,9 +49,15 @@ pub struct ButtonProps<'a> {
 /// ```
 #[component]
 pub fn Button<'a>(mut hooks: Hooks, props: &mut ButtonProps<'a>) -> impl Into<AnyElement<'a>> {
+    let focus = hooks.use_focus_if(props.focusable);
+    let has_focus = if props.focusable {
+        focus.is_focused()
+    } else {
+        props.has_focus
+    };
+
     hooks.use_local_terminal_events({
         let mut handler = props.handler.take();
-        let has_focus = props.has_focus;
         move |event| match event {
             TerminalEvent::FullscreenMouse(FullscreenMouseEvent {
                 kind: MouseEventKind::Down(_),
@@ -0,0 +1,252 @@
+use crate::{
+    hooks::FocusScopeContext, AnyElement, Component, ComponentUpdater, Context, Hooks, Props,
+};
+
+/// The props which can be passed to the [`FocusScope`] component.
+#[non_exhaustive]
+#[derive(Default, Props)]
+pub struct FocusScopeProps<'a> {
+    /// The children of the component.
+    pub children: Vec<AnyElement<'a>>,
+
+    /// If true, Tab and Shift+Tab cycle through the scope's components without ever leaving it,
+    /// as is typical for modal dialogs. Otherwise, moving past the scope's first or last
+    /// component moves focus on to the enclosing scope's adjacent stop.
+    pub trap_focus: bool,
+}
+
+/// `FocusScope` is a component which groups its focusable descendants into an independent focus
+/// cycle.
+///
+/// Components registered via [`UseFocus`](crate::hooks::UseFocus) belong to the nearest enclosing
+/// scope. Components that aren't within any `FocusScope` belong to a root scope that the render
+/// loop provides automatically, so this component is only needed to isolate a group of components,
+/// such as the fields of a dialog.
+///
+/// Within its enclosing scope, a `FocusScope` is a single focus stop. Once it receives focus, Tab
+/// and Shift+Tab move through its own focusable components in tree order, then on to the enclosing
+/// scope's other stops. If the `trap_focus` prop is set, they instead cycle through the scope's
+/// components until focus is moved elsewhere programmatically, for example via
+/// [`FocusHandle::focus`](crate::hooks::FocusHandle::focus). Scopes which trap focus remember
+/// their focused component while they're inactive.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// # fn foo() -> impl Into<AnyElement<'static>> {
+/// element! {
+///     FocusScope(trap_focus: true) {
+///         View(flex_direction: FlexDirection::Column) {
+///             Button(handler: |_| { /* save */ }, focusable: true) {
+///                 Text(content: "Save")
+///             }
+///             Button(handler: |_| { /* cancel */ }, focusable: true) {
+///                 Text(content: "Cancel")
+///             }
+///         }
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Default)]
+pub struct FocusScope {
+    scope: Option<FocusScopeContext>,
+}
+
+impl Drop for FocusScope {
+    fn drop(&mut self) {
+        if let Some(scope) = &self.scope {
+            scope.remove();
+        }
+    }
+}
+
+impl Component for FocusScope {
+    type Props<'a> = FocusScopeProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self::default()
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: Hooks,
+        updater: &mut ComponentUpdater,
+    ) {
+        updater.set_transparent_layout(true);
+        let scope = self.scope.get_or_insert_with(|| {
+            updater
+                .get_context::<FocusScopeContext>()
+                .map(|parent| parent.new_nested())
+                .unwrap_or_default()
+        });
+        scope.begin_render(props.trap_focus);
+        updater.update_children(props.children.iter_mut(), Some(Context::from_ref(scope)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use futures::stream::StreamExt;
+    use macro_rules_attribute::apply;
+    use smol_macros::test;
+
+    #[derive(Default, Props)]
+    struct FieldProps {
+        label: String,
+    }
+
+    #[component]
+    fn Field(mut hooks: Hooks, props: &FieldProps) -> impl Into<AnyElement<'static>> {
+        let focus = hooks.use_focus();
+        element! {
+            Text(content: format!("{}{}", if focus.is_focused() { ">" } else { " " }, props.label))
+        }
+    }
+
+    #[test]
+    fn test_focus_scope() {
+        let actual = element! {
+            View(flex_direction: FlexDirection::Column) {
+                Field(label: "a")
+                FocusScope {
+                    View(flex_direction: FlexDirection::Column) {
+                        Field(label: "b")
+                        Field(label: "c")
+                    }
+                }
+            }
+        }
+        .to_string();
+        assert_eq!(actual, ">a\n b\n c\n");
+    }
+
+    #[derive(Default, Props)]
+    struct MyComponentProps {
+        trap_focus: bool,
+    }
+
+    #[component]
+    fn MyComponent(mut hooks: Hooks, props: &MyComponentProps) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let mut should_exit = hooks.use_state(|| false);
+
+        hooks.use_terminal_events(move |event| {
+            if let TerminalEvent::Key(KeyEvent {
+                code: KeyCode::Char('q'),
+                ..
+            }) = event
+            {
+                should_exit.set(true);
+            }
+        });
+
+        if should_exit.get() {
+            system.exit();
+        }
+
+        element! {
+            View(flex_direction: FlexDirection::Column) {
+                Field(label: "a")
+                FocusScope(trap_focus: props.trap_focus) {
+                    View(flex_direction: FlexDirection::Column) {
+                        Field(label: "b")
+                        Field(label: "c")
+                    }
+                }
+                Field(label: "d")
+            }
+        }
+    }
+
+    async fn render_after_keys(trap_focus: bool, codes: Vec<KeyCode>) -> String {
+        let events = codes
+            .into_iter()
+            .chain([KeyCode::Char('q')])
+            .map(|code| TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, code)))
+            .collect::<Vec<_>>();
+        element!(MyComponent(trap_focus))
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+                events,
+            )))
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await
+            .pop()
+            .unwrap()
+    }
+
+    #[apply(test!)]
+    async fn test_focus_scope_tab() {
+        use KeyCode::{BackTab, Tab};
+
+        assert_eq!(render_after_keys(false, vec![]).await, ">a\n b\n c\n d\n");
+
+        // The nested scope is a single stop in the root scope, whose components are visited in
+        // turn before moving on.
+        assert_eq!(
+            render_after_keys(false, vec![Tab]).await,
+            " a\n>b\n c\n d\n"
+        );
+        assert_eq!(
+            render_after_keys(false, vec![Tab, Tab]).await,
+            " a\n b\n>c\n d\n"
+        );
+        assert_eq!(
+            render_after_keys(false, vec![Tab, Tab, Tab]).await,
+            " a\n b\n c\n>d\n"
+        );
+        assert_eq!(
+            render_after_keys(false, vec![Tab, Tab, Tab, Tab]).await,
+            ">a\n b\n c\n d\n"
+        );
+
+        // Shift+Tab enters the nested scope at its last component.
+        assert_eq!(
+            render_after_keys(false, vec![BackTab]).await,
+            " a\n b\n c\n>d\n"
+        );
+        assert_eq!(
+            render_after_keys(false, vec![BackTab, BackTab]).await,
+            " a\n b\n>c\n d\n"
+        );
+        assert_eq!(
+            render_after_keys(false, vec![BackTab, BackTab, BackTab]).await,
+            " a\n>b\n c\n d\n"
+        );
+        assert_eq!(
+            render_after_keys(false, vec![BackTab, BackTab, BackTab, BackTab]).await,
+            ">a\n b\n c\n d\n"
+        );
+    }
+
+    #[apply(test!)]
+    async fn test_focus_scope_trap_focus() {
+        use KeyCode::{BackTab, Tab};
+
+        assert_eq!(render_after_keys(true, vec![Tab]).await, " a\n>b\n c\n d\n");
+
+        // Once it has focus, the nested scope handles traversal on its own.
+        assert_eq!(
+            render_after_keys(true, vec![Tab, Tab]).await,
+            " a\n b\n>c\n d\n"
+        );
+        assert_eq!(
+            render_after_keys(true, vec![Tab, Tab, Tab]).await,
+            " a\n>b\n c\n d\n"
+        );
+        assert_eq!(
+            render_after_keys(true, vec![Tab, BackTab]).await,
+            " a\n b\n>c\n d\n"
+        );
+
+        // Entering the scope resumes where it left off.
+        assert_eq!(
+            render_after_keys(true, vec![BackTab, BackTab]).await,
+            " a\n>b\n c\n d\n"
+        );
+    }
+}
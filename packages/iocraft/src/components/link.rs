@@ -0,0 +1,100 @@
+use crate::{
+    component,
+    components::{MixedText, MixedTextContent, TextAlign, TextDecoration, TextWrap},
+    element, AnyElement, Color, Props, Weight,
+};
+
+/// The props which can be passed to the [`Link`] component.
+#[non_exhaustive]
+#[derive(Default, Props)]
+pub struct LinkProps {
+    /// The URL to link to.
+    pub url: String,
+
+    /// The text to display. If empty, the URL itself is displayed.
+    pub content: String,
+
+    /// The color to make the text.
+    pub color: Option<Color>,
+
+    /// The weight of the text.
+    pub weight: Weight,
+
+    /// The text decoration.
+    pub decoration: TextDecoration,
+
+    /// Whether to italicize the text.
+    pub italic: bool,
+
+    /// The text wrapping behavior.
+    pub wrap: TextWrap,
+
+    /// The text alignment.
+    pub align: TextAlign,
+}
+
+/// `Link` is a component that renders text which links to a URL.
+///
+/// Links are emitted as OSC 8 hyperlinks, which most modern terminals render as clickable text.
+/// Terminals which don't support them simply display the text. When the output isn't written with
+/// ANSI escape codes, such as when rendering to a string, only the text is written.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// # fn my_element() -> impl Into<AnyElement<'static>> {
+/// element! {
+///     Link(
+///         url: "https://github.com/ccbrown/iocraft",
+///         content: "iocraft",
+///         color: Color::Blue,
+///         decoration: TextDecoration::Underline,
+///     )
+/// }
+/// # }
+/// ```
+#[component]
+pub fn Link(props: &LinkProps) -> impl Into<AnyElement<'static>> {
+    let text = if props.content.is_empty() {
+        &props.url
+    } else {
+        &props.content
+    };
+    let mut content = MixedTextContent::new(text)
+        .weight(props.weight)
+        .decoration(props.decoration)
+        .link(&props.url);
+    content.color = props.color;
+    content.italic = props.italic;
+
+    element! {
+        MixedText(contents: vec![content], wrap: props.wrap, align: props.align)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_link() {
+        assert_eq!(
+            element!(Link(url: "https://example.com")).to_string(),
+            "https://example.com\n"
+        );
+
+        let canvas = element! {
+            View(width: 20) {
+                Link(url: "https://example.com", content: "example")
+            }
+        }
+        .render(None);
+        assert_eq!(canvas.to_string(), "example\n");
+
+        let mut actual = Vec::new();
+        canvas.write_ansi(&mut actual).unwrap();
+        let actual = String::from_utf8(actual).unwrap();
+        assert!(actual.contains("\x1b]8;;https://example.com\x1b\\example\x1b]8;;\x1b\\"));
+    }
+}
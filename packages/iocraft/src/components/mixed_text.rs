@@ -1,9 +1,12 @@
 use crate::{
+    bidi,
     components::text::{Text, TextAlign, TextDecoration, TextDrawer, TextWrap},
     segmented_string::SegmentedString,
     strip_ansi::strip_ansi,
+    tabs::{expand_tabs, DEFAULT_TAB_WIDTH},
     CanvasTextStyle, Color, Component, ComponentDrawer, ComponentUpdater, Hooks, Props, Weight,
 };
+use std::{borrow::Cow, sync::Arc};
 
 /// A section of text in a [`MixedText`] component.
 #[non_exhaustive]
@@ -21,11 +24,29 @@ pub struct MixedTextContent {
     /// The text decoration.
     pub decoration: TextDecoration,
 
+    /// The color of the underline, if the text is underlined. Defaults to the color of the text.
+    pub underline_color: Option<Color>,
+
     /// Whether to italicize the text.
     pub italic: bool,
 
     /// Whether to invert the text's foreground and background colors.
     pub invert: bool,
+
+    /// Whether to draw a line through the text.
+    pub strikethrough: bool,
+
+    /// Whether to draw a line above the text.
+    pub overline: bool,
+
+    /// Whether to make the text blink.
+    pub blink: bool,
+
+    /// Whether to hide the text.
+    pub hidden: bool,
+
+    /// The URL that the text links to, if any.
+    pub link: Option<String>,
 }
 
 impl MixedTextContent {
@@ -55,6 +76,12 @@ impl MixedTextContent {
         self
     }
 
+    /// Returns a new [`MixedTextContent`] with the given underline color.
+    pub fn underline_color(mut self, color: Color) -> Self {
+        self.underline_color = Some(color);
+        self
+    }
+
     /// Returns a new [`MixedTextContent`] with italic text.
     pub fn italic(mut self) -> Self {
         self.italic = true;
@@ -66,6 +93,39 @@ impl MixedTextContent {
         self.invert = true;
         self
     }
+
+    /// Returns a new [`MixedTextContent`] with a line through the text.
+    pub fn strikethrough(mut self) -> Self {
+        self.strikethrough = true;
+        self
+    }
+
+    /// Returns a new [`MixedTextContent`] with a line above the text.
+    pub fn overline(mut self) -> Self {
+        self.overline = true;
+        self
+    }
+
+    /// Returns a new [`MixedTextContent`] with blinking text.
+    pub fn blink(mut self) -> Self {
+        self.blink = true;
+        self
+    }
+
+    /// Returns a new [`MixedTextContent`] with hidden text.
+    pub fn hidden(mut self) -> Self {
+        self.hidden = true;
+        self
+    }
+
+    /// Returns a new [`MixedTextContent`] which links to the given URL.
+    ///
+    /// Links are emitted as OSC 8 hyperlinks, which most modern terminals render as clickable
+    /// text. When the output isn't written with ANSI escape codes, only the text is written.
+    pub fn link<S: ToString>(mut self, url: S) -> Self {
+        self.link = Some(url.to_string());
+        self
+    }
 }
 
 /// The props which can be passed to the [`MixedText`] component.
@@ -80,6 +140,10 @@ pub struct MixedTextProps {
 
     /// The text alignment.
     pub align: TextAlign,
+
+    /// The number of columns between tab stops. Tab characters are replaced with spaces up to the
+    /// next tab stop. Defaults to 8.
+    pub tab_width: Option<usize>,
 }
 
 /// `MixedText` is a component that renders a text string containing a mix of styles.
@@ -128,13 +192,20 @@ impl Component for MixedText {
         for content in props.contents.iter_mut() {
             content.text = strip_ansi(&content.text).into_owned();
         }
-        let plaintext = props
+        self.contents = props.contents.clone();
+        let tab_width = props.tab_width.unwrap_or(DEFAULT_TAB_WIDTH);
+        let mut column = 0;
+        for content in self.contents.iter_mut() {
+            if let Cow::Owned(text) = expand_tabs(&content.text, tab_width, &mut column) {
+                content.text = text;
+            }
+        }
+        let plaintext = self
             .contents
             .iter()
             .map(|content| content.text.as_str())
             .collect::<Vec<_>>()
             .join("");
-        self.contents = props.contents.clone();
         self.wrap = props.wrap;
         self.align = props.align;
         updater.set_measure_func(Text::measure_func(plaintext, props.wrap));
@@ -147,23 +218,42 @@ impl Component for MixedText {
             .iter()
             .map(|content| content.text.as_str())
             .collect();
-        let lines = segmented_string.wrap(match self.wrap {
-            TextWrap::Wrap => width as usize,
-            TextWrap::NoWrap => usize::MAX,
-        });
+        let lines = match self.wrap.truncation_position() {
+            Some(position) => segmented_string.truncate(width as usize, position),
+            None => match self.wrap {
+                TextWrap::Wrap => segmented_string.wrap(width as usize),
+                TextWrap::BreakAnywhere => segmented_string.break_anywhere(width as usize),
+                _ => segmented_string.wrap(usize::MAX),
+            },
+        };
+
+        let lines = lines
+            .into_iter()
+            .map(|mut line| {
+                let line_width = line.width;
+                if matches!(self.wrap, TextWrap::Wrap | TextWrap::BreakAnywhere) {
+                    line.trim_end();
+                }
+                let texts = line.segments.iter().map(|s| s.text).collect::<Vec<_>>();
+                let visual_line = bidi::reorder_line(&texts);
+                let align = self.align.resolve(visual_line.is_rtl);
+                (line, visual_line, line_width, align)
+            })
+            .collect::<Vec<_>>();
 
         let paddings = lines
             .iter()
-            .map(|line| Text::alignment_padding(line.width, self.align, width as _))
+            .map(|(_, _, line_width, align)| {
+                Text::alignment_padding(*line_width, *align, width as _)
+            })
             .collect::<Vec<_>>();
         let x_offset = paddings.iter().copied().min().unwrap_or(0);
 
-        let mut drawer = TextDrawer::new(drawer, x_offset, self.align != TextAlign::Left);
-        for (mut line, padding) in lines.into_iter().zip(paddings) {
-            if self.wrap == TextWrap::Wrap {
-                line.trim_end();
-            }
-
+        let is_left_aligned = lines
+            .iter()
+            .all(|(_, _, _, align)| *align == TextAlign::Left);
+        let mut drawer = TextDrawer::new(drawer, x_offset, !is_left_aligned);
+        for ((line, visual_line, _, _), padding) in lines.iter().zip(paddings) {
             let additional_padding = padding - x_offset;
             if additional_padding > 0 {
                 drawer.append_lines(
@@ -171,20 +261,26 @@ impl Component for MixedText {
                     CanvasTextStyle::default(),
                 );
             }
-            let mut segments = line.segments.into_iter().peekable();
-            while let Some(segment) = segments.next() {
-                let content = &self.contents[segment.index];
+            let mut runs = visual_line.runs.iter().peekable();
+            while let Some((segment_index, text)) = runs.next() {
+                let content = &self.contents[line.segments[*segment_index].index];
                 let style = CanvasTextStyle {
                     color: content.color,
                     weight: content.weight,
-                    underline: content.decoration == TextDecoration::Underline,
+                    underline_style: content.decoration.underline_style(),
+                    underline_color: content.underline_color,
                     italic: content.italic,
                     invert: content.invert,
+                    strikethrough: content.strikethrough,
+                    overline: content.overline,
+                    blink: content.blink,
+                    hidden: content.hidden,
                 };
-                if segments.peek().is_some() {
-                    drawer.append_lines([segment.text], style);
+                let link = content.link.as_deref().map(Arc::from);
+                if runs.peek().is_some() {
+                    drawer.append_linked_lines([text.as_ref()], style, link.as_ref());
                 } else {
-                    drawer.append_lines([segment.text, ""], style);
+                    drawer.append_linked_lines([text.as_ref(), ""], style, link.as_ref());
                 }
             }
         }
@@ -213,6 +309,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mixed_text_bidi() {
+        let canvas = element! {
+            View(width: 12, flex_direction: FlexDirection::Column) {
+                MixedText(align: TextAlign::Start, contents: vec![
+                    MixedTextContent::new("שלום "),
+                    MixedTextContent::new("עולם").color(Color::Red),
+                ])
+            }
+        }
+        .render(None);
+        assert_eq!(canvas.to_string(), "   םלוע םולש\n");
+        assert_eq!(
+            canvas.cell(3, 0).unwrap().text_style().unwrap().color,
+            Some(Color::Red)
+        );
+        assert_eq!(canvas.cell(8, 0).unwrap().text_style().unwrap().color, None);
+    }
+
+    #[test]
+    fn test_mixed_text_tabs() {
+        assert_eq!(
+            element! {
+                MixedText(tab_width: 4, contents: vec![
+                    MixedTextContent::new("ab"),
+                    MixedTextContent::new("c\td").color(Color::Red),
+                    MixedTextContent::new("\te"),
+                ])
+            }
+            .to_string(),
+            "abc d   e\n"
+        );
+    }
+
+    #[test]
+    fn test_mixed_text_truncate() {
+        let canvas = element! {
+            View(width: 8) {
+                MixedText(wrap: TextWrap::Truncate, contents: vec![
+                    MixedTextContent::new("abcd"),
+                    MixedTextContent::new("efghijkl").color(Color::Red),
+                ])
+            }
+        }
+        .render(None);
+        assert_eq!(canvas.to_string(), "abcdefg…\n");
+        assert_eq!(
+            canvas.cell(7, 0).unwrap().text_style().unwrap().color,
+            Some(Color::Red)
+        );
+    }
+
     #[test]
     fn test_mixed_text_invert() {
         let canvas = element! {
@@ -224,6 +372,23 @@ mod tests {
         assert!(canvas.cell(0, 0).unwrap().text_style().unwrap().invert);
     }
 
+    #[test]
+    fn test_mixed_text_link() {
+        let canvas = element! {
+            MixedText(contents: vec![
+                MixedTextContent::new("see "),
+                MixedTextContent::new("docs").link("https://example.com"),
+            ])
+        }
+        .render(None);
+        assert_eq!(canvas.to_string(), "see docs\n");
+        assert_eq!(canvas.cell(0, 0).unwrap().link(), None);
+        assert_eq!(
+            canvas.cell(4, 0).unwrap().link(),
+            Some("https://example.com")
+        );
+    }
+
     #[test]
     fn test_mixed_text_strips_ansi() {
         assert_eq!(
@@ -1,15 +1,30 @@
+mod ansi_text;
+pub use ansi_text::*;
+
 mod button;
 pub use button::*;
 
 mod context_provider;
 pub use context_provider::*;
 
+mod focus_scope;
+pub use focus_scope::*;
+
 mod fragment;
 pub use fragment::*;
 
+mod link;
+pub use link::*;
+
 mod mixed_text;
 pub use mixed_text::*;
 
+mod select;
+pub use select::*;
+
+mod table;
+pub use table::*;
+
 mod text;
 pub use text::*;
 
@@ -21,3 +36,6 @@ pub use text_input::*;
 
 mod view;
 pub use view::*;
+
+mod virtual_list;
+pub use virtual_list::*;
@@ -2,11 +2,12 @@ use crate::{
     component,
     components::View,
     element,
-    hooks::{Ref, State, UseRef, UseState, UseTerminalEvents},
-    AnyElement, CanvasTextStyle, Color, Component, ComponentDrawer, ComponentUpdater,
-    FlexDirection, Hook, Hooks, JustifyContent, KeyCode, KeyEvent, KeyEventKind, MouseEventKind,
-    Overflow, Position, Props, TerminalEvent,
+    hooks::{Ref, State, UseMouseCapture, UseRef, UseSize, UseState, UseTerminalEvents},
+    AlignItems, AnyElement, CanvasTextStyle, Color, Component, ComponentDrawer, ComponentUpdater,
+    FlexBasis, FlexDirection, HandlerMut, Hook, Hooks, JustifyContent, KeyCode, KeyEvent,
+    KeyEventKind, KeyModifiers, MouseEventKind, Overflow, Position, Props, TerminalEvent,
 };
+use taffy::Size;
 
 /// A handle which can be used for imperative control of a [`ScrollView`] component.
 ///
@@ -38,14 +39,17 @@ use crate::{
 /// ```
 #[derive(Default)]
 pub struct ScrollViewHandle {
-    inner: Option<ScrollViewHandleInner>,
+    pub(crate) inner: Option<ScrollViewHandleInner>,
 }
 
-struct ScrollViewHandleInner {
-    scroll_offset: State<i32>,
-    content_height: State<u16>,
-    viewport_height: State<u16>,
-    user_scrolled_up: State<bool>,
+pub(crate) struct ScrollViewHandleInner {
+    pub scroll_offset: State<i32>,
+    pub scroll_offset_x: State<i32>,
+    pub content_height: State<u32>,
+    pub content_width: State<u16>,
+    pub viewport_height: State<u16>,
+    pub viewport_width: State<u16>,
+    pub user_scrolled_up: State<bool>,
 }
 
 impl ScrollViewHandle {
@@ -93,6 +97,25 @@ impl ScrollViewHandle {
         }
     }
 
+    /// Scrolls to the given offset in columns from the left. The offset is clamped to the valid
+    /// range.
+    pub fn scroll_to_x(&mut self, offset: i32) {
+        if let Some(inner) = &mut self.inner {
+            inner.scroll_offset_x.set(clamp_offset(
+                offset,
+                inner.content_width.get().into(),
+                inner.viewport_width.get(),
+            ));
+        }
+    }
+
+    /// Scrolls by the given number of columns (positive = right, negative = left). The resulting
+    /// offset is clamped to the valid range.
+    pub fn scroll_by_x(&mut self, delta: i32) {
+        let offset = self.scroll_offset_x() + delta;
+        self.scroll_to_x(offset);
+    }
+
     /// Returns the current scroll offset in lines from the top.
     pub fn scroll_offset(&self) -> i32 {
         self.inner
@@ -100,13 +123,36 @@ impl ScrollViewHandle {
             .map_or(0, |inner| inner.scroll_offset.get())
     }
 
-    /// Returns the total height of the scrollable content in lines.
+    /// Returns the current scroll offset in columns from the left.
+    pub fn scroll_offset_x(&self) -> i32 {
+        self.inner
+            .as_ref()
+            .map_or(0, |inner| inner.scroll_offset_x.get())
+    }
+
+    /// Returns the total height of the scrollable content in lines, saturating at `u16::MAX`.
+    ///
+    /// The content of a [`VirtualList`](crate::components::VirtualList) can be taller than that.
+    /// Use [`content_height_u32`](Self::content_height_u32) to get its full height.
     pub fn content_height(&self) -> u16 {
+        self.content_height_u32().min(u16::MAX as u32) as u16
+    }
+
+    /// Returns the total height of the scrollable content in lines.
+    pub fn content_height_u32(&self) -> u32 {
         self.inner
             .as_ref()
             .map_or(0, |inner| inner.content_height.get())
     }
 
+    /// Returns the total width of the scrollable content in columns. Content which fits within
+    /// the viewport is considered to be as wide as the viewport.
+    pub fn content_width(&self) -> u16 {
+        self.inner
+            .as_ref()
+            .map_or(0, |inner| inner.content_width.get())
+    }
+
     /// Returns the height of the visible viewport in lines.
     pub fn viewport_height(&self) -> u16 {
         self.inner
@@ -114,6 +160,13 @@ impl ScrollViewHandle {
             .map_or(0, |inner| inner.viewport_height.get())
     }
 
+    /// Returns the width of the visible viewport in columns.
+    pub fn viewport_width(&self) -> u16 {
+        self.inner
+            .as_ref()
+            .map_or(0, |inner| inner.viewport_width.get())
+    }
+
     /// Returns whether auto scroll is currently pinned to the bottom.
     pub fn is_auto_scroll_pinned(&self) -> bool {
         self.inner
@@ -127,31 +180,71 @@ impl ScrollViewHandle {
     }
 }
 
-fn max_offset(content_height: u16, viewport_height: u16) -> i32 {
-    (content_height as i32 - viewport_height as i32).max(0)
+pub(crate) fn max_offset(content_size: u32, viewport_size: u16) -> i32 {
+    (content_size as i64 - viewport_size as i64).clamp(0, i32::MAX as i64) as i32
 }
 
-fn clamp_offset(offset: i32, content_height: u16, viewport_height: u16) -> i32 {
-    offset.clamp(0, max_offset(content_height, viewport_height))
+pub(crate) fn clamp_offset(offset: i32, content_size: u32, viewport_size: u16) -> i32 {
+    offset.clamp(0, max_offset(content_size, viewport_size))
 }
 
-const DEFAULT_SCROLL_STEP: u16 = 3;
+pub(crate) const DEFAULT_SCROLL_STEP: u16 = 3;
 
 // -- Scrollbar component --
 
-#[derive(Default, Props)]
-struct ScrollViewScrollbarProps {
-    viewport_height: u16,
-    content_height: u16,
+// Returns the position and size of the scrollbar thumb, or `None` if no scrollbar is needed.
+fn thumb_geometry(
+    viewport_size: u16,
+    content_size: u32,
     scroll_offset: i32,
-    thumb_color: Option<Color>,
-    track_color: Option<Color>,
+) -> Option<(usize, usize)> {
+    let vs = viewport_size as usize;
+    let cs = content_size as usize;
+    if vs == 0 || cs <= vs {
+        return None;
+    }
+
+    let thumb_size = (vs * vs / cs).max(1);
+    let max_off = max_offset(content_size, viewport_size);
+    let thumb_pos = if max_off > 0 {
+        (scroll_offset.max(0) as usize * (vs - thumb_size)) / max_off as usize
+    } else {
+        0
+    };
+    Some((thumb_pos, thumb_size))
+}
+
+// Returns the scroll offset which centers the scrollbar thumb on the given position of the track.
+fn offset_for_thumb_position(position: u16, viewport_size: u16, content_size: u32) -> i32 {
+    let Some((_, thumb_size)) = thumb_geometry(viewport_size, content_size, 0) else {
+        return 0;
+    };
+    let track = viewport_size as usize - thumb_size;
+    if track == 0 {
+        return 0;
+    }
+    let thumb_pos = (position as usize)
+        .saturating_sub(thumb_size / 2)
+        .min(track);
+    (thumb_pos * max_offset(content_size, viewport_size) as usize / track) as i32
+}
+
+#[derive(Default, Props)]
+pub(crate) struct ScrollViewScrollbarProps {
+    pub horizontal: bool,
+    pub viewport_size: u16,
+    pub content_size: u32,
+    pub scroll_offset: i32,
+    pub thumb_color: Option<Color>,
+    pub track_color: Option<Color>,
+    pub on_scroll: HandlerMut<'static, i32>,
 }
 
 #[derive(Default)]
-struct ScrollViewScrollbar {
-    viewport_height: u16,
-    content_height: u16,
+pub(crate) struct ScrollViewScrollbar {
+    horizontal: bool,
+    viewport_size: u16,
+    content_size: u32,
     scroll_offset: i32,
     thumb_color: Option<Color>,
     track_color: Option<Color>,
@@ -167,37 +260,71 @@ impl Component for ScrollViewScrollbar {
     fn update(
         &mut self,
         props: &mut Self::Props<'_>,
-        _hooks: Hooks,
+        mut hooks: Hooks,
         updater: &mut ComponentUpdater,
     ) {
-        self.viewport_height = props.viewport_height;
-        self.content_height = props.content_height;
+        // Clicking the track jumps to that position, and the thumb can be dragged. The mouse is
+        // captured during the drag so that it keeps working once the pointer leaves the track.
+        let mut capture = hooks.use_mouse_capture();
+        hooks.use_local_terminal_events({
+            let horizontal = props.horizontal;
+            let viewport_size = props.viewport_size;
+            let content_size = props.content_size;
+            let mut on_scroll = props.on_scroll.take();
+            move |event| {
+                let TerminalEvent::FullscreenMouse(event) = event else {
+                    return;
+                };
+                match event.kind {
+                    MouseEventKind::Down(_) => capture.capture(),
+                    MouseEventKind::Drag(_) if capture.is_captured() => {}
+                    MouseEventKind::Up(_) => {
+                        capture.release();
+                        return;
+                    }
+                    _ => return,
+                }
+                let position = if horizontal { event.column } else { event.row };
+                on_scroll(offset_for_thumb_position(
+                    position,
+                    viewport_size,
+                    content_size,
+                ));
+            }
+        });
+
+        self.horizontal = props.horizontal;
+        self.viewport_size = props.viewport_size;
+        self.content_size = props.content_size;
         self.scroll_offset = props.scroll_offset;
         self.thumb_color = props.thumb_color;
         self.track_color = props.track_color;
 
-        updater.set_layout_style(taffy::style::Style {
-            size: taffy::geometry::Size {
-                width: taffy::style::Dimension::Length(1.0),
-                height: taffy::style::Dimension::Percent(1.0),
-            },
-            ..Default::default()
+        updater.set_layout_style(if self.horizontal {
+            taffy::style::Style {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Auto,
+                    height: taffy::style::Dimension::Length(1.0),
+                },
+                flex_grow: 1.0,
+                ..Default::default()
+            }
+        } else {
+            taffy::style::Style {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Length(1.0),
+                    height: taffy::style::Dimension::Percent(1.0),
+                },
+                ..Default::default()
+            }
         });
     }
 
     fn draw(&mut self, drawer: &mut ComponentDrawer<'_>) {
-        let vh = self.viewport_height as usize;
-        let ch = self.content_height as usize;
-        if vh == 0 || ch <= vh {
+        let Some((thumb_pos, thumb_size)) =
+            thumb_geometry(self.viewport_size, self.content_size, self.scroll_offset)
+        else {
             return;
-        }
-
-        let thumb_size = (vh * vh / ch).max(1);
-        let max_off = (ch - vh) as i32;
-        let thumb_pos = if max_off > 0 {
-            (self.scroll_offset as usize * (vh - thumb_size)) / max_off as usize
-        } else {
-            0
         };
 
         let thumb_color = self.thumb_color.unwrap_or(Color::White);
@@ -212,11 +339,22 @@ impl Component for ScrollViewScrollbar {
         };
 
         let mut canvas = drawer.canvas();
-        for y in 0..vh {
-            if y >= thumb_pos && y < thumb_pos + thumb_size {
-                canvas.set_text(0, y as isize, "\u{2503}", thumb_style); // ┃
+        for i in 0..self.viewport_size as usize {
+            let is_thumb = i >= thumb_pos && i < thumb_pos + thumb_size;
+            if self.horizontal {
+                if is_thumb {
+                    // ━
+                    canvas.set_text(i as isize, 0, "\u{2501}", thumb_style);
+                } else {
+                    // ─
+                    canvas.set_text(i as isize, 0, "\u{2500}", track_style);
+                }
+            } else if is_thumb {
+                // ┃
+                canvas.set_text(0, i as isize, "\u{2503}", thumb_style);
             } else {
-                canvas.set_text(0, y as isize, "\u{2502}", track_style); // │
+                // │
+                canvas.set_text(0, i as isize, "\u{2502}", track_style);
             }
         }
     }
@@ -235,7 +373,8 @@ pub struct ScrollViewProps<'a> {
     pub scroll_step: Option<u16>,
     /// An optional handle which can be used for imperative control of the scroll view.
     pub handle: Option<Ref<ScrollViewHandle>>,
-    /// Whether to show a scrollbar. Defaults to `true`.
+    /// Whether to show scrollbars. Defaults to `true`. Each scrollbar is only shown when the
+    /// content overflows the viewport in its direction.
     pub scrollbar: Option<bool>,
     /// Optional color for the scrollbar thumb. Defaults to `White`.
     pub scrollbar_thumb_color: Option<Color>,
@@ -247,26 +386,15 @@ pub struct ScrollViewProps<'a> {
     pub keyboard_scroll: Option<bool>,
 }
 
-// Hook that measures the component height in pre_component_draw and writes
-// the result to a State<u16>.
-struct MeasureHeightHook {
-    out: State<u16>,
-}
-
-impl Hook for MeasureHeightHook {
-    fn pre_component_draw(&mut self, drawer: &mut ComponentDrawer) {
-        let h = drawer.size().height;
-        if self.out.try_get() != Some(h) {
-            self.out.set(h);
-        }
-    }
-}
-
 /// `ScrollView` is a component that provides scrollable content with keyboard and mouse support.
 ///
 /// Place it inside a container with a fixed height. The scroll view will clip its children and
 /// allow scrolling through them using arrow keys, Page Up/Down, Home/End, and mouse wheel.
 ///
+/// Content which is wider than the scroll view, such as a wide table or unwrapped log lines, can
+/// be scrolled horizontally using the Left/Right keys, Shift + mouse wheel, or a horizontal mouse
+/// wheel. Scrollbars can be clicked or dragged to jump to a position.
+///
 /// # Example
 ///
 /// ```
@@ -288,25 +416,73 @@ pub fn ScrollView<'a>(
     props: &mut ScrollViewProps<'a>,
 ) -> impl Into<AnyElement<'a>> {
     let mut scroll_offset = hooks.use_state(|| 0i32);
+    let mut scroll_offset_x = hooks.use_state(|| 0i32);
     let mut user_scrolled_up = hooks.use_state(|| false);
-    let mut content_height: State<u16> = hooks.use_state(|| 0u16);
-    let viewport_height: State<u16> = hooks.use_state(|| 0u16);
-    let content_height_ref: Ref<u16> = hooks.use_ref(|| 0u16);
-
-    // Measure the viewport (this component's) height.
-    let h = hooks.use_hook(move || MeasureHeightHook {
-        out: viewport_height,
-    });
-    h.out = viewport_height;
+    let mut content_height: State<u32> = hooks.use_state(|| 0u32);
+    let mut content_width: State<u16> = hooks.use_state(|| 0u16);
+    let mut viewport_height: State<u16> = hooks.use_state(|| 0u16);
+    let mut viewport_width: State<u16> = hooks.use_state(|| 0u16);
+    let size: State<Size<u16>> = hooks.use_size();
+    let content_size_ref: Ref<Size<u16>> = hooks.use_ref(Size::default);
+    let mut did_show_vertical_scrollbar: Ref<bool> = hooks.use_ref(|| false);
 
     let scroll_step = props.scroll_step.unwrap_or(DEFAULT_SCROLL_STEP) as i32;
     let auto_scroll = props.auto_scroll;
     let keyboard_scroll = props.keyboard_scroll.unwrap_or(true);
-
-    // Sync content height from the ref written by the measurer child.
-    let ch = content_height_ref.get();
-    if content_height.get() != ch {
-        content_height.set(ch);
+    let scrollbar = props.scrollbar.unwrap_or(true);
+
+    // The content is never measured as narrower than the viewport it was laid out in, so it only
+    // overflows horizontally if it was measured as wider than that.
+    let measured_content_size = content_size_ref.get();
+    let measured_viewport_width = size
+        .get()
+        .width
+        .saturating_sub(did_show_vertical_scrollbar.get() as u16);
+    let content_overflows_x = measured_content_size.width > measured_viewport_width;
+
+    // Determine which scrollbars are needed. Each one takes space away from the viewport, which
+    // may cause the content to overflow in the other direction.
+    let mut show_vertical_scrollbar = scrollbar && measured_content_size.height > size.get().height;
+    let show_horizontal_scrollbar = scrollbar
+        && content_overflows_x
+        && measured_content_size.width
+            > size
+                .get()
+                .width
+                .saturating_sub(show_vertical_scrollbar as u16);
+    show_vertical_scrollbar = scrollbar
+        && measured_content_size.height
+            > size
+                .get()
+                .height
+                .saturating_sub(show_horizontal_scrollbar as u16);
+    did_show_vertical_scrollbar.set(show_vertical_scrollbar);
+
+    // Sync the content and viewport sizes.
+    let vh = size
+        .get()
+        .height
+        .saturating_sub(show_horizontal_scrollbar as u16);
+    let vw = size
+        .get()
+        .width
+        .saturating_sub(show_vertical_scrollbar as u16);
+    let cw = if content_overflows_x {
+        measured_content_size.width
+    } else {
+        vw
+    };
+    if content_height.get() != measured_content_size.height as u32 {
+        content_height.set(measured_content_size.height as u32);
+    }
+    if content_width.get() != cw {
+        content_width.set(cw);
+    }
+    if viewport_height.get() != vh {
+        viewport_height.set(vh);
+    }
+    if viewport_width.get() != vw {
+        viewport_width.set(vw);
     }
 
     // Wire up the handle.
@@ -314,8 +490,11 @@ pub fn ScrollView<'a>(
         handle_ref.set(ScrollViewHandle {
             inner: Some(ScrollViewHandleInner {
                 scroll_offset,
+                scroll_offset_x,
                 content_height,
+                content_width,
                 viewport_height,
+                viewport_width,
                 user_scrolled_up,
             }),
         });
@@ -335,43 +514,64 @@ pub fn ScrollView<'a>(
             scroll_offset.set(clamped);
         }
     }
+    let clamped_x = clamp_offset(
+        scroll_offset_x.get(),
+        content_width.get().into(),
+        viewport_width.get(),
+    );
+    if scroll_offset_x.get() != clamped_x {
+        scroll_offset_x.set(clamped_x);
+    }
 
     hooks.use_terminal_events({
-        let vh = viewport_height;
         move |event| {
-            let delta = match &event {
+            let (delta, delta_x) = match &event {
                 TerminalEvent::Key(KeyEvent { code, kind, .. })
                     if *kind != KeyEventKind::Release =>
                 {
                     if keyboard_scroll {
                         match code {
-                            KeyCode::Up => Some(-1),
-                            KeyCode::Down => Some(1),
-                            KeyCode::PageUp => Some(-(vh.get() as i32).max(1)),
-                            KeyCode::PageDown => Some((vh.get() as i32).max(1)),
-                            KeyCode::Home => Some(i32::MIN / 2),
-                            KeyCode::End => Some(i32::MAX / 2),
-                            _ => None,
+                            KeyCode::Up => (Some(-1), None),
+                            KeyCode::Down => (Some(1), None),
+                            KeyCode::Left => (None, Some(-1)),
+                            KeyCode::Right => (None, Some(1)),
+                            KeyCode::PageUp => (Some(-(viewport_height.get() as i32).max(1)), None),
+                            KeyCode::PageDown => {
+                                (Some((viewport_height.get() as i32).max(1)), None)
+                            }
+                            KeyCode::Home => (Some(i32::MIN / 2), None),
+                            KeyCode::End => (Some(i32::MAX / 2), None),
+                            _ => (None, None),
                         }
                     } else {
-                        None
+                        (None, None)
                     }
                 }
-                TerminalEvent::FullscreenMouse(mouse) => match mouse.kind {
-                    MouseEventKind::ScrollUp => Some(-scroll_step),
-                    MouseEventKind::ScrollDown => Some(scroll_step),
-                    _ => None,
-                },
-                _ => None,
+                TerminalEvent::FullscreenMouse(mouse) => {
+                    let shift = mouse.modifiers.contains(KeyModifiers::SHIFT);
+                    match mouse.kind {
+                        MouseEventKind::ScrollUp if shift => (None, Some(-scroll_step)),
+                        MouseEventKind::ScrollDown if shift => (None, Some(scroll_step)),
+                        MouseEventKind::ScrollUp => (Some(-scroll_step), None),
+                        MouseEventKind::ScrollDown => (Some(scroll_step), None),
+                        MouseEventKind::ScrollLeft => (None, Some(-scroll_step)),
+                        MouseEventKind::ScrollRight => (None, Some(scroll_step)),
+                        _ => (None, None),
+                    }
+                }
+                _ => (None, None),
             };
 
             if let Some(delta) = delta {
-                let new_offset =
-                    clamp_offset(scroll_offset.get() + delta, content_height.get(), vh.get());
+                let new_offset = clamp_offset(
+                    scroll_offset.get() + delta,
+                    content_height.get(),
+                    viewport_height.get(),
+                );
                 scroll_offset.set(new_offset);
 
                 if auto_scroll {
-                    let max = max_offset(content_height.get(), vh.get());
+                    let max = max_offset(content_height.get(), viewport_height.get());
                     if delta < 0 {
                         user_scrolled_up.set(true);
                     } else if new_offset >= max {
@@ -379,12 +579,34 @@ pub fn ScrollView<'a>(
                     }
                 }
             }
+
+            if let Some(delta_x) = delta_x {
+                scroll_offset_x.set(clamp_offset(
+                    scroll_offset_x.get() + delta_x,
+                    content_width.get().into(),
+                    viewport_width.get(),
+                ));
+            }
         }
     });
 
+    let on_scroll = move |offset: i32| {
+        let max = max_offset(content_height.get(), viewport_height.get());
+        let offset = offset.clamp(0, max);
+        scroll_offset.set(offset);
+        if auto_scroll {
+            user_scrolled_up.set(offset < max);
+        }
+    };
+    let on_scroll_x = move |offset: i32| {
+        scroll_offset_x.set(clamp_offset(
+            offset,
+            content_width.get().into(),
+            viewport_width.get(),
+        ));
+    };
+
     let children = std::mem::take(&mut props.children);
-    let show_scrollbar =
-        props.scrollbar.unwrap_or(true) && content_height.get() > viewport_height.get();
     let scrollbar_thumb_color = props.scrollbar_thumb_color;
     let scrollbar_track_color = props.scrollbar_track_color;
 
@@ -394,124 +616,116 @@ pub fn ScrollView<'a>(
         scroll_offset.get()
     };
 
-    if pinned_to_bottom {
-        if show_scrollbar {
-            element! {
-                View(width: 100pct, height: 100pct, flex_direction: FlexDirection::Row) {
-                    View(
-                        overflow: Overflow::Hidden,
-                        flex_grow: 1.0,
-                        height: 100pct,
-                        flex_direction: FlexDirection::Column,
-                        justify_content: JustifyContent::FlexEnd,
-                    ) {
-                        ScrollViewContentMeasurer(
-                            content_height_ref: Some(content_height_ref),
-                        ) {
-                            #(children)
-                        }
-                    }
-                    ScrollViewScrollbar(
-                        viewport_height: viewport_height.get(),
-                        content_height: content_height.get(),
-                        scroll_offset: effective_offset,
-                        thumb_color: scrollbar_thumb_color,
-                        track_color: scrollbar_track_color,
-                    )
-                }
-            }
-        } else {
-            element! {
-                View(
-                    overflow: Overflow::Hidden,
-                    width: 100pct,
-                    height: 100pct,
-                    flex_direction: FlexDirection::Column,
-                    justify_content: JustifyContent::FlexEnd,
-                ) {
-                    ScrollViewContentMeasurer(
-                        content_height_ref: Some(content_height_ref),
-                    ) {
-                        #(children)
-                    }
-                }
-            }
+    let content = element! {
+        ScrollViewContentMeasurer(
+            content_size_ref: Some(content_size_ref),
+            scroll_offset_x: scroll_offset_x.get(),
+        ) {
+            #(children)
         }
-    } else if show_scrollbar {
+    };
+
+    let pane = if pinned_to_bottom {
         element! {
-            View(width: 100pct, height: 100pct, flex_direction: FlexDirection::Row) {
-                View(overflow: Overflow::Hidden, flex_grow: 1.0, height: 100pct) {
-                    View(position: Position::Absolute, top: -scroll_offset.get(), width: 100pct) {
-                        ScrollViewContentMeasurer(
-                            content_height_ref: Some(content_height_ref),
-                        ) {
-                            #(children)
-                        }
-                    }
+            View(
+                overflow: Overflow::Hidden,
+                flex_grow: 1.0,
+                flex_basis: FlexBasis::Length(0),
+                min_width: 0,
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::FlexEnd,
+                align_items: AlignItems::FlexStart,
+            ) {
+                View(min_width: 100pct) {
+                    #(content)
                 }
-                ScrollViewScrollbar(
-                    viewport_height: viewport_height.get(),
-                    content_height: content_height.get(),
-                    scroll_offset: effective_offset,
-                    thumb_color: scrollbar_thumb_color,
-                    track_color: scrollbar_track_color,
-                )
             }
         }
     } else {
         element! {
-            View(overflow: Overflow::Hidden, width: 100pct, height: 100pct) {
-                View(position: Position::Absolute, top: -scroll_offset.get(), width: 100pct) {
-                    ScrollViewContentMeasurer(
-                        content_height_ref: Some(content_height_ref),
-                    ) {
-                        #(children)
-                    }
+            View(overflow: Overflow::Hidden, flex_grow: 1.0) {
+                View(position: Position::Absolute, top: -scroll_offset.get(), min_width: 100pct) {
+                    #(content)
                 }
             }
         }
+    };
+
+    element! {
+        View(width: 100pct, height: 100pct, flex_direction: FlexDirection::Column) {
+            View(flex_grow: 1.0, overflow: Overflow::Hidden) {
+                #(pane)
+                #(show_vertical_scrollbar.then(|| element! {
+                    ScrollViewScrollbar(
+                        viewport_size: viewport_height.get(),
+                        content_size: content_height.get(),
+                        scroll_offset: effective_offset,
+                        thumb_color: scrollbar_thumb_color,
+                        track_color: scrollbar_track_color,
+                        on_scroll,
+                    )
+                }))
+            }
+            #(show_horizontal_scrollbar.then(|| element! {
+                View(flex_direction: FlexDirection::Row) {
+                    ScrollViewScrollbar(
+                        horizontal: true,
+                        viewport_size: viewport_width.get(),
+                        content_size: content_width.get() as u32,
+                        scroll_offset: scroll_offset_x.get(),
+                        thumb_color: scrollbar_thumb_color,
+                        track_color: scrollbar_track_color,
+                        on_scroll: on_scroll_x,
+                    )
+                    #(show_vertical_scrollbar.then(|| element!(View(width: 1))))
+                }
+            }))
+        }
     }
 }
 
 #[derive(Default, Props)]
-struct ScrollViewContentMeasurerProps<'a> {
-    children: Vec<AnyElement<'a>>,
-    content_height_ref: Option<Ref<u16>>,
+pub(crate) struct ScrollViewContentMeasurerProps<'a> {
+    pub children: Vec<AnyElement<'a>>,
+    pub content_size_ref: Option<Ref<Size<u16>>>,
+    pub scroll_offset_x: i32,
 }
 
-// Hook that measures this component's height and writes it to a Ref<u16>
+// Hook that measures this component's size and writes it to a Ref<Size<u16>>
 // shared with the parent ScrollView.
-struct ContentHeightHook {
-    out: Option<Ref<u16>>,
+struct ContentSizeHook {
+    out: Option<Ref<Size<u16>>>,
 }
 
-impl Hook for ContentHeightHook {
+impl Hook for ContentSizeHook {
     fn pre_component_draw(&mut self, drawer: &mut ComponentDrawer) {
         if let Some(mut out) = self.out {
-            let h = drawer.size().height;
-            if out.try_get() != Some(h) {
-                out.set(h);
+            let size = drawer.size();
+            if out.try_get() != Some(size) {
+                out.set(size);
             }
         }
     }
 }
 
 /// Private component that lives inside the scroll pane so that
-/// its `pre_component_draw` size equals the natural content height.
+/// its `pre_component_draw` size equals the natural content size.
 #[component]
-fn ScrollViewContentMeasurer<'a>(
+pub(crate) fn ScrollViewContentMeasurer<'a>(
     mut hooks: Hooks,
     props: &mut ScrollViewContentMeasurerProps<'a>,
 ) -> impl Into<AnyElement<'a>> {
-    let content_height_ref = props.content_height_ref;
-    let h = hooks.use_hook(move || ContentHeightHook {
-        out: content_height_ref,
+    let content_size_ref = props.content_size_ref;
+    let h = hooks.use_hook(move || ContentSizeHook {
+        out: content_size_ref,
     });
-    h.out = content_height_ref;
+    h.out = content_size_ref;
 
+    // The content fills the pane's width, or more if it's wider. It's offset relative to its
+    // layout position so that horizontal scrolling doesn't affect the width it's laid out in.
     let children = std::mem::take(&mut props.children);
     element! {
-        View(width: 100pct) {
+        View(flex_grow: 1.0, position: Position::Relative, left: -props.scroll_offset_x) {
             #(children)
         }
     }
@@ -520,6 +734,7 @@ fn ScrollViewContentMeasurer<'a>(
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
+    use crossterm::event::MouseButton;
     use futures::stream::{self, StreamExt};
     use macro_rules_attribute::apply;
     use smol_macros::test;
@@ -592,6 +807,112 @@ mod tests {
         assert!(!output.contains("Line 0"));
     }
 
+    #[apply(test!)]
+    async fn test_scroll_view_scrollbar_drag() {
+        let canvases: Vec<_> = element!(TestScrollView)
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(stream::iter(vec![
+                TerminalEvent::FullscreenMouse(FullscreenMouseEvent::new(
+                    MouseEventKind::Down(MouseButton::Left),
+                    19,
+                    1,
+                )),
+                // The drag continues below the scroll view.
+                TerminalEvent::FullscreenMouse(FullscreenMouseEvent::new(
+                    MouseEventKind::Drag(MouseButton::Left),
+                    15,
+                    40,
+                )),
+                TerminalEvent::FullscreenMouse(FullscreenMouseEvent::new(
+                    MouseEventKind::Up(MouseButton::Left),
+                    15,
+                    40,
+                )),
+                TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, KeyCode::Char('q'))),
+            ])))
+            .collect()
+            .await;
+
+        let output = canvases.last().unwrap().to_string();
+        assert!(output.contains("Line 19"));
+        assert!(!output.contains("Line 14"));
+    }
+
+    #[component]
+    fn WideScrollView(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let mut done = hooks.use_state(|| false);
+        let mut handle = hooks.use_ref_default::<ScrollViewHandle>();
+
+        hooks.use_terminal_events(move |event| match event {
+            TerminalEvent::Key(KeyEvent {
+                code: KeyCode::Char('q'),
+                kind: KeyEventKind::Press,
+                ..
+            }) => done.set(true),
+            TerminalEvent::Key(KeyEvent {
+                code: KeyCode::Char('h'),
+                kind: KeyEventKind::Press,
+                ..
+            }) => handle.write().scroll_to_x(0),
+            _ => {}
+        });
+
+        if done.get() {
+            system.exit();
+        }
+
+        let lines = (0..20)
+            .map(|i| format!("Line {i} {}", "abcdefghij".repeat(3)))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        element! {
+            View(width: 20, height: 5) {
+                ScrollView(handle) {
+                    Text(content: lines, wrap: TextWrap::NoWrap)
+                }
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_scroll_view_horizontal_scroll() {
+        let canvases: Vec<_> = element!(WideScrollView)
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(stream::iter(vec![
+                TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, KeyCode::Right)),
+                TerminalEvent::FullscreenMouse(FullscreenMouseEvent {
+                    modifiers: KeyModifiers::SHIFT,
+                    ..FullscreenMouseEvent::new(MouseEventKind::ScrollDown, 0, 0)
+                }),
+                TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, KeyCode::Char('q'))),
+            ])))
+            .collect()
+            .await;
+
+        let output = canvases.last().unwrap().to_string();
+        assert!(output.contains(" 0 abcdefghij"));
+        assert!(!output.contains("Line 0"));
+        // A horizontal scrollbar is shown since the content is wider than the viewport.
+        assert!(output.contains('\u{2501}'));
+        assert!(output.contains('\u{2500}'));
+    }
+
+    #[apply(test!)]
+    async fn test_scroll_view_handle_scroll_to_x() {
+        let canvases: Vec<_> = element!(WideScrollView)
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(stream::iter(vec![
+                TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, KeyCode::Right)),
+                TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, KeyCode::Right)),
+                TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, KeyCode::Char('h'))),
+                TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, KeyCode::Char('q'))),
+            ])))
+            .collect()
+            .await;
+
+        let output = canvases.last().unwrap().to_string();
+        assert!(output.contains("Line 0"));
+    }
+
     #[apply(test!)]
     async fn test_scroll_view_content_shorter_than_viewport() {
         #[component]
@@ -0,0 +1,1027 @@
+use crate::{
+    component,
+    components::{Text, TextWrap, View},
+    element,
+    hooks::{State, UseFocus, UseSize, UseState, UseTerminalEvents},
+    AnyElement, Color, FlexDirection, FullscreenMouseEvent, HandlerMut, Hooks, KeyCode, KeyEvent,
+    KeyEventKind, KeyModifiers, MouseEventKind, Overflow, Props, TerminalEvent,
+};
+use crossterm::event::MouseButton;
+use taffy::Size;
+use unicode_width::UnicodeWidthStr;
+
+/// An item which can be chosen from a [`Select`] or [`MultiSelect`].
+///
+/// Strings can be converted into items without descriptions.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SelectItem {
+    /// The text displayed for the item, and matched against when filtering.
+    pub label: String,
+
+    /// Optional text displayed, dimmed, below the label.
+    pub description: Option<String>,
+}
+
+impl SelectItem {
+    /// Creates a new item with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            description: None,
+        }
+    }
+
+    /// Sets the description of the item.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    fn height(&self) -> u16 {
+        1 + self.description.is_some() as u16
+    }
+}
+
+impl From<&str> for SelectItem {
+    fn from(label: &str) -> Self {
+        Self::new(label)
+    }
+}
+
+impl From<String> for SelectItem {
+    fn from(label: String) -> Self {
+        Self::new(label)
+    }
+}
+
+/// Returns the indices of the items whose labels contain the query, ignoring case.
+fn matching_items(items: &[SelectItem], query: &str) -> Vec<usize> {
+    let query = query.to_lowercase();
+    items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| item.label.to_lowercase().contains(&query))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Returns the position which results from a navigation key, or `None` if the key doesn't
+/// navigate.
+fn navigate(
+    code: KeyCode,
+    position: Option<usize>,
+    len: usize,
+    page: usize,
+    wrap_around: bool,
+) -> Option<usize> {
+    let last = len.checked_sub(1)?;
+    let Some(position) = position else {
+        return match code {
+            KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown | KeyCode::Home => {
+                Some(0)
+            }
+            KeyCode::End => Some(last),
+            _ => None,
+        };
+    };
+    match code {
+        KeyCode::Up if position == 0 => Some(if wrap_around { last } else { 0 }),
+        KeyCode::Up => Some(position - 1),
+        KeyCode::Down if position >= last => Some(if wrap_around { 0 } else { last }),
+        KeyCode::Down => Some(position + 1),
+        KeyCode::PageUp => Some(position.saturating_sub(page)),
+        KeyCode::PageDown => Some((position + page).min(last)),
+        KeyCode::Home => Some(0),
+        KeyCode::End => Some(last),
+        _ => None,
+    }
+}
+
+/// Returns the index of the first visible row, given the heights of all rows, such that the row
+/// at `position` is visible and the available height is filled where possible.
+fn scroll_offset(offset: usize, position: Option<usize>, heights: &[u16], available: u32) -> usize {
+    let height_of = |range: &[u16]| range.iter().map(|h| *h as u32).sum::<u32>();
+    let mut offset = offset.min(heights.len().saturating_sub(1));
+    if let Some(position) = position {
+        if position < offset {
+            offset = position;
+        }
+        while offset < position && height_of(&heights[offset..=position]) > available {
+            offset += 1;
+        }
+    }
+    // Scroll back if the rows no longer fill the available height.
+    while offset > 0 && height_of(&heights[offset - 1..]) <= available {
+        offset -= 1;
+    }
+    offset
+}
+
+/// The layout of a list of items, after filtering and scrolling.
+struct ListLayout {
+    /// The indices of the items which match the filter.
+    matches: Vec<usize>,
+    /// The position of the highlighted item within `matches`.
+    position: Option<usize>,
+    /// The indices and heights of the items which are visible, in order.
+    visible: Vec<(usize, u16)>,
+    /// The number of rows above the items.
+    first_row: u16,
+}
+
+impl ListLayout {
+    fn new(
+        items: &[SelectItem],
+        query: &str,
+        highlighted: Option<usize>,
+        mut offset: State<usize>,
+        viewport_height: u16,
+    ) -> Self {
+        let matches = matching_items(items, query);
+        let position = highlighted.and_then(|item| matches.iter().position(|&i| i == item));
+        let first_row = (!query.is_empty()) as u16;
+        let available = match viewport_height {
+            // Until the list has been measured, display every item.
+            0 => u32::MAX,
+            height => height.saturating_sub(first_row).max(1) as u32,
+        };
+
+        let heights: Vec<u16> = matches.iter().map(|&i| items[i].height()).collect();
+        let new_offset = scroll_offset(offset.get(), position, &heights, available);
+        if offset.get() != new_offset {
+            offset.set(new_offset);
+        }
+
+        let mut visible = Vec::new();
+        let mut used = 0;
+        for (&item, &height) in matches.iter().zip(&heights).skip(new_offset) {
+            if !visible.is_empty() && used + height as u32 > available {
+                break;
+            }
+            used += height as u32;
+            visible.push((item, height));
+        }
+
+        Self {
+            matches,
+            position,
+            visible,
+            first_row,
+        }
+    }
+}
+
+/// The result of an event handled by a [`ListNavigator`].
+enum ListAction {
+    /// The highlighted item changed.
+    Highlight(usize),
+    /// The item was clicked.
+    Click(usize),
+    /// The item was toggled with the Space key.
+    Toggle(usize),
+    /// Enter was pressed.
+    Submit,
+}
+
+/// The state of a list of items as seen by its event handler, updated as events arrive so that
+/// events delivered in the same batch see each other's effects.
+struct ListNavigator {
+    items: Vec<SelectItem>,
+    query: String,
+    matches: Vec<usize>,
+    position: Option<usize>,
+    visible: Vec<(usize, u16)>,
+    first_row: u16,
+    filterable: bool,
+    wrap_around: bool,
+    space_toggles: bool,
+}
+
+impl ListNavigator {
+    fn highlighted(&self) -> Option<usize> {
+        self.position.map(|p| self.matches[p])
+    }
+
+    fn set_query(&mut self, query: String, query_state: &mut State<String>) -> Option<ListAction> {
+        let highlighted = self.highlighted();
+        self.matches = matching_items(&self.items, &query);
+        self.position = highlighted
+            .and_then(|item| self.matches.iter().position(|&i| i == item))
+            .or((!self.matches.is_empty()).then_some(0));
+        self.query = query;
+        query_state.set(self.query.clone());
+        let new_highlighted = self.highlighted();
+        match new_highlighted {
+            Some(item) if new_highlighted != highlighted => Some(ListAction::Highlight(item)),
+            _ => None,
+        }
+    }
+
+    fn handle_event(
+        &mut self,
+        event: TerminalEvent,
+        query_state: &mut State<String>,
+    ) -> Option<ListAction> {
+        match event {
+            TerminalEvent::Key(KeyEvent {
+                code,
+                kind,
+                modifiers,
+            }) if kind != KeyEventKind::Release
+                && !modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                let page = self.visible.len().max(1);
+                if let Some(position) = navigate(
+                    code,
+                    self.position,
+                    self.matches.len(),
+                    page,
+                    self.wrap_around,
+                ) {
+                    let changed = self.position != Some(position);
+                    self.position = Some(position);
+                    return changed.then(|| ListAction::Highlight(self.matches[position]));
+                }
+                match code {
+                    KeyCode::Enter => Some(ListAction::Submit),
+                    KeyCode::Char(' ') if self.space_toggles => {
+                        self.highlighted().map(ListAction::Toggle)
+                    }
+                    KeyCode::Char(c) if self.filterable => {
+                        let mut query = self.query.clone();
+                        query.push(c);
+                        self.set_query(query, query_state)
+                    }
+                    KeyCode::Backspace if self.filterable && !self.query.is_empty() => {
+                        let mut query = self.query.clone();
+                        query.pop();
+                        self.set_query(query, query_state)
+                    }
+                    KeyCode::Esc if self.filterable && !self.query.is_empty() => {
+                        self.set_query(String::new(), query_state)
+                    }
+                    _ => None,
+                }
+            }
+            TerminalEvent::FullscreenMouse(FullscreenMouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                row,
+                ..
+            }) => {
+                let mut top = self.first_row;
+                for &(item, height) in &self.visible {
+                    if row >= top && row < top + height {
+                        self.position = self.matches.iter().position(|&i| i == item);
+                        return Some(ListAction::Click(item));
+                    }
+                    top += height;
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The colors used to draw a list of items.
+struct ListColors {
+    color: Option<Color>,
+    highlight_color: Color,
+    description_color: Color,
+}
+
+/// Returns the element for a list of items. The marker for each item is drawn before its label,
+/// and its description is indented to match.
+fn list_element(
+    items: &[SelectItem],
+    layout: &ListLayout,
+    query: &str,
+    colors: ListColors,
+    marker: impl Fn(usize) -> String,
+) -> AnyElement<'static> {
+    let highlighted = layout.position.map(|p| layout.matches[p]);
+    element! {
+        View(flex_direction: FlexDirection::Column, width: 100pct, height: 100pct, overflow: Overflow::Hidden) {
+            #((!query.is_empty()).then(|| element! {
+                View(flex_shrink: 0.0) {
+                    Text(content: format!("/{query}"), color: colors.description_color, wrap: TextWrap::NoWrap)
+                }
+            }))
+            #((layout.matches.is_empty() && !items.is_empty()).then(|| element! {
+                View(flex_shrink: 0.0) {
+                    Text(content: "No matches", color: colors.description_color, wrap: TextWrap::NoWrap)
+                }
+            }))
+            #(layout.visible.iter().map(|&(i, _)| {
+                let item = &items[i];
+                let marker = marker(i);
+                let indent = marker.width() as u32;
+                let color = if highlighted == Some(i) { Some(colors.highlight_color) } else { colors.color };
+                element! {
+                    View(flex_direction: FlexDirection::Column, flex_shrink: 0.0) {
+                        Text(content: format!("{marker}{}", item.label), color, wrap: TextWrap::Truncate)
+                        #(item.description.as_ref().map(|description| element! {
+                            View(padding_left: indent) {
+                                Text(content: description, color: colors.description_color, wrap: TextWrap::Truncate)
+                            }
+                        }))
+                    }
+                }
+            }))
+        }
+    }
+    .into_any()
+}
+
+/// The state shared by [`Select`] and [`MultiSelect`].
+struct ListState {
+    query: State<String>,
+    scroll_offset: State<usize>,
+    size: State<Size<u16>>,
+}
+
+fn use_list_state(hooks: &mut Hooks) -> ListState {
+    let query = hooks.use_state(String::new);
+    let scroll_offset = hooks.use_state(|| 0usize);
+    let size = hooks.use_size();
+    ListState {
+        query,
+        scroll_offset,
+        size,
+    }
+}
+
+/// The props which can be passed to the [`Select`] component.
+#[non_exhaustive]
+#[derive(Default, Props)]
+pub struct SelectProps {
+    /// The items which can be chosen.
+    pub items: Vec<SelectItem>,
+
+    /// The index of the chosen item within `items`.
+    pub value: usize,
+
+    /// The handler to invoke with the index of an item when it's chosen, either by moving to it
+    /// with the keyboard or by clicking on it.
+    pub on_change: HandlerMut<'static, usize>,
+
+    /// The handler to invoke with the index of the chosen item when Enter is pressed, or when an
+    /// item is clicked.
+    pub on_submit: HandlerMut<'static, usize>,
+
+    /// True if the select has focus and should process keyboard input. Ignored if
+    /// [`focusable`](Self::focusable) is `true`.
+    pub has_focus: bool,
+
+    /// If true, the select participates in automatic focus management via
+    /// [`UseFocus`](crate::hooks::UseFocus), and can be focused with the Tab key or by clicking
+    /// on an item.
+    pub focusable: bool,
+
+    /// If true, moving past the last item moves to the first, and vice versa.
+    pub wrap_around: bool,
+
+    /// Whether typing filters the items. Defaults to `true`.
+    pub filterable: Option<bool>,
+
+    /// The color of the items.
+    pub color: Option<Color>,
+
+    /// The color of the chosen item. Defaults to blue.
+    pub highlight_color: Option<Color>,
+
+    /// The color of item descriptions and the filter. Defaults to dark gray.
+    pub description_color: Option<Color>,
+}
+
+/// `Select` is a component which lets the user choose one item from a list.
+///
+/// It will fill the available width. If the items don't fit within the height of its container,
+/// the list is scrolled to keep the chosen item visible.
+///
+/// # Key Bindings
+///
+/// While the select has focus:
+///
+/// - Up and Down move to the previous and next items, Page Up and Page Down move by a page, and
+///   Home and End move to the first and last items.
+/// - Enter submits the chosen item.
+/// - Typing filters the items to those whose labels contain the typed text, ignoring case.
+///   Backspace removes the last character of the filter, and Esc clears it.
+///
+/// In fullscreen mode, left-clicking on an item chooses and submits it.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// # #[component]
+/// # fn MyComponent(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+/// let mut value = hooks.use_state(|| 0);
+///
+/// element! {
+///     View(height: 5) {
+///         Select(
+///             items: vec![
+///                 SelectItem::new("Rust").description("Fast and reliable"),
+///                 "Go".into(),
+///                 "Zig".into(),
+///             ],
+///             value: value.get(),
+///             on_change: move |new_value| value.set(new_value),
+///             on_submit: |value| { /* do something */ },
+///         )
+///     }
+/// }
+/// # }
+/// ```
+#[component]
+pub fn Select(mut hooks: Hooks, props: &mut SelectProps) -> impl Into<AnyElement<'static>> {
+    let mut focus = hooks.use_focus_if(props.focusable);
+    let has_focus = if props.focusable {
+        focus.is_focused()
+    } else {
+        props.has_focus
+    };
+    let ListState {
+        mut query,
+        scroll_offset,
+        size,
+    } = use_list_state(&mut hooks);
+
+    let highlighted = (props.value < props.items.len()).then_some(props.value);
+    let layout = ListLayout::new(
+        &props.items,
+        &query.read(),
+        highlighted,
+        scroll_offset,
+        size.get().height,
+    );
+
+    hooks.use_local_terminal_events({
+        let mut navigator = ListNavigator {
+            items: props.items.clone(),
+            query: query.to_string(),
+            matches: layout.matches.clone(),
+            position: layout.position,
+            visible: layout.visible.clone(),
+            first_row: layout.first_row,
+            filterable: props.filterable.unwrap_or(true),
+            wrap_around: props.wrap_around,
+            space_toggles: false,
+        };
+        let mut value = highlighted;
+        let mut on_change = props.on_change.take();
+        let mut on_submit = props.on_submit.take();
+        move |event| {
+            let is_mouse_event = matches!(event, TerminalEvent::FullscreenMouse(_));
+            if !has_focus && !is_mouse_event {
+                return;
+            }
+            match navigator.handle_event(event, &mut query) {
+                Some(ListAction::Highlight(item)) => {
+                    value = Some(item);
+                    on_change(item);
+                }
+                Some(ListAction::Click(item)) => {
+                    focus.focus();
+                    if value != Some(item) {
+                        value = Some(item);
+                        on_change(item);
+                    }
+                    on_submit(item);
+                }
+                Some(ListAction::Submit) => {
+                    if let Some(item) = value.filter(|_| navigator.position.is_some()) {
+                        on_submit(item);
+                    }
+                }
+                Some(ListAction::Toggle(_)) | None => {}
+            }
+        }
+    });
+
+    list_element(
+        &props.items,
+        &layout,
+        &query.to_string(),
+        ListColors {
+            color: props.color,
+            highlight_color: props.highlight_color.unwrap_or(Color::Blue),
+            description_color: props.description_color.unwrap_or(Color::DarkGrey),
+        },
+        |i| if highlighted == Some(i) { "❯ " } else { "  " }.to_string(),
+    )
+}
+
+/// The props which can be passed to the [`MultiSelect`] component.
+#[non_exhaustive]
+#[derive(Default, Props)]
+pub struct MultiSelectProps {
+    /// The items which can be chosen.
+    pub items: Vec<SelectItem>,
+
+    /// The indices of the chosen items within `items`.
+    pub values: Vec<usize>,
+
+    /// The handler to invoke with the indices of the chosen items, in ascending order, when an
+    /// item is checked or unchecked.
+    pub on_change: HandlerMut<'static, Vec<usize>>,
+
+    /// The handler to invoke with the indices of the chosen items, in ascending order, when Enter
+    /// is pressed.
+    pub on_submit: HandlerMut<'static, Vec<usize>>,
+
+    /// True if the select has focus and should process keyboard input. Ignored if
+    /// [`focusable`](Self::focusable) is `true`.
+    pub has_focus: bool,
+
+    /// If true, the select participates in automatic focus management via
+    /// [`UseFocus`](crate::hooks::UseFocus), and can be focused with the Tab key or by clicking
+    /// on an item.
+    pub focusable: bool,
+
+    /// If true, moving past the last item moves to the first, and vice versa.
+    pub wrap_around: bool,
+
+    /// Whether typing filters the items. Defaults to `true`.
+    pub filterable: Option<bool>,
+
+    /// The color of the items.
+    pub color: Option<Color>,
+
+    /// The color of the highlighted item. Defaults to blue.
+    pub highlight_color: Option<Color>,
+
+    /// The color of item descriptions and the filter. Defaults to dark gray.
+    pub description_color: Option<Color>,
+}
+
+/// `MultiSelect` is a component which lets the user choose any number of items from a list, each
+/// of which is displayed with a checkbox.
+///
+/// It will fill the available width. If the items don't fit within the height of its container,
+/// the list is scrolled to keep the highlighted item visible.
+///
+/// # Key Bindings
+///
+/// While the select has focus:
+///
+/// - Up and Down move to the previous and next items, Page Up and Page Down move by a page, and
+///   Home and End move to the first and last items.
+/// - Space checks or unchecks the highlighted item.
+/// - Enter submits the checked items.
+/// - Typing filters the items to those whose labels contain the typed text, ignoring case.
+///   Backspace removes the last character of the filter, and Esc clears it.
+///
+/// In fullscreen mode, left-clicking on an item checks or unchecks it.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// # #[component]
+/// # fn MyComponent(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+/// let mut values = hooks.use_state(Vec::new);
+///
+/// element! {
+///     MultiSelect(
+///         items: vec!["Cheese".into(), "Pepperoni".into(), "Mushrooms".into()],
+///         values: values.read().clone(),
+///         on_change: move |new_values| values.set(new_values),
+///         on_submit: |values| { /* do something */ },
+///     )
+/// }
+/// # }
+/// ```
+#[component]
+pub fn MultiSelect(
+    mut hooks: Hooks,
+    props: &mut MultiSelectProps,
+) -> impl Into<AnyElement<'static>> {
+    let mut focus = hooks.use_focus_if(props.focusable);
+    let has_focus = if props.focusable {
+        focus.is_focused()
+    } else {
+        props.has_focus
+    };
+    let ListState {
+        mut query,
+        scroll_offset,
+        size,
+    } = use_list_state(&mut hooks);
+    let mut cursor = hooks.use_state(|| 0usize);
+
+    let highlighted = (cursor.get() < props.items.len()).then(|| cursor.get());
+    let layout = ListLayout::new(
+        &props.items,
+        &query.read(),
+        highlighted,
+        scroll_offset,
+        size.get().height,
+    );
+
+    hooks.use_local_terminal_events({
+        let mut navigator = ListNavigator {
+            items: props.items.clone(),
+            query: query.to_string(),
+            matches: layout.matches.clone(),
+            position: layout.position,
+            visible: layout.visible.clone(),
+            first_row: layout.first_row,
+            filterable: props.filterable.unwrap_or(true),
+            wrap_around: props.wrap_around,
+            space_toggles: true,
+        };
+        let mut values = props.values.clone();
+        values.sort_unstable();
+        values.dedup();
+        let mut on_change = props.on_change.take();
+        let mut on_submit = props.on_submit.take();
+        move |event| {
+            let is_mouse_event = matches!(event, TerminalEvent::FullscreenMouse(_));
+            if !has_focus && !is_mouse_event {
+                return;
+            }
+            let toggled = match navigator.handle_event(event, &mut query) {
+                Some(ListAction::Highlight(item)) => {
+                    cursor.set(item);
+                    None
+                }
+                Some(ListAction::Click(item)) => {
+                    focus.focus();
+                    cursor.set(item);
+                    Some(item)
+                }
+                Some(ListAction::Toggle(item)) => Some(item),
+                Some(ListAction::Submit) => {
+                    on_submit(values.clone());
+                    None
+                }
+                None => None,
+            };
+            if let Some(item) = toggled {
+                match values.binary_search(&item) {
+                    Ok(i) => {
+                        values.remove(i);
+                    }
+                    Err(i) => values.insert(i, item),
+                }
+                on_change(values.clone());
+            }
+        }
+    });
+
+    list_element(
+        &props.items,
+        &layout,
+        &query.to_string(),
+        ListColors {
+            color: props.color,
+            highlight_color: props.highlight_color.unwrap_or(Color::Blue),
+            description_color: props.description_color.unwrap_or(Color::DarkGrey),
+        },
+        |i| {
+            let pointer = if highlighted == Some(i) { "❯" } else { " " };
+            let checkbox = if props.values.contains(&i) {
+                "[x]"
+            } else {
+                "[ ]"
+            };
+            format!("{pointer} {checkbox} ")
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use futures::stream::StreamExt;
+    use indoc::indoc;
+    use macro_rules_attribute::apply;
+    use smol_macros::test;
+
+    fn items() -> Vec<SelectItem> {
+        vec![
+            SelectItem::new("Rust").description("Fast and reliable"),
+            "Go".into(),
+            "Zig".into(),
+            "Ruby".into(),
+        ]
+    }
+
+    fn key(code: KeyCode) -> TerminalEvent {
+        TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, code))
+    }
+
+    #[test]
+    fn test_matching_items() {
+        assert_eq!(matching_items(&items(), ""), vec![0, 1, 2, 3]);
+        assert_eq!(matching_items(&items(), "ru"), vec![0, 3]);
+        assert_eq!(matching_items(&items(), "G"), vec![1, 2]);
+        assert!(matching_items(&items(), "x").is_empty());
+    }
+
+    #[test]
+    fn test_navigate() {
+        assert_eq!(navigate(KeyCode::Down, Some(0), 4, 2, false), Some(1));
+        assert_eq!(navigate(KeyCode::Down, Some(3), 4, 2, false), Some(3));
+        assert_eq!(navigate(KeyCode::Down, Some(3), 4, 2, true), Some(0));
+        assert_eq!(navigate(KeyCode::Up, Some(0), 4, 2, false), Some(0));
+        assert_eq!(navigate(KeyCode::Up, Some(0), 4, 2, true), Some(3));
+        assert_eq!(navigate(KeyCode::PageDown, Some(1), 4, 2, false), Some(3));
+        assert_eq!(navigate(KeyCode::PageUp, Some(1), 4, 2, false), Some(0));
+        assert_eq!(navigate(KeyCode::End, Some(1), 4, 2, false), Some(3));
+        assert_eq!(navigate(KeyCode::Home, Some(2), 4, 2, false), Some(0));
+        assert_eq!(navigate(KeyCode::Down, None, 4, 2, false), Some(0));
+        assert_eq!(navigate(KeyCode::Down, None, 0, 2, false), None);
+        assert_eq!(navigate(KeyCode::Enter, Some(1), 4, 2, false), None);
+    }
+
+    #[test]
+    fn test_scroll_offset() {
+        let heights = [2, 1, 1, 1, 1];
+        assert_eq!(scroll_offset(0, Some(0), &heights, 3), 0);
+        assert_eq!(scroll_offset(0, Some(2), &heights, 3), 1);
+        assert_eq!(scroll_offset(1, Some(4), &heights, 3), 2);
+        assert_eq!(scroll_offset(3, Some(1), &heights, 3), 1);
+        // Scroll back when there's room for more rows.
+        assert_eq!(scroll_offset(4, None, &heights, 3), 2);
+        assert_eq!(scroll_offset(2, Some(3), &heights, u32::MAX), 0);
+    }
+
+    #[test]
+    fn test_select() {
+        assert_eq!(
+            element! {
+                View(width: 20) {
+                    Select(items: items(), value: 1usize)
+                }
+            }
+            .to_string(),
+            indoc! {"
+                  Rust
+                  Fast and reliable
+                ❯ Go
+                  Zig
+                  Ruby
+            "},
+        );
+    }
+
+    #[test]
+    fn test_multi_select() {
+        let canvas = element! {
+            View(width: 24) {
+                MultiSelect(items: items(), values: vec![0, 2])
+            }
+        }
+        .render(None);
+        assert_eq!(
+            canvas.to_string(),
+            indoc! {"
+                ❯ [x] Rust
+                      Fast and reliable
+                  [ ] Go
+                  [x] Zig
+                  [ ] Ruby
+            "},
+        );
+        let style = canvas.cell(0, 0).unwrap().text_style().unwrap();
+        assert_eq!(style.color, Some(Color::Blue));
+        let style = canvas.cell(6, 1).unwrap().text_style().unwrap();
+        assert_eq!(style.color, Some(Color::DarkGrey));
+    }
+
+    #[derive(Default, Props)]
+    struct MySelectProps {
+        expected_submission: usize,
+        wrap_around: bool,
+    }
+
+    #[component]
+    fn MySelect(mut hooks: Hooks, props: &MySelectProps) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let mut value = hooks.use_state(|| 0);
+        let mut submitted = hooks.use_state(|| None);
+
+        if let Some(submitted) = submitted.get() {
+            assert_eq!(submitted, props.expected_submission);
+            assert_eq!(value.get(), submitted);
+            system.exit();
+        }
+
+        element! {
+            View(width: 20, height: 3) {
+                Select(
+                    items: items(),
+                    value: value.get(),
+                    on_change: move |new_value| value.set(new_value),
+                    on_submit: move |value| submitted.set(Some(value)),
+                    has_focus: true,
+                    wrap_around: props.wrap_around,
+                )
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_select_key_input() {
+        let actual = element!(MySelect(expected_submission: 2usize))
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+                vec![
+                    key(KeyCode::Down),
+                    key(KeyCode::End),
+                    key(KeyCode::Down),
+                    key(KeyCode::Up),
+                    key(KeyCode::Enter),
+                ],
+            )))
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(actual.last().unwrap(), "  Go\n❯ Zig\n  Ruby\n");
+    }
+
+    #[apply(test!)]
+    async fn test_select_wrap_around() {
+        let actual = element!(MySelect(expected_submission: 3usize, wrap_around: true))
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+                vec![key(KeyCode::Up), key(KeyCode::Enter)],
+            )))
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(actual.last().unwrap(), "  Go\n  Zig\n❯ Ruby\n");
+    }
+
+    #[apply(test!)]
+    async fn test_select_filter() {
+        let actual = element!(MySelect(expected_submission: 3usize))
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+                vec![
+                    key(KeyCode::Char('z')),
+                    key(KeyCode::Backspace),
+                    key(KeyCode::Char('r')),
+                    key(KeyCode::Char('U')),
+                    key(KeyCode::Down),
+                    key(KeyCode::Enter),
+                ],
+            )))
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        // Only Ruby fits below the filter, since Rust has a description.
+        assert_eq!(actual.last().unwrap(), "/rU\n❯ Ruby\n\n");
+    }
+
+    #[apply(test!)]
+    async fn test_select_click() {
+        let actual = element!(MySelect(expected_submission: 1usize))
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::once(
+                async {
+                    TerminalEvent::FullscreenMouse(FullscreenMouseEvent::new(
+                        MouseEventKind::Down(MouseButton::Left),
+                        3,
+                        2,
+                    ))
+                },
+            )))
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(
+            actual.last().unwrap(),
+            "  Rust\n  Fast and reliable\n❯ Go\n"
+        );
+    }
+
+    #[component]
+    fn MyFocusableSelects(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let mut a = hooks.use_state(|| 0);
+        let mut b = hooks.use_state(|| 0);
+        let mut submissions = hooks.use_state(Vec::new);
+
+        if submissions.read().len() == 2 {
+            assert_eq!(*submissions.read(), vec!["b1", "b2"]);
+            assert_eq!(a.get(), 0);
+            system.exit();
+        }
+
+        let items = || -> Vec<SelectItem> { vec!["x".into(), "y".into(), "z".into()] };
+        element! {
+            View(width: 20, flex_direction: FlexDirection::Column) {
+                View(height: 3) {
+                    Select(
+                        items: items(),
+                        value: a.get(),
+                        on_change: move |new_value| a.set(new_value),
+                        on_submit: move |value| submissions.write().push(format!("a{}", value)),
+                        focusable: true,
+                    )
+                }
+                View(height: 3) {
+                    Select(
+                        items: items(),
+                        value: b.get(),
+                        on_change: move |new_value| b.set(new_value),
+                        on_submit: move |value| submissions.write().push(format!("b{}", value)),
+                        focusable: true,
+                    )
+                }
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_select_click_focus() {
+        // Only left clicks select items, and clicking an item focuses its select.
+        let events = vec![
+            TerminalEvent::FullscreenMouse(FullscreenMouseEvent::new(
+                MouseEventKind::Down(MouseButton::Right),
+                0,
+                5,
+            )),
+            TerminalEvent::FullscreenMouse(FullscreenMouseEvent::new(
+                MouseEventKind::Down(MouseButton::Left),
+                0,
+                4,
+            )),
+            key(KeyCode::Down),
+            key(KeyCode::Enter),
+        ];
+        let actual = element!(MyFocusableSelects)
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(
+                futures::stream::iter(events).then(|event| async move {
+                    smol::future::yield_now().await;
+                    event
+                }),
+            ))
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(actual.last().unwrap(), "❯ x\n  y\n  z\n  x\n  y\n❯ z\n");
+    }
+
+    #[component]
+    fn MyMultiSelect(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let mut values = hooks.use_state(Vec::new);
+        let mut submitted = hooks.use_state(|| None);
+
+        if let Some(submitted) = submitted.read().as_ref() {
+            assert_eq!(*submitted, vec![1, 3]);
+            system.exit();
+        }
+
+        element! {
+            View(width: 24) {
+                MultiSelect(
+                    items: items(),
+                    values: values.read().clone(),
+                    on_change: move |new_values| values.set(new_values),
+                    on_submit: move |values| submitted.set(Some(values)),
+                    has_focus: true,
+                    filterable: false,
+                )
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_multi_select_input() {
+        let actual = element!(MyMultiSelect)
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+                vec![
+                    key(KeyCode::Char(' ')),
+                    key(KeyCode::Down),
+                    key(KeyCode::Char(' ')),
+                    TerminalEvent::FullscreenMouse(FullscreenMouseEvent::new(
+                        MouseEventKind::Down(MouseButton::Left),
+                        3,
+                        0,
+                    )),
+                    key(KeyCode::End),
+                    key(KeyCode::Char('x')),
+                    key(KeyCode::Char(' ')),
+                    key(KeyCode::Enter),
+                ],
+            )))
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(
+            actual.last().unwrap(),
+            indoc! {"
+                  [ ] Rust
+                      Fast and reliable
+                  [x] Go
+                  [ ] Zig
+                ❯ [x] Ruby
+            "},
+        );
+    }
+}
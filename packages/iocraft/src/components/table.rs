@@ -0,0 +1,773 @@
+use crate::{
+    component,
+    components::{BorderStyle, Button, Text, TextAlign, TextDecoration, TextWrap, View},
+    element,
+    hooks::{UseFocus, UseRef, UseState, UseTerminalEvents},
+    AnyElement, Color, Edges, FlexBasis, FlexDirection, HandlerMut, Hooks, JustifyContent, KeyCode,
+    KeyEvent, KeyEventKind, Props, Size, TerminalEvent, Weight,
+};
+use core::cmp::Ordering;
+use unicode_width::UnicodeWidthStr;
+
+/// The width of a [`TableColumn`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TableColumnWidth {
+    /// The column is as wide as its widest value or header, but shrinks if the table doesn't
+    /// have enough room. This is the default.
+    #[default]
+    Auto,
+    /// The column is the given number of cells wide.
+    Fixed(u32),
+    /// The column is the given percentage of the table's width.
+    Percent(f32),
+    /// The column takes a share of the space left over after the other columns are sized,
+    /// proportional to the given factor.
+    Flex(f32),
+}
+
+/// The order in which a [`Table`] is sorted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TableSortOrder {
+    /// Values are sorted from lowest to highest.
+    Ascending,
+    /// Values are sorted from highest to lowest.
+    Descending,
+}
+
+impl TableSortOrder {
+    fn indicator(self) -> &'static str {
+        match self {
+            Self::Ascending => " ▲",
+            Self::Descending => " ▼",
+        }
+    }
+}
+
+/// Describes a column of a [`Table`].
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// let column = TableColumn::new("Email")
+///     .width(TableColumnWidth::Flex(1.0))
+///     .wrap(TextWrap::TruncateMiddle)
+///     .sortable(true);
+/// ```
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub struct TableColumn {
+    /// The text of the column's header.
+    pub header: String,
+
+    /// The width of the column. Defaults to [`TableColumnWidth::Auto`].
+    pub width: TableColumnWidth,
+
+    /// The alignment of the column's header and values. Defaults to [`TextAlign::Left`].
+    pub align: TextAlign,
+
+    /// How values which don't fit within the column are handled. Defaults to
+    /// [`TextWrap::Truncate`].
+    pub wrap: TextWrap,
+
+    /// Whether the table can be sorted by this column.
+    pub sortable: bool,
+}
+
+impl Default for TableColumn {
+    fn default() -> Self {
+        Self {
+            header: String::new(),
+            width: TableColumnWidth::Auto,
+            align: TextAlign::Left,
+            wrap: TextWrap::Truncate,
+            sortable: false,
+        }
+    }
+}
+
+impl TableColumn {
+    /// Creates a new column with the given header.
+    pub fn new(header: impl Into<String>) -> Self {
+        Self {
+            header: header.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the width of the column.
+    pub fn width(mut self, width: TableColumnWidth) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the alignment of the column's header and values.
+    pub fn align(mut self, align: TextAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Sets how values which don't fit within the column are handled.
+    pub fn wrap(mut self, wrap: TextWrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Sets whether the table can be sorted by this column.
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+}
+
+/// The props which can be passed to the [`Table`] component.
+#[non_exhaustive]
+#[derive(Default, Props)]
+pub struct TableProps {
+    /// The columns of the table.
+    pub columns: Vec<TableColumn>,
+
+    /// The rows of the table. Each row holds one value per column.
+    pub rows: Vec<Vec<String>>,
+
+    /// The number of cells between adjacent columns. Defaults to 1.
+    pub column_gap: Option<u16>,
+
+    /// The style of the border around the table.
+    pub border_style: BorderStyle,
+
+    /// The color of the border around the table.
+    pub border_color: Option<Color>,
+
+    /// The style of the line separating the header from the rows. Defaults to
+    /// [`BorderStyle::Single`]. Use [`BorderStyle::None`] to omit it.
+    pub separator_style: Option<BorderStyle>,
+
+    /// The color of the line separating the header from the rows.
+    pub separator_color: Option<Color>,
+
+    /// The color of the header text.
+    pub header_color: Option<Color>,
+
+    /// The decoration of the header text.
+    pub header_decoration: TextDecoration,
+
+    /// The color of the text in the rows.
+    pub color: Option<Color>,
+
+    /// If given, every other row is drawn with this background color.
+    pub stripe_color: Option<Color>,
+
+    /// If true, a row is highlighted and can be moved with the keyboard or selected with the
+    /// mouse.
+    pub selectable: bool,
+
+    /// The background color of the highlighted row. Defaults to blue.
+    pub highlight_color: Option<Color>,
+
+    /// The maximum number of rows to display at once. If there are more rows, they're scrolled to
+    /// keep the highlighted row visible.
+    pub max_visible_rows: Option<usize>,
+
+    /// True if the table has focus and should process keyboard input. Ignored if
+    /// [`focusable`](Self::focusable) is `true`.
+    pub has_focus: bool,
+
+    /// If true, the table participates in automatic focus management via
+    /// [`UseFocus`](crate::hooks::UseFocus), and can be focused with the Tab key.
+    pub focusable: bool,
+
+    /// The handler to invoke with the index of a row within `rows` when it's selected, either by
+    /// pressing Enter while it's highlighted or by clicking on it. Only invoked if the table is
+    /// `selectable`.
+    pub on_select: HandlerMut<'static, usize>,
+}
+
+/// Compares two values, numerically if they're both numbers.
+fn compare_values(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        _ => a.cmp(b),
+    }
+}
+
+/// Returns the order in which rows are displayed, as indices into `rows`.
+fn sorted_row_order(rows: &[Vec<String>], sort: Option<(usize, TableSortOrder)>) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..rows.len()).collect();
+    if let Some((column, sort_order)) = sort {
+        let value = |row: usize| rows[row].get(column).map(String::as_str).unwrap_or("");
+        order.sort_by(|&a, &b| {
+            let ordering = compare_values(value(a), value(b));
+            match sort_order {
+                TableSortOrder::Ascending => ordering,
+                TableSortOrder::Descending => ordering.reverse(),
+            }
+        });
+    }
+    order
+}
+
+/// Returns the sort which results from toggling the given column: ascending, then descending,
+/// then unsorted.
+fn toggle_sort(
+    sort: Option<(usize, TableSortOrder)>,
+    column: usize,
+) -> Option<(usize, TableSortOrder)> {
+    match sort {
+        Some((c, TableSortOrder::Ascending)) if c == column => {
+            Some((column, TableSortOrder::Descending))
+        }
+        Some((c, TableSortOrder::Descending)) if c == column => None,
+        _ => Some((column, TableSortOrder::Ascending)),
+    }
+}
+
+/// `Table` is a component that displays rows of data in columns with a header.
+///
+/// It will fill the available width. Typically, you will want to render it in a
+/// [`View`](crate::components::View) component of the desired width.
+///
+/// Columns can be given fixed, percentage, automatic, or flexible widths, and values which don't
+/// fit are truncated by default.
+///
+/// # Key Bindings
+///
+/// While the table has focus:
+///
+/// - If the table is `selectable`, Up and Down move the highlight, Home and End move it to the
+///   first and last rows, Page Up and Page Down move it by a page, and Enter selects the
+///   highlighted row.
+/// - The number keys 1 through 9 toggle sorting by the corresponding column, if it's sortable.
+///   Each press cycles between ascending, descending, and unsorted.
+///
+/// In fullscreen mode, clicking on the header of a sortable column toggles sorting by it in the
+/// same way, and clicking on a row of a selectable table highlights and selects it.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// # fn my_element() -> impl Into<AnyElement<'static>> {
+/// element! {
+///     View(width: 40) {
+///         Table(
+///             columns: vec![
+///                 TableColumn::new("Id").align(TextAlign::Right).sortable(true),
+///                 TableColumn::new("Name").width(TableColumnWidth::Flex(1.0)).sortable(true),
+///             ],
+///             rows: vec![
+///                 vec!["1".to_string(), "Alice".to_string()],
+///                 vec!["2".to_string(), "Bob".to_string()],
+///             ],
+///             stripe_color: Color::DarkGrey,
+///             selectable: true,
+///             on_select: |row| { /* do something */ },
+///         )
+///     }
+/// }
+/// # }
+/// ```
+#[component]
+pub fn Table(mut hooks: Hooks, props: &mut TableProps) -> impl Into<AnyElement<'static>> {
+    let focus = hooks.use_focus_if(props.focusable);
+    let has_focus = if props.focusable {
+        focus.is_focused()
+    } else {
+        props.has_focus
+    };
+    let mut sort = hooks.use_state(|| None);
+    let mut highlighted = hooks.use_state(|| None::<usize>);
+    let mut scroll_offset = hooks.use_state(|| 0usize);
+
+    // The handler is shared by the key and click handlers, so it's kept in a ref.
+    let mut on_select = hooks.use_ref(HandlerMut::default);
+    on_select.set(props.on_select.take());
+
+    if let Some((column, _)) = sort.get() {
+        if props
+            .columns
+            .get(column)
+            .is_none_or(|c: &TableColumn| !c.sortable)
+        {
+            sort.set(None);
+        }
+    }
+    let order = sorted_row_order(&props.rows, sort.get());
+
+    // Keep the highlight on the same row as the rows are sorted, and within bounds as they change.
+    let highlighted_position = if props.selectable && !order.is_empty() {
+        let position = highlighted
+            .get()
+            .and_then(|row| order.iter().position(|&r| r == row))
+            .unwrap_or(0);
+        if highlighted.get() != Some(order[position]) {
+            highlighted.set(Some(order[position]));
+        }
+        Some(position)
+    } else {
+        None
+    };
+
+    let visible_rows = props.max_visible_rows.unwrap_or(usize::MAX).max(1);
+    let max_scroll_offset = order.len().saturating_sub(visible_rows);
+    let mut offset = scroll_offset.get().min(max_scroll_offset);
+    if let Some(position) = highlighted_position {
+        if position < offset {
+            offset = position;
+        } else if position >= offset + visible_rows {
+            offset = position + 1 - visible_rows;
+        }
+    }
+    if scroll_offset.get() != offset {
+        scroll_offset.set(offset);
+    }
+
+    hooks.use_terminal_events({
+        let rows = props.rows.clone();
+        let mut order = order.clone();
+        let sortable: Vec<bool> = props.columns.iter().map(|c| c.sortable).collect();
+        let selectable = props.selectable;
+        let mut position = highlighted_position;
+        move |event| {
+            if !has_focus {
+                return;
+            }
+            let TerminalEvent::Key(KeyEvent { code, kind, .. }) = event else {
+                return;
+            };
+            if kind == KeyEventKind::Release {
+                return;
+            }
+            let page = visible_rows.min(order.len()).max(1);
+            let new_position = match (code, position) {
+                (KeyCode::Up, Some(p)) if selectable => Some(p.saturating_sub(1)),
+                (KeyCode::Down, Some(p)) if selectable => Some((p + 1).min(order.len() - 1)),
+                (KeyCode::PageUp, Some(p)) if selectable => Some(p.saturating_sub(page)),
+                (KeyCode::PageDown, Some(p)) if selectable => Some((p + page).min(order.len() - 1)),
+                (KeyCode::Home, Some(_)) if selectable => Some(0),
+                (KeyCode::End, Some(_)) if selectable => Some(order.len() - 1),
+                (KeyCode::Enter, Some(p)) if selectable => {
+                    (on_select.write())(order[p]);
+                    None
+                }
+                (KeyCode::Char(c @ '1'..='9'), _) => {
+                    let column = c as usize - '1' as usize;
+                    if sortable.get(column) == Some(&true) {
+                        sort.set(toggle_sort(sort.get(), column));
+                        // Keep the highlight on the same row for subsequent events.
+                        let row = position.map(|p| order[p]);
+                        order = sorted_row_order(&rows, sort.get());
+                        position = row.and_then(|row| order.iter().position(|&r| r == row));
+                    }
+                    None
+                }
+                _ => None,
+            };
+            if let Some(new_position) = new_position {
+                position = Some(new_position);
+                highlighted.set(Some(order[new_position]));
+            }
+        }
+    });
+
+    // Auto columns are as wide as their widest value, leaving room for the sort indicator.
+    let auto_widths: Vec<usize> = props
+        .columns
+        .iter()
+        .enumerate()
+        .map(|(i, column)| {
+            let indicator_width = if column.sortable {
+                TableSortOrder::Ascending.indicator().width()
+            } else {
+                0
+            };
+            props
+                .rows
+                .iter()
+                .filter_map(|row| row.get(i))
+                .map(|value| value.width())
+                .fold(column.header.width() + indicator_width, usize::max)
+        })
+        .collect();
+
+    let column_gap = props.column_gap.unwrap_or(1);
+    let highlight_color = props.highlight_color.unwrap_or(Color::Blue);
+    let header_cells = props.columns.iter().enumerate().map(|(i, column)| {
+        let mut header = column.header.clone();
+        if let Some((_, sort_order)) = sort.get().filter(|(c, _)| *c == i) {
+            header.push_str(sort_order.indicator());
+        }
+        let cell = table_cell(
+            column,
+            auto_widths[i],
+            header,
+            props.header_color,
+            Weight::Bold,
+            props.header_decoration,
+        );
+        if column.sortable {
+            element! {
+                Button(has_focus: false, handler: move |_| sort.set(toggle_sort(sort.get(), i))) {
+                    #(cell)
+                }
+            }
+            .into_any()
+        } else {
+            cell
+        }
+    });
+    let rows = order
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(visible_rows)
+        .map(|(position, &row)| {
+            let background_color = if highlighted_position == Some(position) {
+                Some(highlight_color)
+            } else if position % 2 == 1 {
+                props.stripe_color
+            } else {
+                None
+            };
+            let cells = props.columns.iter().enumerate().map(|(i, column)| {
+                let value = props.rows[row].get(i).cloned().unwrap_or_default();
+                table_cell(
+                    column,
+                    auto_widths[i],
+                    value,
+                    props.color,
+                    Weight::Normal,
+                    TextDecoration::None,
+                )
+            });
+            let row_element = element! {
+                View(column_gap, background_color) {
+                    #(cells)
+                }
+            };
+            if props.selectable {
+                element! {
+                    Button(has_focus: false, handler: move |_| {
+                        highlighted.set(Some(row));
+                        (on_select.write())(row);
+                    }) {
+                        #(row_element)
+                    }
+                }
+                .into_any()
+            } else {
+                row_element.into_any()
+            }
+        });
+
+    element! {
+        View(
+            flex_direction: FlexDirection::Column,
+            width: 100pct,
+            border_style: props.border_style,
+            border_color: props.border_color,
+        ) {
+            View(
+                column_gap,
+                border_style: props.separator_style.unwrap_or(BorderStyle::Single),
+                border_edges: Edges::Bottom,
+                border_color: props.separator_color,
+            ) {
+                #(header_cells)
+            }
+            #(rows)
+        }
+    }
+}
+
+/// Returns a cell of the given column, laid out according to the column's width and alignment.
+fn table_cell(
+    column: &TableColumn,
+    auto_width: usize,
+    content: String,
+    color: Option<Color>,
+    weight: Weight,
+    decoration: TextDecoration,
+) -> AnyElement<'static> {
+    let (width, flex_grow, flex_shrink, flex_basis) = match column.width {
+        TableColumnWidth::Auto => (Size::Auto, 0.0, 1.0, FlexBasis::Length(auto_width as _)),
+        TableColumnWidth::Fixed(width) => (Size::Length(width), 0.0, 0.0, FlexBasis::Auto),
+        TableColumnWidth::Percent(percent) => (Size::Percent(percent), 0.0, 0.0, FlexBasis::Auto),
+        TableColumnWidth::Flex(factor) => (Size::Auto, factor, 1.0, FlexBasis::Length(0)),
+    };
+    let justify_content = match column.align {
+        TextAlign::Left | TextAlign::Start => JustifyContent::Start,
+        TextAlign::Right | TextAlign::End => JustifyContent::End,
+        TextAlign::Center => JustifyContent::Center,
+    };
+    element! {
+        View(
+            width,
+            min_width: 0,
+            flex_grow,
+            flex_shrink,
+            flex_basis,
+            justify_content,
+        ) {
+            Text(content, color, weight, decoration, wrap: column.wrap, align: column.align)
+        }
+    }
+    .into_any()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crossterm::event::MouseButton;
+    use futures::stream::StreamExt;
+    use indoc::indoc;
+    use macro_rules_attribute::apply;
+    use smol_macros::test;
+
+    fn rows() -> Vec<Vec<String>> {
+        [
+            ["1", "Alice", "alice@example.com"],
+            ["10", "Bob", "bob@example.com"],
+            ["2", "Charlie", "charlie@a-very-long-domain.example.com"],
+        ]
+        .iter()
+        .map(|row| row.iter().map(|v| v.to_string()).collect())
+        .collect()
+    }
+
+    #[test]
+    fn test_compare_values() {
+        assert_eq!(compare_values("2", "10"), Ordering::Less);
+        assert_eq!(compare_values(" 3.5", "3"), Ordering::Greater);
+        assert_eq!(compare_values("2", "10x"), Ordering::Greater);
+        assert_eq!(compare_values("a", "b"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_sorted_row_order() {
+        let rows = rows();
+        assert_eq!(sorted_row_order(&rows, None), vec![0, 1, 2]);
+        assert_eq!(
+            sorted_row_order(&rows, Some((0, TableSortOrder::Ascending))),
+            vec![0, 2, 1]
+        );
+        assert_eq!(
+            sorted_row_order(&rows, Some((1, TableSortOrder::Descending))),
+            vec![2, 1, 0]
+        );
+        assert_eq!(
+            sorted_row_order(&rows, Some((5, TableSortOrder::Ascending))),
+            vec![0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn test_toggle_sort() {
+        let sort = toggle_sort(None, 1);
+        assert_eq!(sort, Some((1, TableSortOrder::Ascending)));
+        let sort = toggle_sort(sort, 1);
+        assert_eq!(sort, Some((1, TableSortOrder::Descending)));
+        assert_eq!(toggle_sort(sort, 0), Some((0, TableSortOrder::Ascending)));
+        assert_eq!(toggle_sort(sort, 1), None);
+    }
+
+    #[test]
+    fn test_table() {
+        assert_eq!(
+            element! {
+                View(width: 40) {
+                    Table(
+                        columns: vec![
+                            TableColumn::new("Id").align(TextAlign::Right),
+                            TableColumn::new("Name").width(TableColumnWidth::Fixed(8)),
+                            TableColumn::new("Email").width(TableColumnWidth::Flex(1.0)),
+                        ],
+                        rows: rows(),
+                    )
+                }
+            }
+            .to_string(),
+            indoc! {"
+                Id Name     Email
+                ────────────────────────────────────────
+                 1 Alice    alice@example.com
+                10 Bob      bob@example.com
+                 2 Charlie  charlie@a-very-long-domain.…
+            "},
+        );
+
+        // Auto and percentage widths, truncation, and an outer border.
+        assert_eq!(
+            element! {
+                View(width: 40) {
+                    Table(
+                        columns: vec![
+                            TableColumn::new("Id").width(TableColumnWidth::Percent(20.0)),
+                            TableColumn::new("Name"),
+                            TableColumn::new("Email").wrap(TextWrap::TruncateStart),
+                        ],
+                        rows: rows(),
+                        border_style: BorderStyle::Round,
+                        separator_style: BorderStyle::Double,
+                    )
+                }
+            }
+            .to_string(),
+            indoc! {"
+                ╭──────────────────────────────────────╮
+                │Id       Name Email                   │
+                │══════════════════════════════════════│
+                │1        Ali… alice@example.com       │
+                │10       Bob  bob@example.com         │
+                │2        Cha… …long-domain.example.com│
+                ╰──────────────────────────────────────╯
+            "},
+        );
+    }
+
+    #[test]
+    fn test_table_striping_and_highlight() {
+        let canvas = element! {
+            View(width: 20) {
+                Table(
+                    columns: vec![TableColumn::new("Id")],
+                    rows: rows(),
+                    stripe_color: Color::DarkGrey,
+                    selectable: true,
+                    separator_style: BorderStyle::None,
+                )
+            }
+        }
+        .render(None);
+        assert_eq!(
+            canvas.cell(0, 1).unwrap().background_color,
+            Some(Color::Blue)
+        );
+        assert_eq!(
+            canvas.cell(0, 2).unwrap().background_color,
+            Some(Color::DarkGrey)
+        );
+        assert_eq!(canvas.cell(0, 3).unwrap().background_color, None);
+    }
+
+    #[test]
+    fn test_table_max_visible_rows() {
+        assert_eq!(
+            element! {
+                View(width: 20) {
+                    Table(
+                        columns: vec![TableColumn::new("Id")],
+                        rows: rows(),
+                        max_visible_rows: 2,
+                        separator_style: BorderStyle::None,
+                    )
+                }
+            }
+            .to_string(),
+            "Id\n1\n10\n",
+        );
+    }
+
+    #[derive(Default, Props)]
+    struct MyComponentProps {
+        expected_selection: usize,
+    }
+
+    #[component]
+    fn MyComponent(mut hooks: Hooks, props: &MyComponentProps) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let mut selected = hooks.use_state(|| None);
+
+        if let Some(selected) = selected.get() {
+            assert_eq!(selected, props.expected_selection);
+            system.exit();
+        }
+
+        element! {
+            View(width: 30) {
+                Table(
+                    columns: vec![
+                        TableColumn::new("Id").sortable(true),
+                        TableColumn::new("Name").width(TableColumnWidth::Flex(1.0)),
+                    ],
+                    rows: rows(),
+                    selectable: true,
+                    has_focus: true,
+                    on_select: move |row| selected.set(Some(row)),
+                )
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_table_key_input() {
+        let actual = element!(MyComponent(expected_selection: 2usize))
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+                vec![
+                    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, KeyCode::Char('1'))),
+                    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, KeyCode::Down)),
+                    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, KeyCode::Enter)),
+                ],
+            )))
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(
+            actual.last().unwrap(),
+            concat!(
+                "Id ▲ Name\n",
+                "──────────────────────────────\n",
+                "1    Alice\n",
+                // The highlighted row's background extends to the edge.
+                "2    Charlie                  \n",
+                "10   Bob\n",
+            ),
+        );
+    }
+
+    fn click(column: u16, row: u16) -> TerminalEvent {
+        TerminalEvent::FullscreenMouse(FullscreenMouseEvent::new(
+            MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+        ))
+    }
+
+    #[apply(test!)]
+    async fn test_table_header_click() {
+        let actual = element!(MyComponent(expected_selection: 0usize))
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+                vec![
+                    click(0, 0),
+                    click(0, 0),
+                    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, KeyCode::Enter)),
+                ],
+            )))
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(
+            actual.last().unwrap(),
+            concat!(
+                "Id ▼ Name\n",
+                "──────────────────────────────\n",
+                "10   Bob\n",
+                "2    Charlie\n",
+                // The highlighted row's background extends to the edge.
+                "1    Alice                    \n",
+            ),
+        );
+    }
+
+    #[apply(test!)]
+    async fn test_table_row_click() {
+        // The component asserts that the clicked row was selected before exiting.
+        element!(MyComponent(expected_selection: 1usize))
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+                vec![click(8, 3)],
+            )))
+            .collect::<Vec<_>>()
+            .await;
+    }
+}
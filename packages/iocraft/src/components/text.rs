@@ -1,18 +1,52 @@
 use crate::{
-    render::MeasureFunc, segmented_string::SegmentedString, strip_ansi::strip_ansi,
-    CanvasTextStyle, Color, Component, ComponentDrawer, ComponentUpdater, Hooks, Props, Weight,
+    bidi,
+    render::MeasureFunc,
+    segmented_string::{SegmentedString, TruncationPosition},
+    strip_ansi::strip_ansi,
+    tabs::{expand_tabs, DEFAULT_TAB_WIDTH},
+    CanvasTextStyle, Color, Component, ComponentDrawer, ComponentUpdater, Hooks, Props,
+    UnderlineStyle, Weight,
 };
+use std::sync::Arc;
 use taffy::{AvailableSpace, Size};
 use unicode_width::UnicodeWidthStr;
 
 /// The text wrapping behavior of a [`Text`] component.
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub enum TextWrap {
     /// Text is wrapped at appropriate characters to minimize overflow. This is the default.
+    ///
+    /// Lines are broken at Unicode line break opportunities, such as spaces, when possible. Words
+    /// which don't fit on a line on their own, such as long URLs or hashes, are broken between
+    /// characters.
     #[default]
     Wrap,
+    /// Text is wrapped between any two characters, filling each line as much as possible without
+    /// regard for word boundaries.
+    BreakAnywhere,
     /// Text is not wrapped, and may overflow the bounds of the component.
     NoWrap,
+    /// Text is not wrapped. Lines which are too long are truncated at the end, with an ellipsis
+    /// (`…`) indicating the removed text.
+    Truncate,
+    /// Text is not wrapped. Lines which are too long are truncated at the start, with an ellipsis
+    /// (`…`) indicating the removed text.
+    TruncateStart,
+    /// Text is not wrapped. Lines which are too long are truncated in the middle, with an ellipsis
+    /// (`…`) indicating the removed text.
+    TruncateMiddle,
+}
+
+impl TextWrap {
+    /// Returns the position at which text is truncated, or `None` if the text isn't truncated.
+    pub(crate) fn truncation_position(self) -> Option<TruncationPosition> {
+        match self {
+            Self::Wrap | Self::BreakAnywhere | Self::NoWrap => None,
+            Self::Truncate => Some(TruncationPosition::End),
+            Self::TruncateStart => Some(TruncationPosition::Start),
+            Self::TruncateMiddle => Some(TruncationPosition::Middle),
+        }
+    }
 }
 
 /// The text alignment of a [`Text`] component.
@@ -25,6 +59,24 @@ pub enum TextAlign {
     Right,
     /// Text is aligned to the center.
     Center,
+    /// Text is aligned to the start of each line, which is the left for left-to-right text and
+    /// the right for right-to-left text.
+    Start,
+    /// Text is aligned to the end of each line, which is the right for left-to-right text and the
+    /// left for right-to-left text.
+    End,
+}
+
+impl TextAlign {
+    /// Resolves [`TextAlign::Start`] and [`TextAlign::End`] for a line with the given base
+    /// direction.
+    pub(crate) fn resolve(self, is_rtl: bool) -> Self {
+        match (self, is_rtl) {
+            (Self::Start, false) | (Self::End, true) => Self::Left,
+            (Self::Start, true) | (Self::End, false) => Self::Right,
+            _ => self,
+        }
+    }
 }
 
 /// The text decoration of a [`Text`] component.
@@ -35,6 +87,28 @@ pub enum TextDecoration {
     None,
     /// The text is underlined.
     Underline,
+    /// The text is underlined with two lines.
+    DoubleUnderline,
+    /// The text is underlined with a wavy line.
+    CurlyUnderline,
+    /// The text is underlined with a dotted line.
+    DottedUnderline,
+    /// The text is underlined with a dashed line.
+    DashedUnderline,
+}
+
+impl TextDecoration {
+    /// Returns the style of the underline, or `None` if the text isn't underlined.
+    pub(crate) fn underline_style(self) -> Option<UnderlineStyle> {
+        match self {
+            Self::None => None,
+            Self::Underline => Some(UnderlineStyle::Single),
+            Self::DoubleUnderline => Some(UnderlineStyle::Double),
+            Self::CurlyUnderline => Some(UnderlineStyle::Curly),
+            Self::DottedUnderline => Some(UnderlineStyle::Dotted),
+            Self::DashedUnderline => Some(UnderlineStyle::Dashed),
+        }
+    }
 }
 
 /// The props which can be passed to the [`Text`] component.
@@ -59,11 +133,30 @@ pub struct TextProps {
     /// The text decoration.
     pub decoration: TextDecoration,
 
+    /// The color of the underline, if the text is underlined. Defaults to the color of the text.
+    pub underline_color: Option<Color>,
+
     /// Whether to italicize the text.
     pub italic: bool,
 
     /// Whether to invert the text's foreground and background colors.
     pub invert: bool,
+
+    /// Whether to draw a line through the text.
+    pub strikethrough: bool,
+
+    /// Whether to draw a line above the text.
+    pub overline: bool,
+
+    /// Whether to make the text blink.
+    pub blink: bool,
+
+    /// Whether to hide the text. Hidden text still occupies space.
+    pub hidden: bool,
+
+    /// The number of columns between tab stops. Tab characters are replaced with spaces up to the
+    /// next tab stop. Defaults to 8.
+    pub tab_width: Option<usize>,
 }
 
 /// `Text` is a component that renders a text string.
@@ -103,10 +196,15 @@ impl Text {
         })
     }
 
-    fn do_wrap(s: &str, width: usize) -> String {
+    fn do_wrap(s: &str, width: usize, text_wrap: TextWrap) -> String {
         let s: SegmentedString = s.into();
+        let lines = if text_wrap == TextWrap::BreakAnywhere {
+            s.break_anywhere(width)
+        } else {
+            s.wrap(width)
+        };
         let mut ret = String::new();
-        for line in s.wrap(width) {
+        for line in lines {
             if !ret.is_empty() {
                 ret.push('\n');
             }
@@ -115,48 +213,89 @@ impl Text {
         ret
     }
 
+    fn do_truncate(s: &str, width: usize, position: TruncationPosition) -> String {
+        let s: SegmentedString = s.into();
+        s.truncate(width, position)
+            .into_iter()
+            .map(|line| line.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     fn wrap(
         content: &str,
         text_wrap: TextWrap,
         known_width: Option<f32>,
         available_width: AvailableSpace,
     ) -> String {
+        if let Some(position) = text_wrap.truncation_position() {
+            return match known_width {
+                Some(w) => Self::do_truncate(content, w as usize, position),
+                None => match available_width {
+                    AvailableSpace::Definite(w) => Self::do_truncate(content, w as usize, position),
+                    AvailableSpace::MaxContent => content.to_string(),
+                    AvailableSpace::MinContent => Self::do_truncate(content, 1, position),
+                },
+            };
+        }
         match text_wrap {
-            TextWrap::Wrap => match known_width {
-                Some(w) => Self::do_wrap(content, w as usize),
+            TextWrap::Wrap | TextWrap::BreakAnywhere => match known_width {
+                Some(w) => Self::do_wrap(content, w as usize, text_wrap),
                 None => match available_width {
-                    AvailableSpace::Definite(w) => Self::do_wrap(content, w as usize),
+                    AvailableSpace::Definite(w) => Self::do_wrap(content, w as usize, text_wrap),
                     AvailableSpace::MaxContent => content.to_string(),
-                    AvailableSpace::MinContent => Self::do_wrap(content, 1),
+                    AvailableSpace::MinContent => Self::do_wrap(content, 1, text_wrap),
                 },
             },
-            TextWrap::NoWrap => content.to_string(),
+            _ => content.to_string(),
         }
     }
 
+    /// Returns the padding for a line. [`TextAlign::Start`] and [`TextAlign::End`] must already
+    /// be resolved.
     pub(crate) fn alignment_padding(line_width: usize, align: TextAlign, width: usize) -> isize {
         match align {
-            TextAlign::Left => 0,
-            TextAlign::Right => width as isize - line_width as isize,
+            TextAlign::Left | TextAlign::Start => 0,
+            TextAlign::Right | TextAlign::End => width as isize - line_width as isize,
             TextAlign::Center => width as isize / 2 - line_width as isize / 2,
         }
     }
 
+    /// Reorders the lines of the text for display, returning each line in visual order along with
+    /// its resolved alignment.
+    fn reorder(content: &str, align: TextAlign) -> Vec<(String, TextAlign)> {
+        content
+            .lines()
+            .map(|line| {
+                let line = bidi::reorder_line(&[line]);
+                let align = align.resolve(line.is_rtl);
+                (line.runs.into_iter().map(|(_, s)| s).collect(), align)
+            })
+            .collect()
+    }
+
     /// Aligns the text, returning the new string and an additional common x offset to apply to all lines.
-    fn align(content: String, align: TextAlign, width: usize) -> (isize, String) {
-        match align {
-            TextAlign::Left => (0, content),
-            _ => {
-                let paddings = content
-                    .lines()
-                    .map(|line| Self::alignment_padding(line.width(), align, width))
+    fn align(lines: Vec<(String, TextAlign)>, width: usize) -> (isize, String) {
+        match lines.iter().all(|(_, align)| *align == TextAlign::Left) {
+            true => (
+                0,
+                lines
+                    .into_iter()
+                    .map(|(line, _)| line)
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+            false => {
+                let paddings = lines
+                    .iter()
+                    .map(|(line, align)| Self::alignment_padding(line.width(), *align, width))
                     .collect::<Vec<_>>();
 
                 let x_offset = paddings.iter().copied().min().unwrap_or(0);
-                let aligned = content
-                    .lines()
+                let aligned = lines
+                    .iter()
                     .zip(paddings)
-                    .map(|(line, padding)| {
+                    .map(|((line, _), padding)| {
                         format!(
                             "{:width$}{}",
                             "",
@@ -201,6 +340,15 @@ impl<'a, 'b> TextDrawer<'a, 'b> {
         &mut self,
         lines: impl IntoIterator<Item = &'c str>,
         style: CanvasTextStyle,
+    ) {
+        self.append_linked_lines(lines, style, None);
+    }
+
+    pub fn append_linked_lines<'c>(
+        &mut self,
+        lines: impl IntoIterator<Item = &'c str>,
+        style: CanvasTextStyle,
+        link: Option<&Arc<str>>,
     ) {
         let mut lines = lines.into_iter().peekable();
         while let Some(mut line) = lines.next() {
@@ -216,7 +364,9 @@ impl<'a, 'b> TextDrawer<'a, 'b> {
                     self.line_encountered_non_whitespace = true;
                 }
             }
-            self.drawer.canvas().set_text(self.x, self.y, line, style);
+            self.drawer
+                .canvas()
+                .set_text_with_link(self.x, self.y, line, style, link);
             if lines.peek().is_some() {
                 self.y += 1;
                 self.x = self.x_offset;
@@ -244,11 +394,18 @@ impl Component for Text {
         self.style = CanvasTextStyle {
             color: props.color,
             weight: props.weight,
-            underline: props.decoration == TextDecoration::Underline,
+            underline_style: props.decoration.underline_style(),
+            underline_color: props.underline_color,
             italic: props.italic,
             invert: props.invert,
+            strikethrough: props.strikethrough,
+            overline: props.overline,
+            blink: props.blink,
+            hidden: props.hidden,
         };
-        self.content = strip_ansi(&props.content).into_owned();
+        let content = strip_ansi(&props.content);
+        let tab_width = props.tab_width.unwrap_or(DEFAULT_TAB_WIDTH);
+        self.content = expand_tabs(&content, tab_width, &mut 0).into_owned();
         self.wrap = props.wrap;
         self.align = props.align;
         updater.set_measure_func(Self::measure_func(self.content.clone(), props.wrap));
@@ -262,8 +419,10 @@ impl Component for Text {
             None,
             AvailableSpace::Definite(width),
         );
-        let (x_offset, content) = Self::align(content, self.align, width as _);
-        let mut drawer = TextDrawer::new(drawer, x_offset, self.align != TextAlign::Left);
+        let lines = Self::reorder(&content, self.align);
+        let is_left_aligned = lines.iter().all(|(_, align)| *align == TextAlign::Left);
+        let (x_offset, content) = Self::align(lines, width as _);
+        let mut drawer = TextDrawer::new(drawer, x_offset, !is_left_aligned);
         drawer.append_lines(content.lines(), self.style);
     }
 }
@@ -378,6 +537,184 @@ mod tests {
         assert!(canvas.cell(0, 0).unwrap().text_style().unwrap().invert);
     }
 
+    #[test]
+    fn test_text_bidi() {
+        assert_eq!(
+            element!(Text(content: "abc שלום def")).to_string(),
+            "abc םולש def\n"
+        );
+
+        // Lines are reordered after wrapping.
+        assert_eq!(
+            element! {
+                View(width: 9) {
+                    Text(content: "שלום עולם (abc)")
+                }
+            }
+            .to_string(),
+            "םלוע םולש\n(abc)\n"
+        );
+
+        assert_eq!(
+            element! {
+                View(width: 10, flex_direction: FlexDirection::Column) {
+                    Text(content: "שלום\nabc", align: TextAlign::Start)
+                }
+            }
+            .to_string(),
+            "      םולש\nabc\n"
+        );
+
+        assert_eq!(
+            element! {
+                View(width: 10, flex_direction: FlexDirection::Column) {
+                    Text(content: "שלום\nabc", align: TextAlign::End)
+                }
+            }
+            .to_string(),
+            "םולש\n       abc\n"
+        );
+    }
+
+    #[test]
+    fn test_text_tabs() {
+        assert_eq!(
+            element!(Text(content: "a\tb\nabc\td")).to_string(),
+            "a       b\nabc     d\n"
+        );
+
+        assert_eq!(
+            element!(Text(content: "a\tb\tc", tab_width: 4)).to_string(),
+            "a   b   c\n"
+        );
+
+        // Tabs are accounted for when wrapping.
+        assert_eq!(
+            element! {
+                View(width: 6) {
+                    Text(content: "ab\tcd ef", tab_width: 4)
+                }
+            }
+            .to_string(),
+            "ab  cd\nef\n"
+        );
+    }
+
+    #[test]
+    fn test_text_break_anywhere() {
+        assert_eq!(
+            element! {
+                View(width: 8) {
+                    Text(content: "see https://example.com/foo", wrap: TextWrap::BreakAnywhere)
+                }
+            }
+            .to_string(),
+            "see http\ns://exam\nple.com/\nfoo\n"
+        );
+
+        assert_eq!(
+            element! {
+                View(width: 8) {
+                    Text(content: "see https://example.com/foo")
+                }
+            }
+            .to_string(),
+            "see\nhttps://\nexample.\ncom/foo\n"
+        );
+
+        // Wide characters are never split.
+        assert_eq!(
+            element! {
+                View(width: 5) {
+                    Text(content: "一二三四五", wrap: TextWrap::BreakAnywhere)
+                }
+            }
+            .to_string(),
+            "一二\n三四\n五\n"
+        );
+    }
+
+    #[test]
+    fn test_text_truncate() {
+        assert_eq!(
+            element! {
+                View(width: 8) {
+                    Text(content: "abcdefghijkl\nfoo", wrap: TextWrap::Truncate)
+                }
+            }
+            .to_string(),
+            "abcdefg…\nfoo\n"
+        );
+
+        assert_eq!(
+            element! {
+                View(width: 8) {
+                    Text(content: "abcdefghijkl", wrap: TextWrap::TruncateStart)
+                }
+            }
+            .to_string(),
+            "…fghijkl\n"
+        );
+
+        assert_eq!(
+            element! {
+                View(width: 8) {
+                    Text(content: "abcdefghijkl", wrap: TextWrap::TruncateMiddle)
+                }
+            }
+            .to_string(),
+            "abcd…jkl\n"
+        );
+
+        // Wide characters are never split.
+        assert_eq!(
+            element! {
+                View(width: 6) {
+                    Text(content: "😀😀😀😀", wrap: TextWrap::Truncate)
+                }
+            }
+            .to_string(),
+            "😀😀…\n"
+        );
+
+        // Truncated text shrinks to fit alongside its siblings.
+        assert_eq!(
+            element! {
+                View(width: 10) {
+                    Text(content: "a_long_file_name.txt", wrap: TextWrap::TruncateMiddle)
+                    View(flex_shrink: 0.0) {
+                        Text(content: " 12KB")
+                    }
+                }
+            }
+            .to_string(),
+            "a_…xt 12KB\n"
+        );
+    }
+
+    #[test]
+    fn test_text_extended_attributes() {
+        let canvas = element! {
+            Text(
+                content: "foo",
+                decoration: TextDecoration::CurlyUnderline,
+                underline_color: Color::Red,
+                strikethrough: true,
+                overline: true,
+                blink: true,
+                hidden: true,
+            )
+        }
+        .render(None);
+        let style = canvas.cell(0, 0).unwrap().text_style().unwrap();
+        assert_eq!(style.underline_style, Some(UnderlineStyle::Curly));
+        assert_eq!(style.underline_color, Some(Color::Red));
+        assert!(style.strikethrough);
+        assert!(style.overline);
+        assert!(style.blink);
+        assert!(style.hidden);
+    }
+
     #[test]
     fn test_alignment_no_wrap_overflow() {
         assert_eq!(
@@ -1,15 +1,24 @@
 use crate::{
+    bidi,
+    clipboard::UseClipboard,
     component,
-    components::{TextDecoration, TextDrawer, TextWrap, View},
+    components::{Text, TextDecoration, TextDrawer, TextWrap, View},
     element,
-    hooks::{Ref, State, UseMemo, UseState, UseTerminalEvents},
-    segmented_string::SegmentedString,
-    AnyElement, CanvasTextStyle, Color, Component, ComponentDrawer, ComponentUpdater, HandlerMut,
-    Hook, Hooks, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, LayoutStyle, Overflow, Position,
-    Props, Size, TerminalEvent, Weight,
+    hooks::{Ref, State, UseAsyncHandler, UseFocus, UseMemo, UseSize, UseState, UseTerminalEvents},
+    segmented_string::{SegmentedString, ELLIPSIS},
+    AnyElement, CanvasTextStyle, Color, Component, ComponentDrawer, ComponentUpdater,
+    FlexDirection, HandlerMut, Hooks, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, LayoutStyle,
+    Overflow, Position, Props, Size, TerminalEvent, Weight,
 };
-use std::sync::Arc;
-use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+use core::future::Future;
+use futures::future::BoxFuture;
+use std::{
+    collections::VecDeque,
+    ops::Range,
+    sync::{Arc, OnceLock},
+};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 /// A handle which can be used for imperative control of a [`TextInput`] component.
 ///
@@ -51,6 +60,9 @@ pub struct TextInputHandle {
 struct TextInputHandleInner {
     cursor_offset: State<usize>,
     requested_cursor_offset: State<Option<usize>>,
+    selection_anchor: State<Option<usize>>,
+    undo_history: State<UndoHistory>,
+    requested_history_action: State<Option<HistoryAction>>,
 }
 
 impl TextInputHandle {
@@ -71,8 +83,161 @@ impl TextInputHandle {
             .as_ref()
             .map_or(0, |inner| inner.cursor_offset.get())
     }
+
+    /// Gets the currently selected range of the value, if any text is selected.
+    ///
+    /// The offsets are in bytes, not characters.
+    pub fn selection(&self) -> Option<Range<usize>> {
+        self.inner.as_ref().and_then(|inner| {
+            selection_range(inner.selection_anchor.get(), inner.cursor_offset.get())
+        })
+    }
+
+    /// Selects the specified range of the value, placing the cursor at the end of the range.
+    ///
+    /// The offsets are in bytes, not characters.
+    pub fn set_selection(&mut self, range: Range<usize>) {
+        if let Some(inner) = &mut self.inner {
+            inner.selection_anchor.set(Some(range.start));
+            inner.requested_cursor_offset.set(Some(range.end));
+        }
+    }
+
+    /// Selects the entire value.
+    pub fn select_all(&mut self) {
+        self.set_selection(0..usize::MAX);
+    }
+
+    /// Clears the selection, leaving the cursor where it is.
+    pub fn clear_selection(&mut self) {
+        if let Some(inner) = &mut self.inner {
+            inner.selection_anchor.set(None);
+        }
+    }
+
+    /// Reverts the most recent edit, if there is one, as if the user pressed Ctrl-Z.
+    ///
+    /// The change is delivered via the input's `on_change` handler on its next render.
+    pub fn undo(&mut self) {
+        if let Some(inner) = &mut self.inner {
+            inner
+                .requested_history_action
+                .set(Some(HistoryAction::Undo));
+        }
+    }
+
+    /// Reapplies the most recently undone edit, if there is one, as if the user pressed Ctrl-Y.
+    ///
+    /// The change is delivered via the input's `on_change` handler on its next render.
+    pub fn redo(&mut self) {
+        if let Some(inner) = &mut self.inner {
+            inner
+                .requested_history_action
+                .set(Some(HistoryAction::Redo));
+        }
+    }
+
+    /// Discards the undo and redo history, e.g. after the value is replaced or submitted.
+    pub fn clear_history(&mut self) {
+        if let Some(inner) = &mut self.inner {
+            inner.undo_history.write().clear();
+        }
+    }
+}
+
+/// Returns the range between the selection anchor and the cursor, if it isn't empty.
+fn selection_range(anchor: Option<usize>, cursor_offset: usize) -> Option<Range<usize>> {
+    anchor
+        .filter(|anchor| *anchor != cursor_offset)
+        .map(|anchor| anchor.min(cursor_offset)..anchor.max(cursor_offset))
+}
+
+/// A function which can accept, transform, or reject new values of a [`TextInput`].
+///
+/// Any function which takes a `String` and returns an `Option<String>` can be converted into a
+/// `TextInputFilter`.
+#[derive(Default)]
+pub struct TextInputFilter(Option<Box<dyn FnMut(String) -> Option<String> + Send + Sync>>);
+
+impl<F> From<F> for TextInputFilter
+where
+    F: FnMut(String) -> Option<String> + Send + Sync + 'static,
+{
+    fn from(f: F) -> Self {
+        Self(Some(Box::new(f)))
+    }
+}
+
+impl TextInputFilter {
+    fn apply(&mut self, value: String) -> Option<String> {
+        match &mut self.0 {
+            Some(f) => f(value),
+            None => Some(value),
+        }
+    }
+}
+
+/// A provider of completion candidates for a [`TextInput`].
+///
+/// Any function which takes the current value as a `&str` and returns a `Vec<String>` of
+/// candidates can be converted into a `TextInputCompletions`. Providers which need to do
+/// asynchronous work, such as querying a server, can be created with
+/// [`from_async`](Self::from_async).
+#[derive(Clone, Default)]
+pub struct TextInputCompletions(Option<CompletionsProvider>);
+
+type SyncCompletionsFn = dyn Fn(&str) -> Vec<String> + Send + Sync;
+type AsyncCompletionsFn = dyn Fn(String) -> BoxFuture<'static, Vec<String>> + Send + Sync;
+
+#[derive(Clone)]
+enum CompletionsProvider {
+    Sync(Arc<SyncCompletionsFn>),
+    Async(Arc<AsyncCompletionsFn>),
+}
+
+impl<F> From<F> for TextInputCompletions
+where
+    F: Fn(&str) -> Vec<String> + Send + Sync + 'static,
+{
+    fn from(f: F) -> Self {
+        Self(Some(CompletionsProvider::Sync(Arc::new(f))))
+    }
+}
+
+impl TextInputCompletions {
+    /// Creates a provider from an asynchronous function. The function is invoked with each new
+    /// value, and results which arrive after the value has changed again are discarded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iocraft::prelude::*;
+    /// # async fn search(query: String) -> Vec<String> { vec![] }
+    /// # fn my_element(mut value: State<String>) -> impl Into<AnyElement<'static>> {
+    /// element! {
+    ///     TextInput(
+    ///         has_focus: true,
+    ///         value: value.to_string(),
+    ///         on_change: move |new_value| value.set(new_value),
+    ///         completions: TextInputCompletions::from_async(search),
+    ///     )
+    /// }
+    /// # }
+    /// ```
+    pub fn from_async<F, Fut>(f: F) -> Self
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Vec<String>> + Send + 'static,
+    {
+        Self(Some(CompletionsProvider::Async(Arc::new(move |value| {
+            Box::pin(f(value))
+        }))))
+    }
 }
 
+/// The maximum number of completion candidates displayed at once.
+const MAX_VISIBLE_COMPLETIONS: usize = 5;
+
 /// The props which can be passed to the [`TextInput`] component.
 #[non_exhaustive]
 #[derive(Default, Props)]
@@ -86,50 +251,272 @@ pub struct TextInputProps {
     /// The text decoration.
     pub decoration: TextDecoration,
 
+    /// The color of the underline, if the text is underlined. Defaults to the color of the text.
+    pub underline_color: Option<Color>,
+
     /// Whether the text is italicized.
     pub italic: bool,
 
     /// Whether the foreground and background colors are inverted.
     pub invert: bool,
 
+    /// Whether the text has a line through it.
+    pub strikethrough: bool,
+
+    /// Whether the text has a line above it.
+    pub overline: bool,
+
+    /// Whether the text blinks.
+    pub blink: bool,
+
+    /// Whether the text is hidden, e.g. for password entry. Hidden text still occupies space.
+    pub hidden: bool,
+
     /// The current value.
     pub value: String,
 
-    /// True if the input has focus and should process keyboard input.
+    /// True if the input has focus and should process keyboard input. Ignored if
+    /// [`focusable`](Self::focusable) is `true`.
     pub has_focus: bool,
 
+    /// If true, the input participates in automatic focus management via
+    /// [`UseFocus`](crate::hooks::UseFocus), and can be focused with the Tab key.
+    pub focusable: bool,
+
     /// The handler to invoke when the value changes.
     pub on_change: HandlerMut<'static, String>,
 
+    /// The handler to invoke with the current value when Enter is pressed in a single-line input.
+    pub on_submit: HandlerMut<'static, String>,
+
+    /// A function which is given each new value before `on_change` is invoked. It can return the
+    /// value as-is to accept it, return a transformed value, or return `None` to reject the edit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iocraft::prelude::*;
+    /// # fn my_element(mut value: State<String>) -> impl Into<AnyElement<'static>> {
+    /// element! {
+    ///     TextInput(
+    ///         has_focus: true,
+    ///         value: value.to_string(),
+    ///         on_change: move |new_value| value.set(new_value),
+    ///         filter: |new_value: String| {
+    ///             new_value.chars().all(|c| c.is_ascii_digit()).then_some(new_value)
+    ///         },
+    ///     )
+    /// }
+    /// # }
+    /// ```
+    pub filter: TextInputFilter,
+
+    /// The maximum number of grapheme clusters, i.e. user-perceived characters, the value may
+    /// contain. Edits which would exceed it are truncated.
+    pub max_length: Option<usize>,
+
+    /// Text to display, dimmed, when the value is empty.
+    pub placeholder: String,
+
+    /// The color to make the placeholder. Defaults to dark gray.
+    pub placeholder_color: Option<Color>,
+
+    /// If given, each character of the value is displayed as this character instead, e.g. for
+    /// password entry. Wide characters are displayed as multiple mask characters, so the mask
+    /// should be a single column wide. Copying and cutting are disabled while masked.
+    pub mask: Option<char>,
+
     /// If true, the input will fill 100% of the height of its container and handle multiline input.
     pub multiline: bool,
 
+    /// The text wrapping behavior of a multiline input. Defaults to [`TextWrap::Wrap`].
+    ///
+    /// Single-line inputs never wrap. Truncation isn't supported, and behaves like
+    /// [`TextWrap::NoWrap`].
+    pub wrap: Option<TextWrap>,
+
     /// The color to make the cursor. Defaults to gray.
     pub cursor_color: Option<Color>,
 
+    /// If true, a single-line input whose value is too wide to fit displays an ellipsis on each
+    /// side where the value is clipped.
+    ///
+    /// Regardless of this setting, the value is scrolled horizontally to keep the cursor visible.
+    pub overflow_indicators: bool,
+
+    /// The background color of selected text. Defaults to blue.
+    pub selection_color: Option<Color>,
+
+    /// The maximum number of edits which can be undone. Defaults to 100. If 0, undo is disabled.
+    pub history_limit: Option<usize>,
+
+    /// Previously submitted values, oldest first, which can be recalled with the Up and Down keys
+    /// in a single-line input.
+    pub history: Vec<String>,
+
+    /// A provider of completion candidates for a single-line input. Candidates are displayed in a
+    /// dropdown below the input, and the selected candidate is suggested inline if it begins
+    /// with the current value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iocraft::prelude::*;
+    /// # fn my_element(mut value: State<String>) -> impl Into<AnyElement<'static>> {
+    /// const COMMANDS: &[&str] = &["help", "history", "quit"];
+    ///
+    /// element! {
+    ///     TextInput(
+    ///         has_focus: true,
+    ///         value: value.to_string(),
+    ///         on_change: move |new_value| value.set(new_value),
+    ///         completions: |value: &str| {
+    ///             COMMANDS
+    ///                 .iter()
+    ///                 .filter(|c| !value.is_empty() && c.starts_with(value))
+    ///                 .map(|c| c.to_string())
+    ///                 .collect::<Vec<_>>()
+    ///         },
+    ///     )
+    /// }
+    /// # }
+    /// ```
+    pub completions: TextInputCompletions,
+
     /// An optional handle which can be used for imperative control of the input.
     pub handle: Option<Ref<TextInputHandle>>,
 }
 
-trait UseSize<'a> {
-    fn use_size(&mut self) -> (u16, u16);
+/// Returns the offset of the start of the grapheme cluster before the given offset.
+fn previous_grapheme_boundary(text: &str, offset: usize) -> usize {
+    text[..offset]
+        .grapheme_indices(true)
+        .next_back()
+        .map_or(0, |(i, _)| i)
+}
+
+/// Returns the offset of the end of the grapheme cluster after the given offset.
+fn next_grapheme_boundary(text: &str, offset: usize) -> usize {
+    text[offset..]
+        .graphemes(true)
+        .next()
+        .map_or(text.len(), |g| offset + g.len())
+}
+
+/// Returns the offset of the start of the word before the given offset.
+fn previous_word_boundary(text: &str, offset: usize) -> usize {
+    text[..offset]
+        .split_word_bound_indices()
+        .rev()
+        .find(|(_, w)| w.chars().any(char::is_alphanumeric))
+        .map_or(0, |(i, _)| i)
+}
+
+/// Returns the offset of the end of the word after the given offset.
+fn next_word_boundary(text: &str, offset: usize) -> usize {
+    text[offset..]
+        .split_word_bound_indices()
+        .find(|(_, w)| w.chars().any(char::is_alphanumeric))
+        .map_or(text.len(), |(i, w)| offset + i + w.len())
 }
 
-impl<'a> UseSize<'a> for Hooks<'a, '_> {
-    fn use_size(&mut self) -> (u16, u16) {
-        self.use_hook(UseSizeImpl::default).size
+/// Normalizes pasted text for insertion into the input.
+fn normalize_pasted_text(text: &str, multiline: bool) -> String {
+    // Terminals commonly send carriage returns for pasted newlines.
+    let text = text.replace("\r\n", "\n").replace('\r', "\n");
+    if multiline {
+        text
+    } else {
+        text.replace('\n', " ")
     }
 }
 
+/// The default maximum number of edits which can be undone.
+const DEFAULT_HISTORY_LIMIT: usize = 100;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum HistoryAction {
+    Undo,
+    Redo,
+}
+
+/// The kind of an edit, used to coalesce consecutive edits into a single undo step.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum EditKind {
+    Typing,
+    Deleting,
+    Other,
+}
+
+/// A snapshot of the input, taken before an edit.
+#[derive(Clone, Debug, PartialEq)]
+struct HistoryEntry {
+    value: String,
+    cursor_offset: usize,
+}
+
+/// The undo and redo stacks of an input.
 #[derive(Default)]
-struct UseSizeImpl {
-    size: (u16, u16),
+struct UndoHistory {
+    undo: VecDeque<HistoryEntry>,
+    redo: Vec<HistoryEntry>,
+    last_edit_kind: Option<EditKind>,
 }
 
-impl Hook for UseSizeImpl {
-    fn pre_component_draw(&mut self, drawer: &mut ComponentDrawer) {
-        let s = drawer.size();
-        self.size = (s.width, s.height);
+impl UndoHistory {
+    /// Records the state of the input before an edit of the given kind.
+    ///
+    /// Consecutive typing or deleting is coalesced, so only the state before the first of those
+    /// edits is recorded.
+    fn record(&mut self, value: &str, cursor_offset: usize, kind: EditKind, limit: usize) {
+        self.redo.clear();
+        if kind != EditKind::Other && self.last_edit_kind == Some(kind) {
+            return;
+        }
+        self.last_edit_kind = Some(kind);
+        self.undo.push_back(HistoryEntry {
+            value: value.to_string(),
+            cursor_offset,
+        });
+        while self.undo.len() > limit {
+            self.undo.pop_front();
+        }
+    }
+
+    /// Ends any in-progress coalescing, so that the next edit is recorded as its own step.
+    fn break_coalescing(&mut self) {
+        self.last_edit_kind = None;
+    }
+
+    /// Pops the state to restore for an undo or redo, pushing the current state onto the opposite
+    /// stack.
+    fn apply(
+        &mut self,
+        action: HistoryAction,
+        value: &str,
+        cursor_offset: usize,
+    ) -> Option<HistoryEntry> {
+        let current = HistoryEntry {
+            value: value.to_string(),
+            cursor_offset,
+        };
+        self.last_edit_kind = None;
+        match action {
+            HistoryAction::Undo => {
+                let entry = self.undo.pop_back()?;
+                self.redo.push(current);
+                Some(entry)
+            }
+            HistoryAction::Redo => {
+                let entry = self.redo.pop()?;
+                self.undo.push_back(current);
+                Some(entry)
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        *self = Self::default();
     }
 }
 
@@ -137,6 +524,8 @@ struct TextBufferRow {
     offset: usize,
     len: usize,
     width: usize,
+    /// The visual column of each grapheme cluster in the row, computed when first needed.
+    visual_columns: OnceLock<Vec<(usize, usize)>>,
 }
 
 #[derive(Default)]
@@ -146,10 +535,14 @@ struct TextBuffer {
 }
 
 impl TextBuffer {
-    fn new<S: Into<String>>(text: S, width: usize) -> Self {
+    fn new<S: Into<String>>(text: S, width: usize, wrap: TextWrap) -> Self {
         let text = text.into();
         let s = SegmentedString::from(text.as_str());
-        let lines = s.wrap(width);
+        let lines = if wrap == TextWrap::BreakAnywhere {
+            s.break_anywhere(width)
+        } else {
+            s.wrap(width)
+        };
         let mut rows: Vec<TextBufferRow> = Vec::with_capacity(lines.len());
         for line in lines {
             rows.push(TextBufferRow {
@@ -160,18 +553,33 @@ impl TextBuffer {
                     .unwrap_or_else(|| rows.last().map_or(0, |r| r.offset)),
                 len: line.segments.first().map_or(0, |s| s.text.len()),
                 width: line.width,
+                visual_columns: OnceLock::new(),
             });
         }
         Self { rows, text }
     }
 
+    /// Returns the visual column of each grapheme cluster in the row, as pairs of the grapheme
+    /// cluster's byte offset within the row and its column, sorted by byte offset.
+    fn visual_columns<'a>(&'a self, row: &'a TextBufferRow) -> &'a [(usize, usize)] {
+        row.visual_columns
+            .get_or_init(|| bidi::visual_columns(&self.text[row.offset..row.offset + row.len]))
+    }
+
     fn row_column_for_offset(&self, offset: usize) -> (u16, u16) {
         for (i, row) in self.rows.iter().enumerate() {
             if offset >= row.offset {
                 let offset_in_row = offset - row.offset;
                 if offset_in_row <= row.len {
-                    let col = self.text[row.offset..offset].width() as u16;
-                    return (i as _, col);
+                    let col = match self
+                        .visual_columns(row)
+                        .iter()
+                        .find(|(idx, _)| *idx == offset_in_row)
+                    {
+                        Some((_, col)) => *col,
+                        None => self.text[row.offset..offset].width(),
+                    };
+                    return (i as _, col as _);
                 }
             }
         }
@@ -181,6 +589,17 @@ impl TextBuffer {
         )
     }
 
+    /// Returns the rows of the buffer in visual order, for display.
+    fn visual_lines(&self) -> impl Iterator<Item = String> + '_ {
+        self.lines().map(|line| {
+            bidi::reorder_line(&[line])
+                .runs
+                .into_iter()
+                .map(|(_, s)| s)
+                .collect()
+        })
+    }
+
     fn lines(&self) -> impl Iterator<Item = &str> {
         self.rows.iter().map(move |row| {
             let start = row.offset;
@@ -190,25 +609,11 @@ impl TextBuffer {
     }
 
     fn left_of_offset(&self, offset: usize) -> usize {
-        if offset == 0 {
-            0
-        } else {
-            self.text[..offset]
-                .char_indices()
-                .last()
-                .map_or(0, |(i, _)| i)
-        }
+        previous_grapheme_boundary(&self.text, offset)
     }
 
     fn right_of_offset(&self, offset: usize) -> usize {
-        if offset >= self.text.len() {
-            self.text.len()
-        } else {
-            self.text[offset..]
-                .char_indices()
-                .nth(1)
-                .map_or(self.text.len(), |(i, _)| offset + i)
-        }
+        next_grapheme_boundary(&self.text, offset)
     }
 
     fn offset_for_closest_column_in_row(&self, row: u16, col: u16) -> usize {
@@ -217,14 +622,11 @@ impl TextBuffer {
         if col >= row.width {
             row.offset + row.len
         } else {
-            let mut width = 0;
-            for (idx, c) in self.text[row.offset..].char_indices() {
-                if width >= col {
-                    return row.offset + idx;
-                }
-                width += c.width().unwrap_or(0);
-            }
-            row.offset + row.len
+            self.visual_columns(row)
+                .iter()
+                .filter(|(_, c)| *c >= col)
+                .min_by_key(|(_, c)| *c)
+                .map_or(row.offset + row.len, |(idx, _)| row.offset + idx)
         }
     }
 
@@ -254,22 +656,54 @@ impl TextBuffer {
         let r = &self.rows[row as usize];
         r.offset + r.len
     }
+
+    /// Returns the cells covered by the given range as spans of rows, columns, and widths.
+    ///
+    /// A range within a single row may cover multiple spans if it contains bidirectional text.
+    fn selection_spans(&self, range: Range<usize>) -> Vec<(u16, u16, u16)> {
+        let mut spans: Vec<(u16, u16, u16)> = Vec::new();
+        for (i, row) in self.rows.iter().enumerate() {
+            if row.offset >= range.end || row.offset + row.len <= range.start {
+                continue;
+            }
+            let row_text = &self.text[row.offset..row.offset + row.len];
+            let mut cells = self
+                .visual_columns(row)
+                .iter()
+                .filter(|(idx, _)| range.contains(&(row.offset + idx)))
+                .map(|&(idx, col)| {
+                    let width = row_text[idx..]
+                        .graphemes(true)
+                        .next()
+                        .map_or(0, |g| g.width());
+                    (col as u16, width as u16)
+                })
+                .filter(|(_, width)| *width > 0)
+                .collect::<Vec<_>>();
+            cells.sort_unstable();
+            for (col, width) in cells {
+                match spans.last_mut() {
+                    Some((r, c, w)) if *r == i as u16 && *c + *w == col => *w += width,
+                    _ => spans.push((i as _, col, width)),
+                }
+            }
+        }
+        spans
+    }
 }
 
 #[derive(Default, Props)]
 struct TextBufferViewProps {
-    color: Option<Color>,
-    weight: Weight,
-    underline: bool,
-    italic: bool,
-    invert: bool,
+    style: CanvasTextStyle,
     buffer: Arc<TextBuffer>,
+    mask: Option<char>,
 }
 
 #[derive(Default)]
 struct TextBufferView {
     text_style: CanvasTextStyle,
     buffer: Arc<TextBuffer>,
+    mask: Option<char>,
 }
 
 impl Component for TextBufferView {
@@ -285,14 +719,9 @@ impl Component for TextBufferView {
         _hooks: Hooks,
         updater: &mut ComponentUpdater,
     ) {
-        self.text_style = CanvasTextStyle {
-            color: props.color,
-            weight: props.weight,
-            underline: props.underline,
-            italic: props.italic,
-            invert: props.invert,
-        };
+        self.text_style = props.style;
         self.buffer = props.buffer.clone();
+        self.mask = props.mask;
         updater.set_layout_style(
             LayoutStyle {
                 position: Position::Absolute,
@@ -306,7 +735,18 @@ impl Component for TextBufferView {
 
     fn draw(&mut self, drawer: &mut ComponentDrawer<'_>) {
         let mut drawer = TextDrawer::new(drawer, 0, false);
-        drawer.append_lines(self.buffer.lines(), self.text_style);
+        let lines = self
+            .buffer
+            .visual_lines()
+            .map(|line| match self.mask {
+                Some(mask) => line
+                    .graphemes(true)
+                    .flat_map(|g| std::iter::repeat_n(mask, g.width()))
+                    .collect(),
+                None => line,
+            })
+            .collect::<Vec<_>>();
+        drawer.append_lines(lines.iter().map(|l| l.as_str()), self.text_style);
     }
 }
 
@@ -315,6 +755,32 @@ impl Component for TextBufferView {
 /// It will fill the available width and display the current value. Typically, you will want to
 /// render it in a [`View`](crate::components::View) component of the desired text field size.
 ///
+/// # Key Bindings
+///
+/// In addition to the arrow keys, Home, End, Backspace, and Delete, the input supports:
+///
+/// - Ctrl-A and Ctrl-E to move to the start and end of the row.
+/// - Ctrl-Left and Ctrl-Right, or Alt-Left and Alt-Right, to move by word. Alt-B and Alt-F do the
+///   same.
+/// - Shift with any of the movement keys to extend the selection, and Alt-A to select everything.
+/// - Ctrl-W to delete the previous word, Ctrl-U to delete to the start of the row, and Ctrl-K to
+///   delete to the end of the row.
+/// - Ctrl-C or Ctrl-Insert to copy the selection, Ctrl-X or Shift-Delete to cut it, and Ctrl-V or
+///   Shift-Insert to paste. Ctrl-C only reaches the input if the render loop was started with
+///   [`ignore_ctrl_c`](crate::RenderLoopFuture::ignore_ctrl_c).
+/// - Ctrl-Z to undo, and Ctrl-Y or Ctrl-Shift-Z to redo. Consecutive typing or deleting is undone
+///   as a single step.
+/// - Up and Down in a single-line input to cycle through the values given via the `history` prop.
+///   Pressing Down past the most recent value restores whatever was being typed.
+/// - Tab, or Right at the end of the input, to accept the selected completion when `completions`
+///   are shown. Up and Down select a different completion, and Esc hides them until the value
+///   changes. While completions are shown, Tab doesn't move focus.
+///
+/// Typing, pasting, or deleting while text is selected replaces the selection. Copied text is
+/// sent to the system clipboard via OSC 52 where the terminal supports it, and is also kept in a
+/// register shared by the components in the same render loop, which is what Ctrl-V and
+/// Shift-Insert paste from. Text pasted via the terminal itself is inserted as usual.
+///
 /// # Example
 ///
 /// ```
@@ -348,12 +814,18 @@ impl Component for TextBufferView {
 #[component]
 pub fn TextInput(mut hooks: Hooks, props: &mut TextInputProps) -> impl Into<AnyElement<'static>> {
     let multiline = props.multiline;
-    let has_focus = props.has_focus;
+    let focus = hooks.use_focus_if(props.focusable);
+    let has_focus = if props.focusable {
+        focus.is_focused()
+    } else {
+        props.has_focus
+    };
     let wrap = if multiline {
-        TextWrap::Wrap
+        props.wrap.unwrap_or_default()
     } else {
         TextWrap::NoWrap
     };
+    let is_wrapping = matches!(wrap, TextWrap::Wrap | TextWrap::BreakAnywhere);
 
     let mut prev_value = hooks.use_state(|| "".to_string());
     let mut cursor_offset = hooks.use_state(|| 0usize);
@@ -362,18 +834,44 @@ pub fn TextInput(mut hooks: Hooks, props: &mut TextInputProps) -> impl Into<AnyE
     let mut scroll_offset_row = hooks.use_state(|| 0u16);
     let mut scroll_offset_col = hooks.use_state(|| 0u16);
     let mut vertical_movement_col_preference = hooks.use_state(|| None);
-    let (width, height) = hooks.use_size();
+    let mut selection_anchor = hooks.use_state(|| None);
+    let mut undo_history = hooks.use_state(UndoHistory::default);
+    let mut requested_history_action = hooks.use_state(|| None);
+    let history_limit = props.history_limit.unwrap_or(DEFAULT_HISTORY_LIMIT);
+    // Re-render when the size changes so that the scroll offsets can be updated to keep the cursor
+    // visible.
+    let size = hooks.use_size().get();
+    let (width, height) = (size.width, size.height);
+    let clipboard = hooks.use_clipboard();
+    let mut history_index = hooks.use_state(|| None::<usize>);
+    let mut history_draft = hooks.use_state(String::new);
+    let mut completion_query = hooks.use_state(|| None::<String>);
+    let mut completion_results = hooks.use_state(|| None::<(String, Vec<String>)>);
+    let mut completion_index = hooks.use_state(|| 0usize);
+    let mut completions_dismissed = hooks.use_state(|| false);
+    let fetch_completions = hooks.use_async_handler(
+        move |(query, results): (String, BoxFuture<'static, Vec<String>>)| async move {
+            let results = results.await;
+            // Discard the results if the value has changed since they were requested.
+            if completion_query.read().as_deref() == Some(query.as_str()) {
+                completion_results.set(Some((query, results)));
+            }
+        },
+    );
 
     if let Some(handle_ref) = props.handle.as_mut() {
         handle_ref.set(TextInputHandle {
             inner: Some(TextInputHandleInner {
                 cursor_offset,
                 requested_cursor_offset,
+                selection_anchor,
+                undo_history,
+                requested_history_action,
             }),
         });
     }
 
-    let max_text_width = if wrap == TextWrap::Wrap {
+    let max_text_width = if is_wrapping {
         // Reserve the last column for the cursor.
         width.max(1) - 1
     } else {
@@ -383,9 +881,9 @@ pub fn TextInput(mut hooks: Hooks, props: &mut TextInputProps) -> impl Into<AnyE
     let buffer = hooks.use_memo(
         {
             let text = props.value.clone();
-            move || Arc::new(TextBuffer::new(text, max_text_width as _))
+            move || Arc::new(TextBuffer::new(text, max_text_width as _, wrap))
         },
-        (&props.value, max_text_width),
+        (&props.value, max_text_width, wrap),
     );
 
     // Update the cursor position if the value has changed.
@@ -394,13 +892,16 @@ pub fn TextInput(mut hooks: Hooks, props: &mut TextInputProps) -> impl Into<AnyE
             &prev_value.read(),
             cursor_offset.get(),
             &props.value,
-            new_cursor_offset_hint.get(),
+            &new_cursor_offset_hint.read(),
         );
         if cursor_offset != new_cursor_offset {
             cursor_offset.set(new_cursor_offset);
         }
         prev_value.set(props.value.clone());
         new_cursor_offset_hint.set(NewCursorOffsetHint::None);
+        if selection_anchor.get().is_some() {
+            selection_anchor.set(None);
+        }
     }
 
     // Update the cursor position if the user requested it.
@@ -411,45 +912,159 @@ pub fn TextInput(mut hooks: Hooks, props: &mut TextInputProps) -> impl Into<AnyE
         requested_cursor_offset.set(None);
     }
 
+    // Apply any undo or redo requested via the handle.
+    if let Some(action) = requested_history_action.get() {
+        requested_history_action.set(None);
+        let entry = undo_history
+            .write()
+            .apply(action, &props.value, cursor_offset.get());
+        if let Some(entry) = entry {
+            new_cursor_offset_hint.set(NewCursorOffsetHint::Edit {
+                value: entry.value.clone(),
+                cursor_offset: entry.cursor_offset,
+            });
+            (props.on_change)(entry.value);
+        }
+    }
+
+    // Request new completions if the value has changed.
+    let completions = props.completions.0.as_ref().filter(|_| !multiline);
+    if let Some(provider) = completions {
+        if completion_query.read().as_deref() != Some(props.value.as_str()) {
+            completion_query.set(Some(props.value.clone()));
+            if completion_index.get() != 0 {
+                completion_index.set(0);
+            }
+            if completions_dismissed.get() {
+                completions_dismissed.set(false);
+            }
+            match provider {
+                CompletionsProvider::Sync(f) => {
+                    completion_results.set(Some((props.value.clone(), f(&props.value))));
+                }
+                CompletionsProvider::Async(f) => {
+                    fetch_completions((props.value.clone(), f(props.value.clone())));
+                }
+            }
+        }
+    }
+    let candidates = match &*completion_results.read() {
+        Some((query, results)) if completions.is_some() && *query == props.value => results
+            .iter()
+            .filter(|candidate| **candidate != props.value)
+            .cloned()
+            .collect(),
+        _ => Vec::new(),
+    };
+    let show_completions = has_focus && !completions_dismissed.get() && !candidates.is_empty();
+    let selected_completion = completion_index
+        .get()
+        .min(candidates.len().saturating_sub(1));
+    if show_completions {
+        focus.capture_tab();
+    }
+
+    if let Some(anchor) = selection_anchor.get() {
+        if anchor > props.value.len() {
+            selection_anchor.set(Some(props.value.len()));
+        }
+    }
+    let selection_spans = selection_range(selection_anchor.get(), cursor_offset.get())
+        .map(|selection| buffer.selection_spans(selection))
+        .unwrap_or_default();
+
     let (cursor_row, mut cursor_col) = buffer.row_column_for_offset(cursor_offset.get());
 
     // If we're wrapping, don't let the cursor go past the visible area. No non-whitespace
     // characters will extend that far.
-    if wrap == TextWrap::Wrap && cursor_col >= width && width > 0 {
+    if is_wrapping && cursor_col >= width && width > 0 {
         cursor_col = width - 1;
     }
 
+    // Suggest the rest of the selected completion inline if it continues the value.
+    let completion_suffix = candidates
+        .get(selected_completion)
+        .filter(|_| {
+            show_completions
+                && cursor_offset.get() == props.value.len()
+                && selection_range(selection_anchor.get(), cursor_offset.get()).is_none()
+        })
+        .and_then(|candidate| candidate.strip_prefix(props.value.as_str()))
+        .filter(|suffix| !suffix.is_empty())
+        .map(|suffix| suffix.to_string());
+
+    // Window the dropdown around the selected completion.
+    let completion_rows = if show_completions {
+        candidates.len().min(MAX_VISIBLE_COMPLETIONS)
+    } else {
+        0
+    };
+    let first_visible_completion = (selected_completion + 1).saturating_sub(completion_rows);
+
+    // Keep the cursor out of the columns occupied by overflow indicators.
+    let show_overflow_indicators = props.overflow_indicators && !multiline && width > 2;
+    let margin = if show_overflow_indicators { 1 } else { 0 };
+
     // Update the offset if the cursor is out of bounds.
+    let content_width = props.value.width() as u16;
     if width > 0 && height > 0 {
         if cursor_row >= scroll_offset_row.get() + height {
             scroll_offset_row.set(cursor_row - height + 1);
         } else if cursor_row < scroll_offset_row.get() {
             scroll_offset_row.set(cursor_row as _);
         }
-        if cursor_col >= scroll_offset_col.get() + width {
-            scroll_offset_col.set(cursor_col - width + 1);
-        } else if cursor_col < scroll_offset_col.get() {
-            scroll_offset_col.set(cursor_col as _);
+        let mut col = scroll_offset_col.get();
+        if cursor_col + margin >= col + width {
+            col = cursor_col + margin - width + 1;
+        } else if cursor_col < col + margin {
+            col = cursor_col.saturating_sub(margin);
         }
 
         // Scroll back when the content shrinks so it isn't cut off.
-        let content_width = props.value.width() as u16;
         let max_scroll_offset_col = (content_width + 1).saturating_sub(width);
-        if scroll_offset_col.get() > max_scroll_offset_col {
-            scroll_offset_col.set(max_scroll_offset_col);
+        col = col.min(max_scroll_offset_col);
+        if scroll_offset_col.get() != col {
+            scroll_offset_col.set(col);
         }
     }
+    let is_clipped_left = show_overflow_indicators && scroll_offset_col.get() > 0;
+    let is_clipped_right =
+        show_overflow_indicators && content_width > scroll_offset_col.get() + width;
 
     hooks.use_terminal_events({
-        let buffer = buffer.clone();
+        let mut buffer = buffer.clone();
         let mut value = props.value.clone();
         let mut temp_cursor_offset = cursor_offset.get();
         let mut on_change = props.on_change.take();
+        let mut on_submit = props.on_submit.take();
+        let mut filter = std::mem::take(&mut props.filter);
+        let max_length = props.max_length;
+        let can_copy = props.mask.is_none();
+        let history = props.history.clone();
+        let completions_value = props.value.clone();
+        let candidates = candidates.clone();
         move |event| {
             if !has_focus {
                 return;
             }
 
+            let cursor = temp_cursor_offset;
+            let selection = selection_range(selection_anchor.get(), cursor);
+            let selection_or_empty = selection.clone().unwrap_or(cursor..cursor);
+            let completing =
+                show_completions && !completions_dismissed.get() && value == completions_value;
+            let selected = completion_index
+                .get()
+                .min(candidates.len().saturating_sub(1));
+            let completion = candidates.get(selected).filter(|_| completing);
+            let mut new_offset = None;
+            let mut extend_selection = false;
+            let mut edit: Option<(Range<usize>, String)> = None;
+            let mut edit_kind = EditKind::Other;
+            let mut history_action = None;
+            let mut recall: Option<(Option<usize>, String)> = None;
+            let mut clear_vertical_movement_col_preference = true;
+
             match event {
                 TerminalEvent::Key(KeyEvent {
                     code,
@@ -459,16 +1074,60 @@ pub fn TextInput(mut hooks: Hooks, props: &mut TextInputProps) -> impl Into<AnyE
                 }) if kind != KeyEventKind::Release
                     && modifiers.contains(KeyModifiers::CONTROL) =>
                 {
+                    extend_selection = modifiers.contains(KeyModifiers::SHIFT);
                     match code {
                         KeyCode::Char('a') => {
-                            cursor_offset.set(buffer.row_start_offset(cursor_offset.get()));
-                            vertical_movement_col_preference.set(None);
+                            new_offset = Some(buffer.row_start_offset(cursor));
                         }
                         KeyCode::Char('e') => {
-                            cursor_offset.set(buffer.row_end_offset(cursor_offset.get()));
-                            vertical_movement_col_preference.set(None);
+                            new_offset = Some(buffer.row_end_offset(cursor));
+                        }
+                        KeyCode::Left => {
+                            new_offset = Some(previous_word_boundary(&value, cursor));
+                        }
+                        KeyCode::Right => {
+                            new_offset = Some(next_word_boundary(&value, cursor));
+                        }
+                        KeyCode::Char('w') => {
+                            let range = selection
+                                .unwrap_or_else(|| previous_word_boundary(&value, cursor)..cursor);
+                            edit = Some((range, String::new()));
+                        }
+                        KeyCode::Char('u') => {
+                            edit = Some((buffer.row_start_offset(cursor)..cursor, String::new()));
+                        }
+                        KeyCode::Char('k') => {
+                            let mut end = buffer.row_end_offset(cursor);
+                            if end == cursor && value[cursor..].starts_with('\n') {
+                                end += 1;
+                            }
+                            edit = Some((cursor..end, String::new()));
+                        }
+                        KeyCode::Char('c') | KeyCode::Insert => {
+                            if let Some(selection) = selection.filter(|_| can_copy) {
+                                clipboard.copy(&value[selection]);
+                            }
+                            clear_vertical_movement_col_preference = false;
+                        }
+                        KeyCode::Char('x') => {
+                            if let Some(selection) = selection.filter(|_| can_copy) {
+                                clipboard.copy(&value[selection.clone()]);
+                                edit = Some((selection, String::new()));
+                            }
+                        }
+                        KeyCode::Char('v') => {
+                            let text = normalize_pasted_text(&clipboard.paste(), multiline);
+                            edit = Some((selection_or_empty, text));
+                        }
+                        KeyCode::Char('z') if !extend_selection => {
+                            history_action = Some(HistoryAction::Undo);
+                        }
+                        KeyCode::Char('y') | KeyCode::Char('z') | KeyCode::Char('Z') => {
+                            history_action = Some(HistoryAction::Redo);
+                        }
+                        _ => {
+                            clear_vertical_movement_col_preference = false;
                         }
-                        _ => {}
                     }
                 }
                 TerminalEvent::Key(KeyEvent {
@@ -476,92 +1135,272 @@ pub fn TextInput(mut hooks: Hooks, props: &mut TextInputProps) -> impl Into<AnyE
                     kind,
                     modifiers,
                     ..
-                }) if kind != KeyEventKind::Release
-                    && !modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
-                {
-                    let mut clear_vertical_movement_col_preference = true;
+                }) if kind != KeyEventKind::Release && modifiers.contains(KeyModifiers::ALT) => {
+                    extend_selection = modifiers.contains(KeyModifiers::SHIFT);
+                    match code {
+                        KeyCode::Left | KeyCode::Char('b') => {
+                            new_offset = Some(previous_word_boundary(&value, cursor));
+                        }
+                        KeyCode::Right | KeyCode::Char('f') => {
+                            new_offset = Some(next_word_boundary(&value, cursor));
+                        }
+                        KeyCode::Char('a') => {
+                            selection_anchor.set(Some(0));
+                            new_offset = Some(value.len());
+                            extend_selection = true;
+                        }
+                        _ => {
+                            clear_vertical_movement_col_preference = false;
+                        }
+                    }
+                }
+                TerminalEvent::Key(KeyEvent {
+                    code,
+                    kind,
+                    modifiers,
+                    ..
+                }) if kind != KeyEventKind::Release => {
+                    extend_selection = modifiers.contains(KeyModifiers::SHIFT);
 
                     match code {
+                        KeyCode::Tab if completion.is_some() => {
+                            edit = completion.map(|c| (0..value.len(), c.clone()));
+                        }
+                        KeyCode::Right
+                            if !extend_selection
+                                && selection.is_none()
+                                && cursor == value.len()
+                                && completion.is_some_and(|c| c.starts_with(value.as_str())) =>
+                        {
+                            edit = completion.map(|c| (0..value.len(), c.clone()));
+                        }
+                        KeyCode::Up if completing && candidates.len() > 1 => {
+                            completion_index
+                                .set((selected + candidates.len() - 1) % candidates.len());
+                        }
+                        KeyCode::Down if completing && candidates.len() > 1 => {
+                            completion_index.set((selected + 1) % candidates.len());
+                        }
+                        KeyCode::Esc if completing => {
+                            completions_dismissed.set(true);
+                        }
+                        KeyCode::Up if !multiline && !history.is_empty() => {
+                            let index = match history_index.get() {
+                                Some(index) => index.min(history.len()).saturating_sub(1),
+                                None => {
+                                    history_draft.set(value.clone());
+                                    history.len() - 1
+                                }
+                            };
+                            recall = Some((Some(index), history[index].clone()));
+                        }
+                        KeyCode::Down if !multiline && history_index.get().is_some() => {
+                            recall = Some(match history_index.get() {
+                                Some(index) if index + 1 < history.len() => {
+                                    (Some(index + 1), history[index + 1].clone())
+                                }
+                                _ => (None, history_draft.read().clone()),
+                            });
+                        }
                         KeyCode::Char(c) => {
-                            value.insert(temp_cursor_offset, c);
-                            temp_cursor_offset += c.len_utf8();
-                            on_change(value.clone());
+                            if selection.is_none() {
+                                edit_kind = EditKind::Typing;
+                            }
+                            edit = Some((selection_or_empty, c.to_string()));
                         }
                         KeyCode::Backspace => {
-                            if temp_cursor_offset > 0 {
-                                temp_cursor_offset -= value[..temp_cursor_offset]
-                                    .chars()
-                                    .last()
-                                    .unwrap()
-                                    .len_utf8();
-                                value.remove(temp_cursor_offset);
+                            if selection.is_none() {
+                                edit_kind = EditKind::Deleting;
+                            }
+                            let range = selection.unwrap_or_else(|| {
+                                previous_grapheme_boundary(&value, cursor)..cursor
+                            });
+                            edit = Some((range, String::new()));
+                        }
+                        KeyCode::Delete if extend_selection => {
+                            if let Some(selection) = selection.filter(|_| can_copy) {
+                                clipboard.copy(&value[selection.clone()]);
+                                edit = Some((selection, String::new()));
                             }
-                            on_change(value.clone());
-                            new_cursor_offset_hint.set(NewCursorOffsetHint::Backspace);
                         }
                         KeyCode::Delete => {
-                            if temp_cursor_offset < value.len() {
-                                value.remove(temp_cursor_offset);
+                            if selection.is_none() {
+                                edit_kind = EditKind::Deleting;
                             }
-                            on_change(value.clone());
-                            new_cursor_offset_hint.set(NewCursorOffsetHint::Deletion);
+                            let range = selection
+                                .unwrap_or_else(|| cursor..next_grapheme_boundary(&value, cursor));
+                            edit = Some((range, String::new()));
+                        }
+                        KeyCode::Insert if extend_selection => {
+                            let text = normalize_pasted_text(&clipboard.paste(), multiline);
+                            edit = Some((selection_or_empty, text));
                         }
                         KeyCode::Enter if multiline => {
-                            value.insert(temp_cursor_offset, '\n');
-                            temp_cursor_offset += 1;
-                            on_change(value.clone());
+                            edit = Some((selection_or_empty, "\n".to_string()));
+                        }
+                        KeyCode::Enter => {
+                            if history_index.get().is_some() {
+                                history_index.set(None);
+                            }
+                            on_submit(value.clone());
                         }
                         KeyCode::Left => {
-                            cursor_offset.set(buffer.left_of_offset(cursor_offset.get()));
+                            new_offset = Some(match selection {
+                                Some(selection) if !extend_selection => selection.start,
+                                _ => buffer.left_of_offset(cursor),
+                            });
                         }
                         KeyCode::Right => {
-                            cursor_offset.set(buffer.right_of_offset(cursor_offset.get()));
+                            new_offset = Some(match selection {
+                                Some(selection) if !extend_selection => selection.end,
+                                _ => buffer.right_of_offset(cursor),
+                            });
                         }
                         KeyCode::Up if multiline => {
                             clear_vertical_movement_col_preference = false;
                             if vertical_movement_col_preference.get().is_none() {
-                                let (_, col) = buffer.row_column_for_offset(cursor_offset.get());
+                                let (_, col) = buffer.row_column_for_offset(cursor);
                                 vertical_movement_col_preference.set(Some(col));
                             }
-                            cursor_offset.set(buffer.above_offset(
-                                cursor_offset.get(),
-                                vertical_movement_col_preference.get(),
-                            ));
+                            new_offset = Some(
+                                buffer.above_offset(cursor, vertical_movement_col_preference.get()),
+                            );
                         }
                         KeyCode::Down if multiline => {
                             clear_vertical_movement_col_preference = false;
                             if vertical_movement_col_preference.get().is_none() {
-                                let (_, col) = buffer.row_column_for_offset(cursor_offset.get());
+                                let (_, col) = buffer.row_column_for_offset(cursor);
                                 vertical_movement_col_preference.set(Some(col));
                             }
-                            cursor_offset.set(buffer.below_offset(
-                                cursor_offset.get(),
-                                vertical_movement_col_preference.get(),
-                            ));
+                            new_offset = Some(
+                                buffer.below_offset(cursor, vertical_movement_col_preference.get()),
+                            );
                         }
                         KeyCode::Home => {
-                            cursor_offset.set(buffer.row_start_offset(cursor_offset.get()));
+                            new_offset = Some(buffer.row_start_offset(cursor));
                         }
                         KeyCode::End => {
-                            cursor_offset.set(buffer.row_end_offset(cursor_offset.get()));
+                            new_offset = Some(buffer.row_end_offset(cursor));
                         }
                         _ => {
                             clear_vertical_movement_col_preference = false;
                         }
                     }
+                }
+                TerminalEvent::Paste(text) => {
+                    edit = Some((selection_or_empty, normalize_pasted_text(&text, multiline)));
+                }
+                _ => {
+                    clear_vertical_movement_col_preference = false;
+                }
+            }
 
-                    if clear_vertical_movement_col_preference {
-                        vertical_movement_col_preference.set(None);
+            let mut new_value = None;
+            if let Some((range, mut text)) = edit {
+                if let Some(max_length) = max_length {
+                    let len = value.graphemes(true).count()
+                        - value[range.clone()].graphemes(true).count();
+                    if let Some((idx, _)) = text
+                        .grapheme_indices(true)
+                        .nth(max_length.saturating_sub(len))
+                    {
+                        text.truncate(idx);
                     }
                 }
-                _ => {}
+                if !range.is_empty() || !text.is_empty() {
+                    let mut edited_value = value.clone();
+                    edited_value.replace_range(range.clone(), &text);
+                    let mut edited_cursor_offset = range.start + text.len();
+                    if let Some(filtered_value) = filter.apply(edited_value.clone()) {
+                        if filtered_value != edited_value {
+                            edited_cursor_offset = new_cursor_offset(
+                                &value,
+                                cursor,
+                                &filtered_value,
+                                &NewCursorOffsetHint::None,
+                            );
+                        }
+                        if history_limit > 0 {
+                            undo_history
+                                .write()
+                                .record(&value, cursor, edit_kind, history_limit);
+                        }
+                        if history_index.get().is_some() {
+                            history_index.set(None);
+                        }
+                        new_value = Some((filtered_value, edited_cursor_offset));
+                    }
+                }
+            } else if let Some(action) = history_action {
+                let entry = undo_history.write().apply(action, &value, cursor);
+                new_value = entry.map(|entry| (entry.value, entry.cursor_offset));
+            } else if let Some((index, recalled_value)) = recall {
+                history_index.set(index);
+                if recalled_value != value {
+                    if history_limit > 0 {
+                        undo_history
+                            .write()
+                            .record(&value, cursor, EditKind::Other, history_limit);
+                    }
+                    let recalled_cursor_offset = recalled_value.len();
+                    new_value = Some((recalled_value, recalled_cursor_offset));
+                }
+            }
+
+            if let Some((edited_value, edited_cursor_offset)) = new_value {
+                value = edited_value;
+                temp_cursor_offset = edited_cursor_offset;
+                new_cursor_offset_hint.set(NewCursorOffsetHint::Edit {
+                    value: value.clone(),
+                    cursor_offset: temp_cursor_offset,
+                });
+                if selection_anchor.get().is_some() {
+                    selection_anchor.set(None);
+                }
+                // Keep the buffer in sync so that subsequent events in the same batch see
+                // the new value.
+                buffer = Arc::new(TextBuffer::new(value.as_str(), max_text_width as _, wrap));
+                on_change(value.clone());
+            } else if let Some(offset) = new_offset {
+                if undo_history.read().last_edit_kind.is_some() {
+                    undo_history.write().break_coalescing();
+                }
+                if !extend_selection {
+                    if selection_anchor.get().is_some() {
+                        selection_anchor.set(None);
+                    }
+                } else if selection_anchor.get().is_none() {
+                    selection_anchor.set(Some(cursor));
+                }
+                temp_cursor_offset = offset;
+                cursor_offset.set(offset);
+            }
+
+            if clear_vertical_movement_col_preference {
+                vertical_movement_col_preference.set(None);
             }
         }
     });
 
+    let text_style = CanvasTextStyle {
+        color: props.color,
+        weight: props.weight,
+        underline_style: props.decoration.underline_style(),
+        underline_color: props.underline_color,
+        italic: props.italic,
+        invert: props.invert,
+        strikethrough: props.strikethrough,
+        overline: props.overline,
+        blink: props.blink,
+        hidden: props.hidden,
+    };
+
     element! {
-        View(overflow: Overflow::Hidden, width: 100pct, height: if multiline { Size::Percent(100.0) } else { Size::Length(1) }, position: Position::Relative) {
+        View(overflow: Overflow::Hidden, width: 100pct, height: if multiline { Size::Percent(100.0) } else { Size::Length(1 + completion_rows as u32) }, position: Position::Relative) {
             View(position: Position::Absolute, top: -(scroll_offset_row.get() as i32), left: -(scroll_offset_col.get() as i32)) {
+                #(selection_spans.into_iter().map(|(row, col, width)| element! {
+                    View(position: Position::Absolute, top: row, left: col, width, height: 1, background_color: props.selection_color.unwrap_or(Color::Blue))
+                }))
                 #(if has_focus {
                     Some(element! {
                         View(position: Position::Absolute, top: cursor_row, left: cursor_col, width: 1, height: 1, background_color: props.cursor_color.unwrap_or(Color::Grey))
@@ -569,33 +1408,74 @@ pub fn TextInput(mut hooks: Hooks, props: &mut TextInputProps) -> impl Into<AnyE
                 } else {
                     None
                 })
-                TextBufferView(
-                    buffer,
-                    color: props.color,
-                    weight: props.weight,
-                    underline: props.decoration == TextDecoration::Underline,
-                    italic: props.italic,
-                    invert: props.invert,
-                )
+                #(completion_suffix.map(|suffix| element! {
+                    View(position: Position::Absolute, top: cursor_row, left: cursor_col) {
+                        Text(
+                            content: suffix,
+                            color: props.placeholder_color.unwrap_or(Color::DarkGrey),
+                            wrap: TextWrap::NoWrap,
+                        )
+                    }
+                }))
+                TextBufferView(buffer, style: text_style, mask: props.mask)
             }
+            #(is_clipped_left.then(|| element! {
+                View(position: Position::Absolute, top: 0, left: 0) {
+                    Text(content: ELLIPSIS, color: props.color)
+                }
+            }))
+            #(is_clipped_right.then(|| element! {
+                View(position: Position::Absolute, top: 0, left: width as i32 - 1) {
+                    Text(content: ELLIPSIS, color: props.color)
+                }
+            }))
+            #((props.value.is_empty() && !props.placeholder.is_empty()).then(|| element! {
+                View(position: Position::Absolute, top: 0, left: 0, width: 100pct) {
+                    Text(
+                        content: &props.placeholder,
+                        color: props.placeholder_color.unwrap_or(Color::DarkGrey),
+                        wrap: if multiline { TextWrap::Wrap } else { TextWrap::NoWrap },
+                    )
+                }
+            }))
+            #((completion_rows > 0).then(|| element! {
+                View(position: Position::Absolute, top: 1, left: 0, width: 100pct, flex_direction: FlexDirection::Column) {
+                    #(candidates.iter().enumerate().skip(first_visible_completion).take(completion_rows).map(|(i, candidate)| element! {
+                        View(height: 1, background_color: (i == selected_completion).then(|| props.selection_color.unwrap_or(Color::Blue))) {
+                            Text(content: candidate, color: props.color, wrap: TextWrap::NoWrap)
+                        }
+                    }))
+                }
+            }))
         }
     }
 }
 
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 enum NewCursorOffsetHint {
     #[default]
     None,
-    Backspace,
-    Deletion,
+    /// The input changed the value to `value`, leaving the cursor at `cursor_offset`.
+    Edit { value: String, cursor_offset: usize },
 }
 
 fn new_cursor_offset(
     prev_value: &str,
     cursor_offset: usize,
     value: &str,
-    hint: NewCursorOffsetHint,
+    hint: &NewCursorOffsetHint,
 ) -> usize {
+    // If the value is exactly the result of our own edits, we know where the cursor belongs.
+    if let NewCursorOffsetHint::Edit {
+        value: edited_value,
+        cursor_offset,
+    } = hint
+    {
+        if edited_value == value {
+            return *cursor_offset;
+        }
+    }
+
     let has_same_head = value.len() >= cursor_offset
         && value.as_bytes()[..cursor_offset] == prev_value.as_bytes()[..cursor_offset];
 
@@ -608,11 +1488,14 @@ fn new_cursor_offset(
         cursor_offset + (value.len() - prev_value.len())
     } else if value.len() < prev_value.len() && has_same_tail && has_same_head {
         // ambiguous case, could be backspace or deletion
-        if hint == NewCursorOffsetHint::Deletion {
-            cursor_offset
-        } else {
+        match hint {
+            // our own edit left the cursor in place, so it was a deletion
+            NewCursorOffsetHint::Edit {
+                cursor_offset: edited_cursor_offset,
+                ..
+            } if *edited_cursor_offset == cursor_offset => cursor_offset,
             // bias towards backspace
-            cursor_offset - (prev_value.len() - value.len())
+            _ => cursor_offset - (prev_value.len() - value.len()),
         }
     } else if value.len() < prev_value.len() && has_same_tail {
         // backspace
@@ -630,13 +1513,14 @@ fn new_cursor_offset(
 mod tests {
     use super::*;
     use crate::prelude::*;
-    use futures::stream::StreamExt;
+    use futures::stream::{Stream, StreamExt};
     use macro_rules_attribute::apply;
     use smol_macros::test;
 
     #[derive(Default, Props)]
     struct MyComponentProps {
         initial_value: String,
+        overflow_indicators: bool,
     }
 
     #[component]
@@ -654,6 +1538,7 @@ mod tests {
                     has_focus: true,
                     value: value.to_string(),
                     on_change: move |new_value| value.set(new_value),
+                    overflow_indicators: props.overflow_indicators,
                 )
             }
         }
@@ -720,17 +1605,35 @@ mod tests {
     }
 
     #[apply(test!)]
-    async fn test_text_input_overflow() {
-        let actual = element!(MyComponent)
-            .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
-                vec![
-                    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, KeyCode::Char('x'))),
-                    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Repeat, KeyCode::Char('x'))),
-                    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Repeat, KeyCode::Char('x'))),
-                    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Repeat, KeyCode::Char('x'))),
-                    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Repeat, KeyCode::Char('x'))),
-                    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Repeat, KeyCode::Char('x'))),
-                    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Repeat, KeyCode::Char('x'))),
+    async fn test_text_input_grapheme_clusters() {
+        let actual = element! {
+            MyComponent(initial_value: "ab\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}e\u{301}")
+        }
+        .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+            vec![
+                TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, KeyCode::Backspace)),
+                TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, KeyCode::Backspace)),
+                TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, KeyCode::Char('!'))),
+            ],
+        )))
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .await;
+        assert_eq!(actual.last().unwrap(), " ab! \n");
+    }
+
+    #[apply(test!)]
+    async fn test_text_input_overflow() {
+        let actual = element!(MyComponent)
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+                vec![
+                    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, KeyCode::Char('x'))),
+                    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Repeat, KeyCode::Char('x'))),
+                    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Repeat, KeyCode::Char('x'))),
+                    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Repeat, KeyCode::Char('x'))),
+                    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Repeat, KeyCode::Char('x'))),
+                    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Repeat, KeyCode::Char('x'))),
+                    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Repeat, KeyCode::Char('x'))),
                     TerminalEvent::Key(KeyEvent::new(KeyEventKind::Repeat, KeyCode::Char('x'))),
                     TerminalEvent::Key(KeyEvent::new(KeyEventKind::Repeat, KeyCode::Char('x'))),
                     TerminalEvent::Key(KeyEvent::new(KeyEventKind::Repeat, KeyCode::Char('x'))),
@@ -769,6 +1672,24 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[apply(test!)]
+    async fn test_text_input_paste() {
+        let actual = element! {
+            MyComponent(initial_value: "fo")
+        }
+        .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+            vec![
+                TerminalEvent::Paste("o\r\nba".to_string()),
+                TerminalEvent::Paste("r!".to_string()),
+            ],
+        )))
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .await;
+        let expected = vec![" fo \n", " foo bar! \n"];
+        assert_eq!(actual, expected);
+    }
+
     #[apply(test!)]
     async fn test_text_input_multiline_newline() {
         let actual = element!(MyMultilineComponent)
@@ -793,9 +1714,627 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> TerminalEvent {
+        TerminalEvent::Key(KeyEvent {
+            modifiers,
+            ..KeyEvent::new(KeyEventKind::Press, code)
+        })
+    }
+
+    #[apply(test!)]
+    async fn test_text_input_selection() {
+        let actual = element! {
+            MyComponent(initial_value: "foo bar")
+        }
+        .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+            vec![
+                key(KeyCode::Left, KeyModifiers::SHIFT),
+                key(KeyCode::Left, KeyModifiers::SHIFT),
+                key(KeyCode::Left, KeyModifiers::SHIFT),
+                key(KeyCode::Char('x'), KeyModifiers::empty()),
+                key(KeyCode::Char('y'), KeyModifiers::empty()),
+                key(KeyCode::Left, KeyModifiers::CONTROL | KeyModifiers::SHIFT),
+                key(KeyCode::Backspace, KeyModifiers::empty()),
+                key(KeyCode::Char('!'), KeyModifiers::empty()),
+            ],
+        )))
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .await;
+        assert_eq!(actual.last().unwrap(), " foo ! \n");
+    }
+
+    #[apply(test!)]
+    async fn test_text_input_word_navigation_and_kill() {
+        let actual = element! {
+            MyComponent(initial_value: "foo bar baz")
+        }
+        .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+            vec![
+                key(KeyCode::Char('w'), KeyModifiers::CONTROL),
+                key(KeyCode::Left, KeyModifiers::ALT),
+                key(KeyCode::Right, KeyModifiers::CONTROL),
+                key(KeyCode::Char('k'), KeyModifiers::CONTROL),
+                key(KeyCode::Char('b'), KeyModifiers::ALT),
+                key(KeyCode::Char('u'), KeyModifiers::CONTROL),
+                key(KeyCode::Char('!'), KeyModifiers::empty()),
+            ],
+        )))
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .await;
+        assert_eq!(actual.last().unwrap(), " !bar\n");
+    }
+
+    #[apply(test!)]
+    async fn test_text_input_clipboard() {
+        let actual = element! {
+            MyComponent(initial_value: "ab")
+        }
+        .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+            vec![
+                key(KeyCode::Char('a'), KeyModifiers::ALT),
+                key(KeyCode::Char('x'), KeyModifiers::CONTROL),
+                key(KeyCode::Char('v'), KeyModifiers::CONTROL),
+                key(KeyCode::Home, KeyModifiers::SHIFT),
+                key(KeyCode::Insert, KeyModifiers::CONTROL),
+                key(KeyCode::End, KeyModifiers::empty()),
+                key(KeyCode::Insert, KeyModifiers::SHIFT),
+                key(KeyCode::Char('!'), KeyModifiers::empty()),
+            ],
+        )))
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .await;
+        assert_eq!(actual.last().unwrap(), " abab! \n");
+    }
+
+    #[component]
+    fn MySelectAllComponent(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let mut value = hooks.use_state(|| "foo".to_string());
+        let mut handle = hooks.use_ref_default::<TextInputHandle>();
+
+        hooks.use_effect(move || handle.write().select_all(), ());
+
+        if value.read().contains("!") {
+            assert_eq!(handle.read().selection(), None);
+            system.exit();
+        } else if handle.read().selection().is_some() {
+            assert_eq!(handle.read().selection(), Some(0..3));
+        }
+
+        element! {
+            View(height: 1, width: 11, padding_left: 1) {
+                TextInput(
+                    has_focus: true,
+                    value: value.to_string(),
+                    on_change: move |new_value| value.set(new_value),
+                    handle,
+                )
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_text_input_handle_selection() {
+        let actual = element!(MySelectAllComponent)
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+                vec![key(KeyCode::Char('!'), KeyModifiers::empty())],
+            )))
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(actual.last().unwrap(), " ! \n");
+    }
+
+    #[apply(test!)]
+    async fn test_text_input_undo_redo() {
+        let actual = element!(MyComponent)
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+                vec![
+                    key(KeyCode::Char('f'), KeyModifiers::empty()),
+                    key(KeyCode::Char('o'), KeyModifiers::empty()),
+                    key(KeyCode::Char('o'), KeyModifiers::empty()),
+                    key(KeyCode::Backspace, KeyModifiers::empty()),
+                    key(KeyCode::Char('z'), KeyModifiers::CONTROL),
+                    key(KeyCode::Char('z'), KeyModifiers::CONTROL),
+                    key(KeyCode::Char('y'), KeyModifiers::CONTROL),
+                    key(KeyCode::Char('!'), KeyModifiers::empty()),
+                ],
+            )))
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(actual.last().unwrap(), " foo! \n");
+    }
+
+    #[component]
+    fn MyUndoComponent(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let mut value = hooks.use_state(|| "foo".to_string());
+        let mut step = hooks.use_state(|| 0);
+        let mut handle = hooks.use_ref_default::<TextInputHandle>();
+
+        hooks.use_effect(
+            move || match (step.get(), value.read().as_str()) {
+                (0, "foox") => {
+                    handle.write().undo();
+                    step.set(1);
+                }
+                (1, "foo") => {
+                    handle.write().redo();
+                    step.set(2);
+                }
+                _ => {}
+            },
+            value.to_string(),
+        );
+
+        if step.get() == 2 && value.read().as_str() == "foox" {
+            system.exit();
+        }
+
+        element! {
+            View(height: 1, width: 11, padding_left: 1) {
+                TextInput(
+                    has_focus: true,
+                    value: value.to_string(),
+                    on_change: move |new_value| value.set(new_value),
+                    handle,
+                )
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_text_input_handle_undo_redo() {
+        let actual = element!(MyUndoComponent)
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+                vec![key(KeyCode::Char('x'), KeyModifiers::empty())],
+            )))
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(actual.last().unwrap(), " foox \n");
+    }
+
+    #[test]
+    fn test_history() {
+        let mut history = UndoHistory::default();
+        history.record("", 0, EditKind::Typing, 100);
+        history.record("a", 1, EditKind::Typing, 100);
+        history.record("ab", 2, EditKind::Deleting, 100);
+        assert_eq!(history.undo.len(), 2);
+
+        let entry = history.apply(HistoryAction::Undo, "a", 1).unwrap();
+        assert_eq!((entry.value.as_str(), entry.cursor_offset), ("ab", 2));
+        let entry = history.apply(HistoryAction::Undo, "ab", 2).unwrap();
+        assert_eq!((entry.value.as_str(), entry.cursor_offset), ("", 0));
+        assert_eq!(history.apply(HistoryAction::Undo, "", 0), None);
+        let entry = history.apply(HistoryAction::Redo, "", 0).unwrap();
+        assert_eq!((entry.value.as_str(), entry.cursor_offset), ("ab", 2));
+
+        // New edits discard the redo stack.
+        history.record("ab", 2, EditKind::Typing, 100);
+        assert_eq!(history.apply(HistoryAction::Redo, "abc", 3), None);
+
+        // Typing after moving the cursor is a new step.
+        history.break_coalescing();
+        history.record("abc", 1, EditKind::Typing, 100);
+        assert_eq!(history.undo.len(), 3);
+
+        // The oldest entries are dropped once the limit is reached.
+        history.record("axbc", 2, EditKind::Other, 2);
+        assert_eq!(
+            history
+                .undo
+                .iter()
+                .map(|e| e.value.as_str())
+                .collect::<Vec<_>>(),
+            vec!["abc", "axbc"]
+        );
+
+        history.clear();
+        assert!(history.undo.is_empty());
+    }
+
+    #[component]
+    fn MyFilteredComponent(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let mut value = hooks.use_state(|| "".to_string());
+        let mut submitted = hooks.use_state(|| None);
+
+        if let Some(submitted) = submitted.read().as_ref() {
+            assert_eq!(submitted, "ABCD");
+            system.exit();
+        }
+
+        element! {
+            View(height: 1, width: 11, padding_left: 1) {
+                TextInput(
+                    has_focus: true,
+                    value: value.to_string(),
+                    on_change: move |new_value| value.set(new_value),
+                    on_submit: move |value| submitted.set(Some(value)),
+                    filter: |new_value: String| {
+                        (!new_value.contains('x')).then(|| new_value.to_uppercase())
+                    },
+                    max_length: 4,
+                )
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_text_input_filter_and_submit() {
+        let actual = element!(MyFilteredComponent)
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+                vec![
+                    key(KeyCode::Char('a'), KeyModifiers::empty()),
+                    key(KeyCode::Char('x'), KeyModifiers::empty()),
+                    key(KeyCode::Char('b'), KeyModifiers::empty()),
+                    TerminalEvent::Paste("cde".to_string()),
+                    key(KeyCode::Char('f'), KeyModifiers::empty()),
+                    key(KeyCode::Enter, KeyModifiers::empty()),
+                ],
+            )))
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(actual.last().unwrap(), " ABCD \n");
+    }
+
+    #[component]
+    fn MyMaxLengthComponent(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let mut value = hooks.use_state(|| "".to_string());
+        let mut submitted = hooks.use_state(|| false);
+
+        if submitted.get() {
+            system.exit();
+        }
+
+        element! {
+            View(height: 1, width: 11) {
+                TextInput(
+                    has_focus: true,
+                    value: value.to_string(),
+                    on_change: move |new_value| value.set(new_value),
+                    on_submit: move |_| submitted.set(true),
+                    max_length: 3,
+                )
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_text_input_max_length_graphemes() {
+        let actual = element!(MyMaxLengthComponent)
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+                vec![
+                    TerminalEvent::Paste("e\u{301}🇺🇸".to_string()),
+                    TerminalEvent::Paste("a\u{301}bc".to_string()),
+                    key(KeyCode::Enter, KeyModifiers::empty()),
+                ],
+            )))
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(actual.last().unwrap(), "e\u{301}🇺🇸a\u{301} \n");
+    }
+
+    /// Yields before each event so that the component renders in between.
+    fn one_at_a_time(events: Vec<TerminalEvent>) -> impl Stream<Item = TerminalEvent> {
+        futures::stream::iter(events).then(|event| async move {
+            smol::future::yield_now().await;
+            event
+        })
+    }
+
+    #[component]
+    fn MyHistoryComponent(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let mut value = hooks.use_state(|| "".to_string());
+        let mut submitted = hooks.use_state(Vec::<String>::new);
+
+        if submitted.read().len() == 2 {
+            assert_eq!(*submitted.read(), vec!["first", "firstd"]);
+            system.exit();
+        }
+
+        element! {
+            View(height: 1, width: 11, padding_left: 1) {
+                TextInput(
+                    has_focus: true,
+                    value: value.to_string(),
+                    on_change: move |new_value| value.set(new_value),
+                    on_submit: move |value| submitted.write().push(value),
+                    history: vec!["first".to_string(), "second".to_string()],
+                )
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_text_input_history() {
+        let actual = element!(MyHistoryComponent)
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(one_at_a_time(vec![
+                key(KeyCode::Up, KeyModifiers::empty()),
+                key(KeyCode::Up, KeyModifiers::empty()),
+                key(KeyCode::Up, KeyModifiers::empty()),
+                key(KeyCode::Enter, KeyModifiers::empty()),
+                key(KeyCode::Char('d'), KeyModifiers::empty()),
+                key(KeyCode::Up, KeyModifiers::empty()),
+                key(KeyCode::Down, KeyModifiers::empty()),
+                key(KeyCode::Enter, KeyModifiers::empty()),
+            ])))
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert!(actual.contains(&" second \n".to_string()));
+        assert_eq!(actual.last().unwrap(), " firstd \n");
+    }
+
+    const COMMANDS: &[&str] = &["help", "history", "hello"];
+
+    fn complete_command(value: &str) -> Vec<String> {
+        COMMANDS
+            .iter()
+            .filter(|command| !value.is_empty() && command.starts_with(value))
+            .map(|command| command.to_string())
+            .collect()
+    }
+
+    #[derive(Default, Props)]
+    struct MyCompletionsComponentProps {
+        is_async: bool,
+    }
+
+    #[component]
+    fn MyCompletionsComponent(
+        mut hooks: Hooks,
+        props: &MyCompletionsComponentProps,
+    ) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let mut value = hooks.use_state(|| "".to_string());
+        let mut submitted = hooks.use_state(|| None);
+
+        if let Some(submitted) = submitted.read().as_ref() {
+            assert_eq!(submitted, "hello");
+            system.exit();
+        }
+
+        let completions = if props.is_async {
+            TextInputCompletions::from_async(|value| async move { complete_command(&value) })
+        } else {
+            complete_command.into()
+        };
+
+        element! {
+            View(width: 10) {
+                TextInput(
+                    has_focus: true,
+                    value: value.to_string(),
+                    on_change: move |new_value| value.set(new_value),
+                    on_submit: move |value| submitted.set(Some(value)),
+                    completions,
+                )
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_text_input_completions() {
+        for is_async in [false, true] {
+            let actual = element!(MyCompletionsComponent(is_async))
+                .mock_terminal_render_loop(MockTerminalConfig::with_events(one_at_a_time(vec![
+                    key(KeyCode::Char('h'), KeyModifiers::empty()),
+                    key(KeyCode::Char('e'), KeyModifiers::empty()),
+                    key(KeyCode::Down, KeyModifiers::empty()),
+                    key(KeyCode::Tab, KeyModifiers::empty()),
+                    key(KeyCode::Enter, KeyModifiers::empty()),
+                ])))
+                .map(|c| c.to_string())
+                .collect::<Vec<_>>()
+                .await;
+            assert!(actual.contains(&"help\nhelp      \nhello\n".to_string()));
+            assert_eq!(actual.last().unwrap(), "hello \n");
+        }
+    }
+
+    #[component]
+    fn MyFocusScopeCompletionsComponent(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let mut command = hooks.use_state(|| "".to_string());
+        let mut args = hooks.use_state(|| "".to_string());
+
+        if args.read().as_str() == "x" {
+            system.exit();
+        }
+
+        element! {
+            FocusScope {
+                View(width: 10, flex_direction: FlexDirection::Column) {
+                    TextInput(
+                        focusable: true,
+                        value: command.to_string(),
+                        on_change: move |new_value| command.set(new_value),
+                        completions: complete_command,
+                    )
+                    TextInput(
+                        focusable: true,
+                        value: args.to_string(),
+                        on_change: move |new_value| args.set(new_value),
+                    )
+                }
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_text_input_completions_focus_scope() {
+        // The first Tab accepts the completion without moving focus. The second one moves focus.
+        let actual = element!(MyFocusScopeCompletionsComponent)
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(one_at_a_time(vec![
+                key(KeyCode::Char('h'), KeyModifiers::empty()),
+                key(KeyCode::Char('e'), KeyModifiers::empty()),
+                key(KeyCode::Tab, KeyModifiers::empty()),
+                key(KeyCode::Tab, KeyModifiers::empty()),
+                key(KeyCode::Char('x'), KeyModifiers::empty()),
+            ])))
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert!(actual.contains(&"help \n\n".to_string()));
+        assert!(actual.contains(&"help\n \n".to_string()));
+        assert_eq!(actual.last().unwrap(), "help\nx \n");
+    }
+
+    #[test]
+    fn test_text_input_completion_rendering() {
+        let canvas = element! {
+            View(width: 10) {
+                TextInput(has_focus: true, value: "he", completions: complete_command)
+            }
+        }
+        .render(None);
+        assert_eq!(canvas.to_string(), "help\nhelp      \nhello\n");
+        let style = canvas.cell(3, 0).unwrap().text_style().unwrap();
+        assert_eq!(style.color, Some(Color::DarkGrey));
+        assert_eq!(
+            canvas.cell(0, 1).unwrap().background_color,
+            Some(Color::Blue)
+        );
+        assert_eq!(canvas.cell(0, 2).unwrap().background_color, None);
+
+        // Completions are only shown while focused.
+        assert_eq!(
+            element! {
+                View(width: 10) {
+                    TextInput(has_focus: false, value: "he", completions: complete_command)
+                }
+            }
+            .to_string(),
+            "he\n"
+        );
+    }
+
+    #[test]
+    fn test_text_input_placeholder() {
+        let canvas = element! {
+            View(width: 20) {
+                TextInput(placeholder: "Search...")
+            }
+        }
+        .render(None);
+        assert_eq!(canvas.to_string(), "Search...\n");
+        let style = canvas.cell(0, 0).unwrap().text_style().unwrap();
+        assert_eq!(style.color, Some(Color::DarkGrey));
+
+        assert_eq!(
+            element! {
+                View(width: 20) {
+                    TextInput(has_focus: true, value: "foo", placeholder: "Search...")
+                }
+            }
+            .to_string(),
+            "foo \n"
+        );
+    }
+
+    #[test]
+    fn test_text_input_mask() {
+        assert_eq!(
+            element! {
+                View(width: 20) {
+                    TextInput(has_focus: true, value: "hunter2", mask: '*')
+                }
+            }
+            .to_string(),
+            "******* \n"
+        );
+    }
+
+    #[apply(test!)]
+    async fn test_text_input_overflow_indicators() {
+        let actual = element! {
+            MyComponent(initial_value: "abcdefghijklmnop", overflow_indicators: true)
+        }
+        .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+            vec![key(KeyCode::Char('!'), KeyModifiers::empty())],
+        )))
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .await;
+        assert_eq!(actual.last().unwrap(), " …jklmnop! \n");
+
+        let actual = element! {
+            MyComponent(initial_value: "abcdefghijklmnop", overflow_indicators: true)
+        }
+        .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+            vec![
+                key(KeyCode::Home, KeyModifiers::empty()),
+                key(KeyCode::Char('!'), KeyModifiers::empty()),
+            ],
+        )))
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .await;
+        assert_eq!(actual.last().unwrap(), " !abcdefgh…\n");
+    }
+
+    #[apply(test!)]
+    async fn test_text_input_multiline_scroll() {
+        let actual = element!(MyMultilineComponent)
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+                vec![
+                    key(KeyCode::Char('a'), KeyModifiers::empty()),
+                    key(KeyCode::Enter, KeyModifiers::empty()),
+                    key(KeyCode::Char('b'), KeyModifiers::empty()),
+                    key(KeyCode::Enter, KeyModifiers::empty()),
+                    key(KeyCode::Char('c'), KeyModifiers::empty()),
+                    key(KeyCode::Enter, KeyModifiers::empty()),
+                    key(KeyCode::Char('d'), KeyModifiers::empty()),
+                    key(KeyCode::Char('!'), KeyModifiers::empty()),
+                ],
+            )))
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(actual.last().unwrap(), " b\n c\n d! \n");
+    }
+
+    #[test]
+    fn test_word_boundaries() {
+        assert_eq!(previous_word_boundary("foo bar", 7), 4);
+        assert_eq!(previous_word_boundary("foo bar", 6), 4);
+        assert_eq!(previous_word_boundary("foo bar ", 8), 4);
+        assert_eq!(previous_word_boundary("foo bar", 4), 0);
+        assert_eq!(previous_word_boundary("  ", 2), 0);
+        assert_eq!(next_word_boundary("foo bar", 0), 3);
+        assert_eq!(next_word_boundary("foo bar", 3), 7);
+        assert_eq!(next_word_boundary("foo, bar", 3), 8);
+        assert_eq!(next_word_boundary("foo  ", 3), 5);
+    }
+
+    #[test]
+    fn test_text_buffer_selection_spans() {
+        let buffer = TextBuffer::new("foo bar baz", 8, TextWrap::Wrap);
+        assert_eq!(buffer.selection_spans(1..6), vec![(0, 1, 5)]);
+        assert_eq!(buffer.selection_spans(5..10), vec![(0, 5, 3), (1, 0, 2)]);
+
+        let buffer = TextBuffer::new("一二", 8, TextWrap::Wrap);
+        assert_eq!(buffer.selection_spans(3..6), vec![(0, 2, 2)]);
+
+        // A logically contiguous selection may be visually discontiguous in bidirectional text.
+        let buffer = TextBuffer::new("abאבג", 10, TextWrap::Wrap);
+        assert_eq!(buffer.selection_spans(1..4), vec![(0, 1, 1), (0, 4, 1)]);
+    }
+
     #[test]
     fn test_text_buffer_cursor_movement() {
-        let buffer = TextBuffer::new("foo\nbar baz", 10);
+        let buffer = TextBuffer::new("foo\nbar baz", 10, TextWrap::Wrap);
         assert_eq!(buffer.left_of_offset(2), 1);
         assert_eq!(buffer.right_of_offset(2), 3);
         assert_eq!(buffer.above_offset(2, None), 2);
@@ -804,42 +2343,75 @@ mod tests {
         assert_eq!(buffer.above_offset(5, None), 1);
         assert_eq!(buffer.below_offset(5, None), 5);
         assert_eq!(buffer.above_offset(5, Some(6)), 3);
+
+        let buffer = TextBuffer::new("\u{1f1fa}\u{1f1f8}e\u{301}\nab", 10, TextWrap::Wrap);
+        assert_eq!(buffer.right_of_offset(0), 8);
+        assert_eq!(buffer.right_of_offset(8), 11);
+        assert_eq!(buffer.left_of_offset(11), 8);
+        assert_eq!(buffer.left_of_offset(8), 0);
+        assert_eq!(buffer.above_offset(14, None), 8);
+    }
+
+    #[test]
+    fn test_text_buffer_bidi() {
+        // "ab" followed by three Hebrew letters, which are displayed in reverse.
+        let buffer = TextBuffer::new("abאבג", 10, TextWrap::Wrap);
+        assert_eq!(buffer.visual_lines().collect::<Vec<_>>(), vec!["abגבא"]);
+        assert_eq!(buffer.row_column_for_offset(0), (0, 0));
+        assert_eq!(buffer.row_column_for_offset(2), (0, 4));
+        assert_eq!(buffer.row_column_for_offset(6), (0, 2));
+        assert_eq!(buffer.row_column_for_offset(8), (0, 5));
+
+        // Cursor movement follows the logical order.
+        assert_eq!(buffer.right_of_offset(2), 4);
+        assert_eq!(buffer.left_of_offset(4), 2);
+    }
+
+    #[test]
+    fn test_text_buffer_break_anywhere() {
+        let buffer = TextBuffer::new("foo barbaz\n", 4, TextWrap::BreakAnywhere);
+        assert_eq!(
+            buffer.lines().collect::<Vec<_>>(),
+            vec!["foo ", "barb", "az", ""]
+        );
+        assert_eq!(buffer.row_column_for_offset(5), (1, 1));
+        assert_eq!(buffer.row_column_for_offset(11), (3, 0));
     }
 
     #[test]
     fn test_test_buffer_row_column_for_offset() {
         assert_eq!(
-            TextBuffer::new("一二!", 10).row_column_for_offset(7),
+            TextBuffer::new("一二!", 10, TextWrap::Wrap).row_column_for_offset(7),
             (0, 5)
         );
 
         assert_eq!(
-            TextBuffer::new("foo bar bazqux", 10).row_column_for_offset(9),
+            TextBuffer::new("foo bar bazqux", 10, TextWrap::Wrap).row_column_for_offset(9),
             (1, 1)
         );
 
         assert_eq!(
-            TextBuffer::new("1234512345", 10).row_column_for_offset(10),
+            TextBuffer::new("1234512345", 10, TextWrap::Wrap).row_column_for_offset(10),
             (0, 10)
         );
 
         assert_eq!(
-            TextBuffer::new("12345123451", 10).row_column_for_offset(11),
+            TextBuffer::new("12345123451", 10, TextWrap::Wrap).row_column_for_offset(11),
             (1, 1)
         );
 
         assert_eq!(
-            TextBuffer::new("asd asd asd", 10).row_column_for_offset(11),
+            TextBuffer::new("asd asd asd", 10, TextWrap::Wrap).row_column_for_offset(11),
             (1, 3)
         );
 
         assert_eq!(
-            TextBuffer::new("12345123 5 ", 10).row_column_for_offset(11),
+            TextBuffer::new("12345123 5 ", 10, TextWrap::Wrap).row_column_for_offset(11),
             (0, 11)
         );
 
         assert_eq!(
-            TextBuffer::new("asd\n", 10).row_column_for_offset(4),
+            TextBuffer::new("asd\n", 10, TextWrap::Wrap).row_column_for_offset(4),
             (1, 0)
         );
     }
@@ -853,33 +2425,33 @@ mod tests {
                 mixed_text,
                 cursor_offset,
                 "你好世界，Hello aWorld",
-                NewCursorOffsetHint::None
+                &NewCursorOffsetHint::None
             ),
             cursor_offset + 1
         );
 
         assert_eq!(
-            new_cursor_offset("", 0, "foo", NewCursorOffsetHint::None),
+            new_cursor_offset("", 0, "foo", &NewCursorOffsetHint::None),
             3
         );
         assert_eq!(
-            new_cursor_offset("foo", 3, "foobar", NewCursorOffsetHint::None),
+            new_cursor_offset("foo", 3, "foobar", &NewCursorOffsetHint::None),
             6
         );
         assert_eq!(
-            new_cursor_offset("foobar", 3, "foobar", NewCursorOffsetHint::None),
+            new_cursor_offset("foobar", 3, "foobar", &NewCursorOffsetHint::None),
             3
         );
         assert_eq!(
-            new_cursor_offset("foobar", 3, "fooar", NewCursorOffsetHint::None),
+            new_cursor_offset("foobar", 3, "fooar", &NewCursorOffsetHint::None),
             3
         );
         assert_eq!(
-            new_cursor_offset("foobar", 3, "fooasdbar", NewCursorOffsetHint::None),
+            new_cursor_offset("foobar", 3, "fooasdbar", &NewCursorOffsetHint::None),
             6
         );
         assert_eq!(
-            new_cursor_offset("a\n", 0, "\n", NewCursorOffsetHint::None),
+            new_cursor_offset("a\n", 0, "\n", &NewCursorOffsetHint::None),
             0
         );
         assert_eq!(
@@ -887,7 +2459,46 @@ mod tests {
                 "asddasd\nasdasd",
                 3,
                 "asdasd\nasdasd",
-                NewCursorOffsetHint::Backspace
+                &NewCursorOffsetHint::Edit {
+                    value: "asdasd\nasdasd".to_string(),
+                    cursor_offset: 2,
+                }
+            ),
+            2
+        );
+        assert_eq!(
+            new_cursor_offset(
+                "asddasd\nasdasd",
+                3,
+                "asdasd\nasdasd",
+                &NewCursorOffsetHint::Edit {
+                    value: "asdasd\nasdasd".to_string(),
+                    cursor_offset: 3,
+                }
+            ),
+            3
+        );
+        assert_eq!(
+            new_cursor_offset(
+                "asddasd\nasdasd",
+                3,
+                "asdasd\nasdasd",
+                &NewCursorOffsetHint::None
+            ),
+            2
+        );
+
+        // If the value doesn't match our own edit, e.g. because the owner adjusted it, the edit
+        // still tells us whether a deletion or backspace was performed.
+        assert_eq!(
+            new_cursor_offset(
+                "asddasd\nasdasd",
+                3,
+                "asdasd\nasdasd",
+                &NewCursorOffsetHint::Edit {
+                    value: "asdasd\nasdasd!".to_string(),
+                    cursor_offset: 2,
+                }
             ),
             2
         );
@@ -896,7 +2507,10 @@ mod tests {
                 "asddasd\nasdasd",
                 3,
                 "asdasd\nasdasd",
-                NewCursorOffsetHint::Deletion
+                &NewCursorOffsetHint::Edit {
+                    value: "asdasd\nasdasd!".to_string(),
+                    cursor_offset: 3,
+                }
             ),
             3
         );
@@ -1,7 +1,13 @@
 use crate::{
-    AnyElement, CanvasTextStyle, Color, Component, ComponentDrawer, ComponentUpdater, Edges, Hooks,
-    Props,
+    hit_test::HitTestRegion, AnyElement, CanvasTextStyle, Color, Component, ComponentDrawer,
+    ComponentUpdater, Edges, FullscreenMouseEvent, HandlerMut, Hook, Hooks, MouseEventKind, Props,
+    TerminalEvent, TerminalEvents,
 };
+use core::{
+    pin::{pin, Pin},
+    task::{Context, Poll},
+};
+use futures::stream::Stream;
 use iocraft_macros::with_layout_style_props;
 use taffy::{LengthPercentage, Rect};
 
@@ -154,6 +160,16 @@ pub struct ViewProps<'a> {
 
     /// The color of the background.
     pub background_color: Option<Color>,
+
+    /// A handler to invoke when a mouse button is pressed within the view. The event's coordinates
+    /// are relative to the view. Mouse events are only available in fullscreen mode.
+    pub on_click: HandlerMut<'static, FullscreenMouseEvent>,
+
+    /// A handler to invoke when the mouse moves onto the view.
+    pub on_mouse_enter: HandlerMut<'static, ()>,
+
+    /// A handler to invoke when the mouse moves off of the view.
+    pub on_mouse_leave: HandlerMut<'static, ()>,
 }
 
 /// `View` is your most fundamental building block for laying out and styling components.
@@ -188,9 +204,14 @@ impl Component for View {
     fn update(
         &mut self,
         props: &mut Self::Props<'_>,
-        _hooks: Hooks,
+        mut hooks: Hooks,
         updater: &mut ComponentUpdater,
     ) {
+        let mouse_handlers = hooks.use_hook(MouseHandlersHook::default);
+        mouse_handlers.on_click = props.on_click.take();
+        mouse_handlers.on_mouse_enter = props.on_mouse_enter.take();
+        mouse_handlers.on_mouse_leave = props.on_mouse_leave.take();
+
         self.border_style = props.border_style;
         self.border_text_style = CanvasTextStyle {
             color: props.border_color,
@@ -341,10 +362,68 @@ impl Component for View {
     }
 }
 
+// Dispatches mouse events to the view's handlers. Terminal events are only subscribed to if a
+// handler is actually given, since doing so puts the terminal into raw mode.
+#[derive(Default)]
+struct MouseHandlersHook {
+    events: Option<TerminalEvents>,
+    hit_test_region: HitTestRegion,
+    hovered: bool,
+    on_click: HandlerMut<'static, FullscreenMouseEvent>,
+    on_mouse_enter: HandlerMut<'static, ()>,
+    on_mouse_leave: HandlerMut<'static, ()>,
+}
+
+impl Hook for MouseHandlersHook {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        while let Some(Poll::Ready(Some(event))) = self
+            .events
+            .as_mut()
+            .map(|events| pin!(events).poll_next(cx))
+        {
+            let TerminalEvent::FullscreenMouse(event) = event else {
+                continue;
+            };
+            let event = self.hit_test_region.localize(event);
+            if event.is_some() != self.hovered {
+                self.hovered = event.is_some();
+                if self.hovered {
+                    (self.on_mouse_enter)(());
+                } else {
+                    (self.on_mouse_leave)(());
+                }
+            }
+            if let Some(event) = event {
+                if let MouseEventKind::Down(_) = event.kind {
+                    (self.on_click)(event);
+                }
+            }
+        }
+        Poll::Pending
+    }
+
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        let has_handlers = !self.on_click.is_default()
+            || !self.on_mouse_enter.is_default()
+            || !self.on_mouse_leave.is_default();
+        if has_handlers && self.events.is_none() {
+            self.events = updater.terminal_events();
+        }
+    }
+
+    fn post_component_draw(&mut self, drawer: &mut ComponentDrawer) {
+        self.hit_test_region = drawer.hit_test_region();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
+    use crossterm::event::MouseButton;
+    use futures::stream::{self, StreamExt};
     use indoc::indoc;
+    use macro_rules_attribute::apply;
+    use smol_macros::test;
 
     #[derive(Default, Props)]
     pub struct MyTextProps {
@@ -687,4 +766,122 @@ mod tests {
             "},
         );
     }
+
+    #[component]
+    fn OverlappingViews(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let mut back_clicks = hooks.use_state(|| 0);
+        let mut front_clicks = hooks.use_state(|| 0);
+        let mut front_enters = hooks.use_state(|| 0);
+        let mut front_leaves = hooks.use_state(|| 0);
+
+        if back_clicks.get() + front_clicks.get() == 2 {
+            system.exit();
+        }
+
+        element! {
+            View(width: 10, height: 4) {
+                View(width: 10, height: 4, on_click: move |_| back_clicks += 1) {
+                    Text(content: format!(
+                        "{} {} {} {}",
+                        back_clicks, front_clicks, front_enters, front_leaves,
+                    ))
+                }
+                View(
+                    position: Position::Absolute,
+                    top: 1,
+                    left: 2,
+                    width: 4,
+                    height: 2,
+                    on_click: move |event: FullscreenMouseEvent| {
+                        assert_eq!((event.column, event.row), (1, 1));
+                        front_clicks += 1;
+                    },
+                    on_mouse_enter: move |_| front_enters += 1,
+                    on_mouse_leave: move |_| front_leaves += 1,
+                )
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_view_mouse_events() {
+        let actual = element!(OverlappingViews)
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(stream::iter(vec![
+                TerminalEvent::FullscreenMouse(FullscreenMouseEvent::new(
+                    MouseEventKind::Down(MouseButton::Left),
+                    3,
+                    2,
+                )),
+                TerminalEvent::FullscreenMouse(FullscreenMouseEvent::new(
+                    MouseEventKind::Down(MouseButton::Left),
+                    0,
+                    3,
+                )),
+            ])))
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        // Only the topmost view receives each click.
+        assert_eq!(actual.last().unwrap(), "1 1 1 1\n\n\n\n");
+    }
+
+    #[test]
+    fn test_view_grid() {
+        assert_eq!(
+            element! {
+                View(
+                    display: Display::Grid,
+                    width: 12,
+                    grid_template_columns: vec![GridTrack::Length(5), GridTrack::Fraction(1.0)],
+                ) {
+                    Text(content: "name")
+                    Text(content: "foo")
+                    Text(content: "age")
+                    Text(content: "42")
+                }
+            }
+            .to_string(),
+            "name foo\nage  42\n"
+        );
+
+        assert_eq!(
+            element! {
+                View(
+                    display: Display::Grid,
+                    width: 11,
+                    grid_template_columns: vec![GridTrack::repeat(3, vec![GridTrack::Fraction(1.0)])],
+                    column_gap: 1,
+                ) {
+                    Text(content: "a")
+                    Text(content: "b")
+                    Text(content: "c")
+                    View(grid_column: GridPlacement::Span(2)) {
+                        Text(content: "dd")
+                    }
+                    Text(content: "e")
+                }
+            }
+            .to_string(),
+            "a   b   c\ndd      e\n"
+        );
+
+        assert_eq!(
+            element! {
+                View(
+                    display: Display::Grid,
+                    grid_template_columns: vec![GridTrack::Length(2), GridTrack::Length(2)],
+                ) {
+                    View(grid_column: 2, grid_row: 1) {
+                        Text(content: "x")
+                    }
+                    View(grid_column: 1, grid_row: 2) {
+                        Text(content: "y")
+                    }
+                }
+            }
+            .to_string(),
+            "  x\ny\n"
+        );
+    }
 }
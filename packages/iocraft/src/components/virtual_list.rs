@@ -0,0 +1,769 @@
+use crate::{
+    component,
+    components::{
+        scroll_view::{
+            clamp_offset, max_offset, ScrollViewContentMeasurer, ScrollViewHandleInner,
+            ScrollViewScrollbar, DEFAULT_SCROLL_STEP,
+        },
+        ScrollViewHandle, View,
+    },
+    element,
+    hooks::{Ref, State, UseRef, UseSize, UseState, UseTerminalEvents},
+    AnyElement, Color, ComponentDrawer, FlexBasis, FlexDirection, Hook, Hooks, KeyCode, KeyEvent,
+    KeyEventKind, KeyModifiers, MouseEventKind, Overflow, Position, Props, TerminalEvent,
+};
+use taffy::Size;
+
+const DEFAULT_OVERSCAN: usize = 3;
+
+/// Determines the heights of the rows in a [`VirtualList`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VirtualListRowHeight {
+    /// Every row has the given height. This is the most efficient option, since the position of
+    /// any row can be calculated without rendering it.
+    Fixed(u16),
+    /// Each row is measured when it's rendered. Rows which haven't been rendered yet are assumed
+    /// to have the estimated height.
+    ///
+    /// Measurements are cached by row index. If rows are inserted or removed anywhere other than
+    /// the end of the list, the cached heights of the rows after that point are stale until those
+    /// rows are rendered again, so scroll positions may be briefly off by the difference.
+    Measured {
+        /// The assumed height of rows which haven't been measured yet.
+        estimate: u16,
+    },
+}
+
+impl Default for VirtualListRowHeight {
+    fn default() -> Self {
+        Self::Fixed(1)
+    }
+}
+
+/// A callback which renders a row of a [`VirtualList`], given its index.
+///
+/// Any function which takes a `usize` and returns a value which can be converted into an
+/// [`AnyElement`] can be converted into a `VirtualListRenderer`.
+pub struct VirtualListRenderer(Box<dyn FnMut(usize) -> AnyElement<'static> + Send + Sync>);
+
+impl Default for VirtualListRenderer {
+    fn default() -> Self {
+        Self(Box::new(|_| element!(View).into()))
+    }
+}
+
+impl<F, E> From<F> for VirtualListRenderer
+where
+    F: FnMut(usize) -> E + Send + Sync + 'static,
+    E: Into<AnyElement<'static>>,
+{
+    fn from(mut f: F) -> Self {
+        Self(Box::new(move |index| f(index).into()))
+    }
+}
+
+/// The props which can be passed to the [`VirtualList`] component.
+#[non_exhaustive]
+#[derive(Default, Props)]
+pub struct VirtualListProps {
+    /// The number of rows in the list.
+    pub item_count: usize,
+    /// How the heights of the rows are determined. Defaults to a fixed height of 1.
+    pub row_height: VirtualListRowHeight,
+    /// Renders the row at the given index. This is only invoked for rows which are visible, or
+    /// within the overscan.
+    pub render_item: VirtualListRenderer,
+    /// The number of rows to render above and below the viewport. Defaults to 3.
+    pub overscan: Option<usize>,
+    /// When true, the list stays pinned to the bottom as rows are added.
+    /// Scrolling up disengages auto scroll; reaching the bottom re-engages it.
+    pub auto_scroll: bool,
+    /// Number of lines to scroll per mouse wheel tick. Defaults to 3.
+    pub scroll_step: Option<u16>,
+    /// An optional handle which can be used for imperative control of the list.
+    pub handle: Option<Ref<ScrollViewHandle>>,
+    /// Whether to show a scrollbar. Defaults to `true`.
+    pub scrollbar: Option<bool>,
+    /// Optional color for the scrollbar thumb. Defaults to `White`.
+    pub scrollbar_thumb_color: Option<Color>,
+    /// Optional color for the scrollbar track. Defaults to `DarkGrey`.
+    pub scrollbar_track_color: Option<Color>,
+    /// Whether keyboard events (arrow keys, Page Up/Down, Home/End) scroll
+    /// the list. Defaults to `true`.
+    pub keyboard_scroll: Option<bool>,
+}
+
+/// `VirtualList` is a scrollable list which only renders the rows that are visible.
+///
+/// Unlike [`ScrollView`](crate::components::ScrollView), which lays out all of its content on
+/// every render, `VirtualList` only instantiates components for the rows within the viewport, plus
+/// a few rows of overscan above and below it. This makes it suitable for very large lists, such
+/// as logs with hundreds of thousands of lines.
+///
+/// Place it inside a container with a fixed height. It supports the same keyboard and mouse
+/// scrolling as `ScrollView`, and can be controlled via a [`ScrollViewHandle`]. Rows which are
+/// wider than the list can be scrolled horizontally, but only a vertical scrollbar is shown.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// # fn my_element(lines: Vec<String>) -> impl Into<AnyElement<'static>> {
+/// element! {
+///     View(width: 80, height: 20) {
+///         VirtualList(
+///             item_count: lines.len(),
+///             auto_scroll: true,
+///             render_item: move |i: usize| element! {
+///                 Text(content: &lines[i])
+///             },
+///         )
+///     }
+/// }
+/// # }
+/// ```
+#[component]
+pub fn VirtualList(
+    mut hooks: Hooks,
+    props: &mut VirtualListProps,
+) -> impl Into<AnyElement<'static>> {
+    let mut scroll_offset = hooks.use_state(|| 0i32);
+    let mut scroll_offset_x = hooks.use_state(|| 0i32);
+    let mut user_scrolled_up = hooks.use_state(|| false);
+    let mut content_height: State<u32> = hooks.use_state(|| 0u32);
+    let mut content_width: State<u16> = hooks.use_state(|| 0u16);
+    let mut viewport_height: State<u16> = hooks.use_state(|| 0u16);
+    let mut viewport_width: State<u16> = hooks.use_state(|| 0u16);
+    let size: State<Size<u16>> = hooks.use_size();
+    let content_size_ref: Ref<Size<u16>> = hooks.use_ref(Size::default);
+    let mut measured_heights: Ref<RowHeights> = hooks.use_ref(RowHeights::default);
+    let measure_generation = hooks.use_state(|| 0u64);
+
+    let scroll_step = props.scroll_step.unwrap_or(DEFAULT_SCROLL_STEP) as i32;
+    let auto_scroll = props.auto_scroll;
+    let keyboard_scroll = props.keyboard_scroll.unwrap_or(true);
+    let overscan = props.overscan.unwrap_or(DEFAULT_OVERSCAN);
+    let item_count = props.item_count;
+    let row_height = props.row_height;
+
+    // Determine the height of each row, and the total height of the list.
+    let (fixed_height, estimate) = match row_height {
+        VirtualListRowHeight::Fixed(height) => (Some(height), height),
+        VirtualListRowHeight::Measured { estimate } => (None, estimate),
+    };
+    if fixed_height.is_none() {
+        measured_heights.write().resize(item_count, estimate);
+    }
+    let heights = measured_heights.read();
+    let height_of = |index: usize| -> u32 {
+        match fixed_height {
+            Some(height) => height as u32,
+            None => heights.height(index),
+        }
+    };
+    // Lists whose total height doesn't fit in a u32 are truncated at the end.
+    let total_height = match fixed_height {
+        Some(height) => u32::try_from(item_count)
+            .unwrap_or(u32::MAX)
+            .saturating_mul(height as u32),
+        None => heights.total(),
+    };
+
+    let show_scrollbar = props.scrollbar.unwrap_or(true) && total_height > size.get().height as u32;
+    let vh = size.get().height;
+    let vw = size.get().width.saturating_sub(show_scrollbar as u16);
+    let cw = content_size_ref.get().width.max(vw);
+    if content_height.get() != total_height {
+        content_height.set(total_height);
+    }
+    if content_width.get() != cw {
+        content_width.set(cw);
+    }
+    if viewport_height.get() != vh {
+        viewport_height.set(vh);
+    }
+    if viewport_width.get() != vw {
+        viewport_width.set(vw);
+    }
+
+    // Wire up the handle.
+    if let Some(handle_ref) = props.handle.as_mut() {
+        handle_ref.set(ScrollViewHandle {
+            inner: Some(ScrollViewHandleInner {
+                scroll_offset,
+                scroll_offset_x,
+                content_height,
+                content_width,
+                viewport_height,
+                viewport_width,
+                user_scrolled_up,
+            }),
+        });
+    }
+
+    // Determine if we should use auto_scroll (pinned to bottom) mode.
+    let pinned_to_bottom = auto_scroll && !user_scrolled_up.get();
+
+    let effective_offset = if pinned_to_bottom {
+        max_offset(total_height, vh)
+    } else {
+        let clamped = clamp_offset(scroll_offset.get(), total_height, vh);
+        if scroll_offset.get() != clamped {
+            scroll_offset.set(clamped);
+        }
+        clamped
+    };
+    let clamped_x = clamp_offset(scroll_offset_x.get(), cw.into(), vw);
+    if scroll_offset_x.get() != clamped_x {
+        scroll_offset_x.set(clamped_x);
+    }
+
+    hooks.use_terminal_events(move |event| {
+        let (delta, delta_x) = match &event {
+            TerminalEvent::Key(KeyEvent { code, kind, .. })
+                if keyboard_scroll && *kind != KeyEventKind::Release =>
+            {
+                match code {
+                    KeyCode::Up => (Some(-1), None),
+                    KeyCode::Down => (Some(1), None),
+                    KeyCode::Left => (None, Some(-1)),
+                    KeyCode::Right => (None, Some(1)),
+                    KeyCode::PageUp => (Some(-(viewport_height.get() as i32).max(1)), None),
+                    KeyCode::PageDown => (Some((viewport_height.get() as i32).max(1)), None),
+                    KeyCode::Home => (Some(i32::MIN), None),
+                    KeyCode::End => (Some(i32::MAX), None),
+                    _ => (None, None),
+                }
+            }
+            TerminalEvent::FullscreenMouse(mouse) => {
+                let shift = mouse.modifiers.contains(KeyModifiers::SHIFT);
+                match mouse.kind {
+                    MouseEventKind::ScrollUp if shift => (None, Some(-scroll_step)),
+                    MouseEventKind::ScrollDown if shift => (None, Some(scroll_step)),
+                    MouseEventKind::ScrollUp => (Some(-scroll_step), None),
+                    MouseEventKind::ScrollDown => (Some(scroll_step), None),
+                    MouseEventKind::ScrollLeft => (None, Some(-scroll_step)),
+                    MouseEventKind::ScrollRight => (None, Some(scroll_step)),
+                    _ => (None, None),
+                }
+            }
+            _ => (None, None),
+        };
+
+        if let Some(delta_x) = delta_x {
+            scroll_offset_x.set(clamp_offset(
+                scroll_offset_x.get() + delta_x,
+                content_width.get().into(),
+                viewport_width.get(),
+            ));
+        }
+
+        if let Some(delta) = delta {
+            let max = max_offset(content_height.get(), viewport_height.get());
+            // When pinned, the stored offset may be stale, so scroll relative to the bottom.
+            let current = if auto_scroll && !user_scrolled_up.get() {
+                max
+            } else {
+                scroll_offset.get()
+            };
+            let new_offset = current.saturating_add(delta).clamp(0, max);
+            scroll_offset.set(new_offset);
+
+            if auto_scroll {
+                user_scrolled_up.set(new_offset < max);
+            }
+        }
+    });
+
+    let on_scroll = move |offset: i32| {
+        let max = max_offset(content_height.get(), viewport_height.get());
+        let offset = offset.clamp(0, max);
+        scroll_offset.set(offset);
+        if auto_scroll {
+            user_scrolled_up.set(offset < max);
+        }
+    };
+
+    // Find the rows which intersect the viewport, then extend the range by the overscan.
+    let (first_visible, first_visible_top) = match fixed_height {
+        Some(0) => (item_count, 0),
+        Some(height) => {
+            let index = (effective_offset as u32 / height as u32) as usize;
+            (index, (index as u32).saturating_mul(height as u32))
+        }
+        None => heights.locate(effective_offset as u32),
+    };
+    let mut end = first_visible;
+    let mut bottom = first_visible_top;
+    while end < item_count && bottom < effective_offset as u32 + vh as u32 {
+        bottom += height_of(end);
+        end += 1;
+    }
+    let start = first_visible.saturating_sub(overscan);
+    let end = (end + overscan).min(item_count);
+    let start_top = match fixed_height {
+        Some(height) => (start as u32).saturating_mul(height as u32),
+        None => heights.prefix_sum(start),
+    };
+    drop(heights);
+
+    let render_item = &mut props.render_item.0;
+    let rows = (start..end)
+        .map(|index| {
+            element! {
+                VirtualListRow(
+                    key: index,
+                    index,
+                    fixed_height,
+                    measured_heights: fixed_height.is_none().then_some(measured_heights),
+                    measure_generation,
+                ) {
+                    #(render_item(index))
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    element! {
+        View(width: 100pct, height: 100pct, flex_direction: FlexDirection::Row) {
+            View(
+                overflow: Overflow::Hidden,
+                flex_grow: 1.0,
+                flex_basis: FlexBasis::Length(0),
+                min_width: 0,
+                height: 100pct,
+            ) {
+                View(
+                    position: Position::Absolute,
+                    top: start_top as i32 - effective_offset,
+                    min_width: 100pct,
+                ) {
+                    ScrollViewContentMeasurer(
+                        content_size_ref: Some(content_size_ref),
+                        scroll_offset_x: scroll_offset_x.get(),
+                    ) {
+                        View(flex_direction: FlexDirection::Column, flex_grow: 1.0) {
+                            #(rows)
+                        }
+                    }
+                }
+            }
+            #(show_scrollbar.then(|| element! {
+                ScrollViewScrollbar(
+                    viewport_size: vh,
+                    content_size: total_height,
+                    scroll_offset: effective_offset,
+                    thumb_color: props.scrollbar_thumb_color,
+                    track_color: props.scrollbar_track_color,
+                    on_scroll,
+                )
+            }))
+        }
+    }
+}
+
+// The heights of the rows of a list with measured row heights. Rows which haven't been measured
+// yet count as the estimated height. The heights are kept in a Fenwick tree so that the total
+// height and the position of any row can be found without visiting every row.
+#[derive(Default)]
+struct RowHeights {
+    estimate: u16,
+    measured: Vec<Option<u16>>,
+    // Node i (1-based) holds the sum of the heights of rows (i - lowest_bit(i), i].
+    tree: Vec<u32>,
+}
+
+fn lowest_bit(i: usize) -> usize {
+    i & i.wrapping_neg()
+}
+
+impl RowHeights {
+    fn len(&self) -> usize {
+        self.measured.len()
+    }
+
+    // Sets the number of rows and the estimated height. Growing or shrinking the list only costs
+    // time proportional to the number of rows added or removed.
+    fn resize(&mut self, len: usize, estimate: u16) {
+        if estimate != self.estimate {
+            self.estimate = estimate;
+            self.measured.truncate(len);
+            self.rebuild();
+        }
+        self.measured.truncate(len);
+        self.tree.truncate(len);
+        while self.len() < len {
+            self.measured.push(None);
+            let i = self.len();
+            let below = self
+                .prefix_sum(i - 1)
+                .wrapping_sub(self.prefix_sum(i - lowest_bit(i)));
+            self.tree.push(below.wrapping_add(estimate as u32));
+        }
+    }
+
+    fn rebuild(&mut self) {
+        let estimate = self.estimate;
+        self.tree = self
+            .measured
+            .iter()
+            .map(|height| height.unwrap_or(estimate) as u32)
+            .collect();
+        for i in 1..=self.tree.len() {
+            let parent = i + lowest_bit(i);
+            if parent <= self.tree.len() {
+                self.tree[parent - 1] = self.tree[parent - 1].wrapping_add(self.tree[i - 1]);
+            }
+        }
+    }
+
+    fn height(&self, index: usize) -> u32 {
+        self.measured[index].unwrap_or(self.estimate) as u32
+    }
+
+    // Records the measured height of a row, returning true if it changed.
+    fn set(&mut self, index: usize, height: u16) -> bool {
+        if index >= self.len() || self.measured[index] == Some(height) {
+            return false;
+        }
+        let delta = (height as u32).wrapping_sub(self.height(index));
+        self.measured[index] = Some(height);
+        let mut i = index + 1;
+        while i <= self.tree.len() {
+            self.tree[i - 1] = self.tree[i - 1].wrapping_add(delta);
+            i += lowest_bit(i);
+        }
+        true
+    }
+
+    // Returns the total height of the first `count` rows.
+    fn prefix_sum(&self, count: usize) -> u32 {
+        let mut sum = 0u32;
+        let mut i = count;
+        while i > 0 {
+            sum = sum.wrapping_add(self.tree[i - 1]);
+            i -= lowest_bit(i);
+        }
+        sum
+    }
+
+    fn total(&self) -> u32 {
+        self.prefix_sum(self.len())
+    }
+
+    // Returns the index of the row at the given offset from the top of the list, along with the
+    // offset of that row's top. Zero-height rows are skipped.
+    fn locate(&self, offset: u32) -> (usize, u32) {
+        let mut index = 0;
+        let mut remaining = offset;
+        let mut step = self.len().checked_next_power_of_two().unwrap_or(0);
+        while step > 0 {
+            if index + step <= self.len() && self.tree[index + step - 1] <= remaining {
+                index += step;
+                remaining -= self.tree[index - 1];
+            }
+            step /= 2;
+        }
+        (index, offset - remaining)
+    }
+}
+
+#[derive(Default, Props)]
+struct VirtualListRowProps {
+    children: Vec<AnyElement<'static>>,
+    index: usize,
+    fixed_height: Option<u16>,
+    measured_heights: Option<Ref<RowHeights>>,
+    measure_generation: Option<State<u64>>,
+}
+
+// Hook that records the height of a measured row, triggering a re-render of the list if it
+// changed.
+struct RowHeightHook {
+    index: usize,
+    measured_heights: Option<Ref<RowHeights>>,
+    measure_generation: Option<State<u64>>,
+}
+
+impl Hook for RowHeightHook {
+    fn pre_component_draw(&mut self, drawer: &mut ComponentDrawer) {
+        let Some(heights) = &mut self.measured_heights else {
+            return;
+        };
+        let height = drawer.size().height;
+        let Some(mut heights) = heights.try_write() else {
+            return;
+        };
+        if heights.set(self.index, height) {
+            if let Some(generation) = &mut self.measure_generation {
+                generation.set(generation.get().wrapping_add(1));
+            }
+        }
+    }
+}
+
+#[component]
+fn VirtualListRow(
+    mut hooks: Hooks,
+    props: &mut VirtualListRowProps,
+) -> impl Into<AnyElement<'static>> {
+    let h = hooks.use_hook(|| RowHeightHook {
+        index: 0,
+        measured_heights: None,
+        measure_generation: None,
+    });
+    h.index = props.index;
+    h.measured_heights = props.measured_heights;
+    h.measure_generation = props.measure_generation;
+
+    let children = std::mem::take(&mut props.children);
+    match props.fixed_height {
+        Some(height) => element! {
+            View(height, flex_shrink: 0.0, overflow: Overflow::Hidden) {
+                #(children)
+            }
+        },
+        None => element! {
+            View(flex_shrink: 0.0) {
+                #(children)
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use futures::stream::{self, StreamExt};
+    use macro_rules_attribute::apply;
+    use smol_macros::test;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    #[derive(Default, Props)]
+    struct TestListProps {
+        row_height: VirtualListRowHeight,
+        auto_scroll: bool,
+        render_count: Option<Arc<AtomicUsize>>,
+        wide: bool,
+    }
+
+    #[component]
+    fn TestList(mut hooks: Hooks, props: &TestListProps) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let mut done = hooks.use_state(|| false);
+
+        hooks.use_terminal_events(move |event| {
+            if let TerminalEvent::Key(KeyEvent {
+                code: KeyCode::Char('q'),
+                kind: KeyEventKind::Press,
+                ..
+            }) = event
+            {
+                done.set(true);
+            }
+        });
+
+        if done.get() {
+            system.exit();
+        }
+
+        let render_count = props.render_count.clone();
+        let multiline = props.row_height != VirtualListRowHeight::Fixed(1);
+        let wide = props.wide;
+        element! {
+            View(width: 20, height: 5) {
+                VirtualList(
+                    item_count: 100_000usize,
+                    row_height: props.row_height,
+                    auto_scroll: props.auto_scroll,
+                    render_item: move |i: usize| {
+                        if let Some(render_count) = &render_count {
+                            render_count.fetch_add(1, Ordering::Relaxed);
+                        }
+                        let content = if multiline && i.is_multiple_of(2) {
+                            format!("Item {i}\n(even)")
+                        } else if wide {
+                            format!("Item {i} {}", "-".repeat(30))
+                        } else {
+                            format!("Item {i}")
+                        };
+                        element!(Text(content, wrap: if wide { TextWrap::NoWrap } else { TextWrap::Wrap }))
+                    },
+                )
+            }
+        }
+    }
+
+    fn keys(codes: Vec<KeyCode>) -> MockTerminalConfig {
+        MockTerminalConfig::with_events(stream::iter(
+            codes
+                .into_iter()
+                .chain([KeyCode::Char('q')])
+                .map(|code| TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, code)))
+                .collect::<Vec<_>>(),
+        ))
+    }
+
+    #[apply(test!)]
+    async fn test_virtual_list_fixed_height() {
+        let render_count = Arc::new(AtomicUsize::new(0));
+        let canvases: Vec<_> = element!(TestList(render_count: render_count.clone()))
+            .mock_terminal_render_loop(keys(vec![KeyCode::Down, KeyCode::Down]))
+            .map(|c| c.to_string())
+            .collect()
+            .await;
+
+        let output = canvases.last().unwrap();
+        assert!(output.starts_with("Item 2 "));
+        assert!(output.contains("Item 6"));
+        assert!(!output.contains("Item 7"));
+
+        // Only the visible rows and overscan should ever have been rendered.
+        assert!(render_count.load(Ordering::Relaxed) < 100);
+    }
+
+    #[apply(test!)]
+    async fn test_virtual_list_end() {
+        let canvases: Vec<_> = element!(TestList)
+            .mock_terminal_render_loop(keys(vec![KeyCode::End]))
+            .map(|c| c.to_string())
+            .collect()
+            .await;
+
+        let output = canvases.last().unwrap();
+        assert!(output.contains("Item 99995"));
+        assert!(output.contains("Item 99999"));
+    }
+
+    #[apply(test!)]
+    async fn test_virtual_list_auto_scroll() {
+        let canvases: Vec<_> = element!(TestList(auto_scroll: true))
+            .mock_terminal_render_loop(keys(vec![]))
+            .map(|c| c.to_string())
+            .collect()
+            .await;
+
+        let output = canvases.last().unwrap();
+        assert!(output.contains("Item 99999"));
+    }
+
+    #[apply(test!)]
+    async fn test_virtual_list_measured_height() {
+        let canvases: Vec<_> =
+            element!(TestList(row_height: VirtualListRowHeight::Measured { estimate: 1 }))
+                .mock_terminal_render_loop(keys(vec![KeyCode::Down, KeyCode::Down]))
+                .map(|c| c.to_string())
+                .collect()
+                .await;
+
+        // Rows 0 and 2 are two lines tall, so scrolling down two lines reveals row 1 at the top.
+        let output = canvases.last().unwrap();
+        assert!(output.starts_with("Item 1 "));
+        assert!(output.contains("Item 2 "));
+        assert!(output.contains("(even)"));
+        assert!(output.contains("Item 4"));
+        assert!(!output.contains("Item 5"));
+    }
+
+    #[apply(test!)]
+    async fn test_virtual_list_horizontal_scroll() {
+        let canvases: Vec<_> = element!(TestList(wide: true))
+            .mock_terminal_render_loop(keys(vec![KeyCode::Right, KeyCode::Right]))
+            .map(|c| c.to_string())
+            .collect()
+            .await;
+
+        let output = canvases.last().unwrap();
+        assert!(output.starts_with("em 0 ---"));
+        assert!(output.contains("em 4 ---"));
+    }
+
+    #[component]
+    fn HugeList(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let mut done = hooks.use_state(|| false);
+        let handle = hooks.use_ref_default::<ScrollViewHandle>();
+
+        hooks.use_terminal_events(move |event| {
+            if let TerminalEvent::Key(KeyEvent {
+                code: KeyCode::Char('q'),
+                kind: KeyEventKind::Press,
+                ..
+            }) = event
+            {
+                done.set(true);
+            }
+        });
+
+        if done.get() {
+            system.exit();
+        }
+
+        let (content_height, offset) = {
+            let handle = handle.read();
+            (handle.content_height_u32(), handle.scroll_offset())
+        };
+        element! {
+            View(flex_direction: FlexDirection::Column) {
+                Text(content: format!("{content_height} {offset}"))
+                View(width: 20, height: 5) {
+                    VirtualList(
+                        item_count: usize::MAX,
+                        row_height: VirtualListRowHeight::Fixed(1000),
+                        handle,
+                        render_item: |i: usize| element!(Text(content: format!("Item {i}"))),
+                    )
+                }
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_virtual_list_huge_content() {
+        let canvases: Vec<_> = element!(HugeList)
+            .mock_terminal_render_loop(keys(vec![KeyCode::End]))
+            .map(|c| c.to_string())
+            .collect()
+            .await;
+
+        // The total height saturates rather than wrapping around, so End scrolls as far as the
+        // offset can go and the scrollbar thumb ends up at the bottom.
+        let output = canvases.last().unwrap();
+        assert!(output.starts_with(&format!("{} {}\n", u32::MAX, i32::MAX)));
+        assert!(output.trim_end().ends_with('\u{2503}'));
+    }
+
+    #[test]
+    fn test_row_heights() {
+        let mut heights = super::RowHeights::default();
+        heights.resize(10, 1);
+        assert_eq!(heights.total(), 10);
+        assert_eq!(heights.locate(3), (3, 3));
+
+        assert!(heights.set(2, 3));
+        assert!(!heights.set(2, 3));
+        assert!(heights.set(5, 0));
+        assert_eq!(heights.total(), 11);
+        assert_eq!(heights.prefix_sum(3), 5);
+        assert_eq!(heights.locate(2), (2, 2));
+        assert_eq!(heights.locate(4), (2, 2));
+        assert_eq!(heights.locate(5), (3, 5));
+        // Zero-height rows are skipped.
+        assert_eq!(heights.locate(7), (6, 7));
+
+        // Growing and shrinking keep the measurements of the remaining rows.
+        heights.resize(13, 1);
+        assert_eq!(heights.total(), 14);
+        assert_eq!(heights.locate(13), (12, 13));
+        heights.resize(4, 1);
+        assert_eq!(heights.total(), 6);
+
+        // Changing the estimate only affects rows which haven't been measured.
+        heights.resize(4, 2);
+        assert_eq!(heights.total(), 9);
+        assert_eq!(heights.locate(100), (4, 9));
+    }
+}
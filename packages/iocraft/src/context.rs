@@ -3,11 +3,14 @@ use core::{
     cell::{Ref, RefCell, RefMut},
     mem,
 };
+use std::sync::{Arc, Mutex};
 
 /// The system context, which is always available to all components.
 pub struct SystemContext {
     should_exit: bool,
     mouse_capture: Option<bool>,
+    clipboard: Arc<Mutex<String>>,
+    terminal_focused: bool,
 }
 
 impl SystemContext {
@@ -15,6 +18,8 @@ impl SystemContext {
         Self {
             should_exit: false,
             mouse_capture: None,
+            clipboard: Arc::default(),
+            terminal_focused: true,
         }
     }
 
@@ -37,6 +42,22 @@ impl SystemContext {
     pub(crate) fn mouse_capture(&self) -> Option<bool> {
         self.mouse_capture
     }
+
+    /// The clipboard register shared by the components being rendered. Terminals generally don't
+    /// allow applications to read the system clipboard, so copied text is pasted from here.
+    pub(crate) fn clipboard(&self) -> &Arc<Mutex<String>> {
+        &self.clipboard
+    }
+
+    /// Whether the terminal window has focus, as of the most recent focus event received by the
+    /// render loop.
+    pub(crate) fn terminal_focused(&self) -> bool {
+        self.terminal_focused
+    }
+
+    pub(crate) fn set_terminal_focused(&mut self, focused: bool) {
+        self.terminal_focused = focused;
+    }
 }
 
 /// A context that can be passed to components.
@@ -1,9 +1,10 @@
 use crate::{
     any_key::AnyKey,
+    color_depth::is_ansi_forced,
     component::{Component, ComponentHelper, ComponentHelperExt},
     mock_terminal_render_loop,
     props::AnyProps,
-    render, terminal_render_loop, Canvas, MockTerminalConfig, Terminal,
+    render, terminal_render_loop, Canvas, ColorDepth, MockTerminalConfig, Terminal,
 };
 use crossterm::terminal;
 use futures::Stream;
@@ -180,42 +181,87 @@ pub trait ElementExt: private::Sealed + Sized {
         self.write_to_is_terminal(stdout()).unwrap();
     }
 
+    /// Renders the element and prints it to stdout, using the given color depth instead of the
+    /// [detected](ColorDepth::detect) one if ANSI escape codes are written.
+    fn print_with_color_depth(&mut self, color_depth: ColorDepth) {
+        self.write_to_is_terminal_with_color_depth(stdout(), color_depth)
+            .unwrap();
+    }
+
     /// Renders the element and prints it to stderr.
     fn eprint(&mut self) {
         self.write_to_is_terminal(stderr()).unwrap();
     }
 
+    /// Renders the element and prints it to stderr, using the given color depth instead of the
+    /// [detected](ColorDepth::detect) one if ANSI escape codes are written.
+    fn eprint_with_color_depth(&mut self, color_depth: ColorDepth) {
+        self.write_to_is_terminal_with_color_depth(stderr(), color_depth)
+            .unwrap();
+    }
+
     /// Renders the element and writes it to the given writer.
     fn write<W: Write>(&mut self, w: W) -> io::Result<()> {
         let canvas = self.render(None);
         canvas.write(w)
     }
 
+    /// Renders the element and writes it to the given writer with ANSI escape codes, converting
+    /// each color to the closest color which can be displayed with the given color depth.
+    fn write_ansi<W: Write>(&mut self, w: W, color_depth: ColorDepth) -> io::Result<()> {
+        let canvas = self.render(None);
+        canvas.write_ansi_with_color_depth(w, color_depth)
+    }
+
     /// Renders the element and writes it to the given raw file descriptor. If the file descriptor
-    /// is a TTY, the canvas will be rendered based on its size, with ANSI escape codes.
+    /// is a TTY, the canvas will be rendered based on its size, with ANSI escape codes and colors
+    /// suited to the [detected](ColorDepth::detect) color depth.
+    ///
+    /// ANSI escape codes are also written if the `FORCE_COLOR` or `CLICOLOR_FORCE` environment
+    /// variable is set, even if the file descriptor isn't a TTY. If `NO_COLOR` is set, colors are
+    /// omitted but other styling is preserved.
     #[cfg(unix)]
     fn write_to_raw_fd<F: Write + std::os::fd::AsRawFd>(&mut self, fd: F) -> io::Result<()> {
         use crossterm::tty::IsTty;
-        if fd.is_tty() {
-            let (width, _) = terminal::size()?;
-            let canvas = self.render(Some(width as _));
-            canvas.write_ansi(fd)
-        } else {
-            self.write(fd)
-        }
+        let is_tty = fd.is_tty();
+        write_to_terminal_or_plain(self, fd, is_tty, None)
+    }
+
+    /// Equivalent to [`ElementExt::write_to_raw_fd`], but uses the given color depth instead of
+    /// the [detected](ColorDepth::detect) one if ANSI escape codes are written.
+    #[cfg(unix)]
+    fn write_to_raw_fd_with_color_depth<F: Write + std::os::fd::AsRawFd>(
+        &mut self,
+        fd: F,
+        color_depth: ColorDepth,
+    ) -> io::Result<()> {
+        use crossterm::tty::IsTty;
+        let is_tty = fd.is_tty();
+        write_to_terminal_or_plain(self, fd, is_tty, Some(color_depth))
     }
 
     /// Renders the element and writes it to the given writer also implementing
     /// [`IsTerminal`](std::io::IsTerminal). If the writer is a terminal, the canvas will be
-    /// rendered based on its size, with ANSI escape codes.
+    /// rendered based on its size, with ANSI escape codes and colors suited to the
+    /// [detected](ColorDepth::detect) color depth.
+    ///
+    /// ANSI escape codes are also written if the `FORCE_COLOR` or `CLICOLOR_FORCE` environment
+    /// variable is set, even if the writer isn't a terminal. If `NO_COLOR` is set, colors are
+    /// omitted but other styling is preserved.
     fn write_to_is_terminal<W: Write + IsTerminal>(&mut self, w: W) -> io::Result<()> {
-        if w.is_terminal() {
-            let (width, _) = terminal::size()?;
-            let canvas = self.render(Some(width as _));
-            canvas.write_ansi(w)
-        } else {
-            self.write(w)
-        }
+        let is_terminal = w.is_terminal();
+        write_to_terminal_or_plain(self, w, is_terminal, None)
+    }
+
+    /// Equivalent to [`ElementExt::write_to_is_terminal`], but uses the given color depth instead
+    /// of the [detected](ColorDepth::detect) one if ANSI escape codes are written.
+    fn write_to_is_terminal_with_color_depth<W: Write + IsTerminal>(
+        &mut self,
+        w: W,
+        color_depth: ColorDepth,
+    ) -> io::Result<()> {
+        let is_terminal = w.is_terminal();
+        write_to_terminal_or_plain(self, w, is_terminal, Some(color_depth))
     }
 
     /// Returns a future which renders the element in a loop, allowing it to be dynamic and
@@ -282,6 +328,26 @@ pub trait ElementExt: private::Sealed + Sized {
     }
 }
 
+fn write_to_terminal_or_plain<E: ElementExt, W: Write>(
+    e: &mut E,
+    w: W,
+    is_terminal: bool,
+    color_depth: Option<ColorDepth>,
+) -> io::Result<()> {
+    if is_terminal {
+        let (width, _) = terminal::size()?;
+        let canvas = e.render(Some(width as _));
+        canvas.write_ansi_with_color_depth(w, color_depth.unwrap_or_else(ColorDepth::detect))
+    } else if is_ansi_forced() {
+        // The output is probably headed to a terminal eventually, e.g. via a pager.
+        let width = terminal::size().ok().map(|(width, _)| width as _);
+        let canvas = e.render(width);
+        canvas.write_ansi_with_color_depth(w, color_depth.unwrap_or_else(ColorDepth::detect))
+    } else {
+        e.write(w)
+    }
+}
+
 /// Specifies which handle to render the TUI to.
 #[cfg_attr(not(feature = "unstable-output-streams"), doc(hidden))]
 #[cfg_attr(docsrs, doc(cfg(feature = "unstable-output-streams")))]
@@ -302,6 +368,7 @@ enum RenderLoopFutureState<'a, E: ElementExt> {
         fullscreen: bool,
         mouse_capture: Option<bool>,
         ignore_ctrl_c: bool,
+        color_depth: Option<ColorDepth>,
         output: Output,
         stdout_writer: Option<Box<dyn Write + Send + 'a>>,
         stderr_writer: Option<Box<dyn Write + Send + 'a>>,
@@ -326,6 +393,7 @@ impl<'a, E: ElementExt + 'a> RenderLoopFuture<'a, E> {
                 fullscreen: false,
                 mouse_capture: None,
                 ignore_ctrl_c: false,
+                color_depth: None,
                 output: Output::default(),
                 stdout_writer: None,
                 stderr_writer: None,
@@ -387,6 +455,20 @@ impl<'a, E: ElementExt + 'a> RenderLoopFuture<'a, E> {
         self
     }
 
+    /// Sets the color depth to render with. Colors which can't be displayed with the given depth
+    /// are converted to the closest color which can.
+    ///
+    /// By default, the color depth is detected via [`ColorDepth::detect`].
+    pub fn color_depth(mut self, depth: ColorDepth) -> Self {
+        match &mut self.state {
+            RenderLoopFutureState::Init { color_depth, .. } => {
+                *color_depth = Some(depth);
+            }
+            _ => panic!("color_depth() must be called before polling the future"),
+        }
+        self
+    }
+
     /// Set the stdout handle for hook output and TUI rendering (when output is Stdout).
     ///
     /// See [`output`](Self::output) for known crossterm caveats when mixing streams.
@@ -466,6 +548,7 @@ impl<'a, E: ElementExt + Send + 'a> Future for RenderLoopFuture<'a, E> {
                         fullscreen,
                         mouse_capture,
                         ignore_ctrl_c,
+                        color_depth,
                         output,
                         stdout_writer,
                         stderr_writer,
@@ -475,6 +558,7 @@ impl<'a, E: ElementExt + Send + 'a> Future for RenderLoopFuture<'a, E> {
                             fullscreen,
                             mouse_capture,
                             ignore_ctrl_c,
+                            color_depth,
                             output,
                             stdout_writer,
                             stderr_writer,
@@ -483,6 +567,7 @@ impl<'a, E: ElementExt + Send + 'a> Future for RenderLoopFuture<'a, E> {
                             fullscreen,
                             mouse_capture,
                             ignore_ctrl_c,
+                            color_depth,
                             output,
                             stdout_writer,
                             stderr_writer,
@@ -502,6 +587,7 @@ impl<'a, E: ElementExt + Send + 'a> Future for RenderLoopFuture<'a, E> {
                         output,
                         fullscreen,
                         effective_mouse_capture,
+                        color_depth.unwrap_or_else(ColorDepth::detect),
                     ) {
                         Ok(t) => t,
                         Err(e) => return std::task::Poll::Ready(Err(e)),
@@ -615,7 +701,7 @@ mod tests {
     use crate::prelude::*;
     use futures::Future;
 
-    #[allow(clippy::unnecessary_mut_passed)]
+    #[allow(clippy::unnecessary_mut_passed, clippy::needless_borrow)]
     #[test]
     fn test_element() {
         let mut view_element = element!(View);
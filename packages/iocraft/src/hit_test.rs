@@ -0,0 +1,143 @@
+use crate::FullscreenMouseEvent;
+use std::sync::{Arc, Mutex};
+use taffy::{NodeId, Point, Rect, Size};
+
+/// A region of the canvas occupied by a component, recorded while drawing.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct HitRegion {
+    /// The component which drew the region.
+    pub node_id: NodeId,
+    /// The visible area of the component itself, excluding its children. This is empty for
+    /// components with transparent layouts.
+    pub rect: Rect<u16>,
+    /// The index of the last region belonging to the component's subtree.
+    pub subtree_end: usize,
+}
+
+impl HitRegion {
+    fn contains(&self, column: u16, row: u16) -> bool {
+        column >= self.rect.left
+            && column < self.rect.right
+            && row >= self.rect.top
+            && row < self.rect.bottom
+    }
+}
+
+#[derive(Default)]
+struct HitTesterState {
+    regions: Vec<HitRegion>,
+    capture: Option<NodeId>,
+}
+
+enum HitTestResult {
+    Miss,
+    Hit,
+    Captured,
+}
+
+/// Records the regions drawn by each component so that mouse events can be dispatched to the
+/// topmost component under the cursor.
+///
+/// Regions are stored in the order they're drawn, which is also the order in which they're stacked:
+/// a region drawn later is on top of any region drawn before it.
+///
+/// A component may also capture the mouse, in which case it receives all mouse events regardless
+/// of position, and no other component receives any.
+#[derive(Clone, Default)]
+pub(crate) struct HitTester {
+    state: Arc<Mutex<HitTesterState>>,
+}
+
+impl HitTester {
+    /// Replaces the recorded regions with those from the most recent draw. If the component which
+    /// captured the mouse is no longer present, the capture is released.
+    pub fn set_regions(&self, regions: Vec<HitRegion>) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(capture) = state.capture {
+            if !regions.iter().any(|region| region.node_id == capture) {
+                state.capture = None;
+            }
+        }
+        state.regions = regions;
+    }
+
+    /// Routes all mouse events to the given component until released.
+    pub fn capture(&self, node_id: NodeId) {
+        self.state.lock().unwrap().capture = Some(node_id);
+    }
+
+    /// Releases the mouse if it was captured by the given component.
+    pub fn release(&self, node_id: NodeId) {
+        let mut state = self.state.lock().unwrap();
+        if state.capture == Some(node_id) {
+            state.capture = None;
+        }
+    }
+
+    /// Returns `true` if the mouse is captured by the given component.
+    pub fn is_captured_by(&self, node_id: NodeId) -> bool {
+        self.state.lock().unwrap().capture == Some(node_id)
+    }
+
+    fn test(&self, index: usize, column: u16, row: u16) -> HitTestResult {
+        let state = self.state.lock().unwrap();
+        let Some(region) = state.regions.get(index) else {
+            return HitTestResult::Miss;
+        };
+        match state.capture {
+            Some(capture) if capture == region.node_id => HitTestResult::Captured,
+            Some(_) => HitTestResult::Miss,
+            None => {
+                // The component is hit if the topmost region belongs to its subtree.
+                let is_hit = state
+                    .regions
+                    .iter()
+                    .rposition(|region| region.contains(column, row))
+                    .is_some_and(|topmost| topmost >= index && topmost <= region.subtree_end);
+                if is_hit {
+                    HitTestResult::Hit
+                } else {
+                    HitTestResult::Miss
+                }
+            }
+        }
+    }
+}
+
+/// Identifies the region of a single component within a [`HitTester`], along with the location of
+/// the component as it was last drawn.
+#[derive(Clone, Default)]
+pub(crate) struct HitTestRegion {
+    pub tester: HitTester,
+    pub index: usize,
+    pub position: Point<i16>,
+    pub size: Size<u16>,
+}
+
+impl HitTestRegion {
+    /// If the event is targeted at the component, returns the event translated to component-local
+    /// coordinates.
+    ///
+    /// Events are targeted at the component if they're within the component and the component or
+    /// one of its descendants is the topmost component at the event's position, or if the
+    /// component has captured the mouse. Coordinates of captured events which are outside of the
+    /// component are clamped to zero.
+    pub fn localize(&self, event: FullscreenMouseEvent) -> Option<FullscreenMouseEvent> {
+        let row = event.row as i32 - self.position.y as i32;
+        let column = event.column as i32 - self.position.x as i32;
+        match self.tester.test(self.index, event.column, event.row) {
+            HitTestResult::Captured => {}
+            HitTestResult::Hit
+                if row >= 0
+                    && column >= 0
+                    && row < self.size.height as i32
+                    && column < self.size.width as i32 => {}
+            _ => return None,
+        }
+        Some(FullscreenMouseEvent {
+            row: row.clamp(0, u16::MAX as i32) as u16,
+            column: column.clamp(0, u16::MAX as i32) as u16,
+            ..event
+        })
+    }
+}
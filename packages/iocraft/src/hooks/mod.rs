@@ -48,10 +48,16 @@ mod use_context;
 pub use use_context::*;
 mod use_effect;
 pub use use_effect::*;
+mod use_focus;
+pub use use_focus::*;
 mod use_future;
 pub use use_future::*;
+mod use_hover;
+pub use use_hover::*;
 mod use_memo;
 pub use use_memo::*;
+mod use_mouse_capture;
+pub use use_mouse_capture::*;
 mod use_output;
 pub use use_output::*;
 mod use_ref;
@@ -60,7 +66,11 @@ mod use_state;
 pub use use_state::*;
 mod use_terminal_events;
 pub use use_terminal_events::*;
+mod use_terminal_focus;
+pub use use_terminal_focus::*;
 mod use_terminal_size;
 pub use use_terminal_size::*;
 mod use_component_rect;
 pub use use_component_rect::*;
+mod use_size;
+pub(crate) use use_size::*;
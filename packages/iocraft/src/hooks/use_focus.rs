@@ -0,0 +1,607 @@
+use crate::{
+    hooks::UseContext, ComponentUpdater, Hook, Hooks, KeyCode, KeyEvent, KeyEventKind,
+    TerminalEvent, TerminalEvents,
+};
+use futures::stream::Stream;
+use std::{
+    collections::{HashMap, HashSet},
+    pin::{pin, Pin},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll, Waker},
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+/// `UseFocus` is a hook that allows a component to participate in focus management.
+///
+/// Components which use this hook are registered as focusable with the nearest enclosing
+/// [`FocusScope`](crate::components::FocusScope), or with the root scope if there is none. Within a
+/// scope, focusable components are ordered as they appear in the tree. The first one receives
+/// focus initially, and the Tab and Shift+Tab keys move focus forwards and backwards.
+///
+/// A nested scope counts as a single stop within its parent. Focusing it activates the scope, and
+/// the Tab and Shift+Tab keys then move through the components within it before moving on to the
+/// parent's other stops, or cycle through them indefinitely if the scope traps focus. Only
+/// components within active scopes are reported as focused.
+///
+/// [`TextInput`](crate::components::TextInput) and [`Button`](crate::components::Button) use this
+/// hook when their `focusable` prop is `true`.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// #[component]
+/// fn Checkbox(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+///     let focus = hooks.use_focus();
+///     let mut checked = hooks.use_state(|| false);
+///
+///     hooks.use_terminal_events({
+///         let has_focus = focus.is_focused();
+///         move |event| match event {
+///             TerminalEvent::Key(KeyEvent { code: KeyCode::Char(' '), kind, .. })
+///                 if has_focus && kind != KeyEventKind::Release =>
+///             {
+///                 checked.set(!checked.get());
+///             }
+///             _ => {}
+///         }
+///     });
+///
+///     element! {
+///         Text(
+///             content: if checked.get() { "[x]" } else { "[ ]" },
+///             weight: if focus.is_focused() { Weight::Bold } else { Weight::Normal },
+///         )
+///     }
+/// }
+/// ```
+pub trait UseFocus: private::Sealed {
+    /// Registers the component as focusable and returns a handle which can be used to query or
+    /// change its focus.
+    fn use_focus(&mut self) -> FocusHandle;
+
+    /// Like [`use_focus`](UseFocus::use_focus), but only registers the component as focusable if
+    /// `focusable` is `true`. Otherwise the component is skipped during focus traversal and the
+    /// returned handle never reports focus.
+    fn use_focus_if(&mut self, focusable: bool) -> FocusHandle;
+}
+
+impl UseFocus for Hooks<'_, '_> {
+    fn use_focus(&mut self) -> FocusHandle {
+        self.use_focus_if(true)
+    }
+
+    fn use_focus_if(&mut self, focusable: bool) -> FocusHandle {
+        let scope = self
+            .try_use_context::<FocusScopeContext>()
+            .map(|scope| scope.clone());
+        let hook = self.use_hook(UseFocusImpl::new);
+        hook.scope = if focusable { scope } else { None };
+        hook.is_focused = hook
+            .scope
+            .as_ref()
+            .is_some_and(|scope| scope.register(hook.id));
+        FocusHandle {
+            id: hook.id,
+            scope: hook.scope.clone(),
+            is_focused: hook.is_focused,
+        }
+    }
+}
+
+/// A handle which can be used to query or change the focus of a component. It is returned by
+/// [`UseFocus::use_focus`].
+#[derive(Clone, Default)]
+pub struct FocusHandle {
+    id: u64,
+    scope: Option<FocusScopeContext>,
+    is_focused: bool,
+}
+
+impl FocusHandle {
+    /// Returns `true` if the component had focus when it was rendered.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Gives focus to the component, taking it away from any other component in the same scope.
+    /// If the component is within a nested [`FocusScope`](crate::components::FocusScope), the
+    /// scope is activated as well. The change takes effect on the next render.
+    pub fn focus(&mut self) {
+        if let Some(scope) = &self.scope {
+            scope.tree.lock().unwrap().focus(scope.id, self.id);
+        }
+    }
+
+    /// Removes focus from the component if it has it. The change takes effect on the next render.
+    pub fn blur(&mut self) {
+        if let Some(scope) = &self.scope {
+            scope.tree.lock().unwrap().blur(scope.id, self.id);
+        }
+    }
+
+    /// Keeps the Tab key from moving focus away from the component, so that the component can
+    /// handle it itself. Shift+Tab still moves focus.
+    ///
+    /// This only lasts until the component is rendered again, so it should be invoked during each
+    /// render for which the component wants to handle Tab.
+    pub fn capture_tab(&self) {
+        if let Some(scope) = &self.scope {
+            if let Some(state) = scope.tree.lock().unwrap().scopes.get_mut(&scope.id) {
+                state.captures_tab.insert(self.id);
+            }
+        }
+    }
+}
+
+fn next_id() -> u64 {
+    static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+#[derive(Default)]
+struct FocusScopeState {
+    parent: Option<u64>,
+    // The focus stops in tree order, as of the most recent render. A stop is either a focusable
+    // component or a nested scope.
+    order: Vec<u64>,
+    focused: Option<u64>,
+    did_auto_focus: bool,
+    // The components which handle Tab themselves, as of the most recent render.
+    captures_tab: HashSet<u64>,
+    // Whether traversal wraps around within the scope instead of moving on to the parent's stops.
+    trap_focus: bool,
+}
+
+// The state of every focus scope within a render loop. Scopes are kept together so that focus
+// changes which span several of them can be made atomically.
+struct FocusTree {
+    root: u64,
+    scopes: HashMap<u64, FocusScopeState>,
+    events: Option<TerminalEvents>,
+    waker: Option<Waker>,
+}
+
+impl FocusTree {
+    fn new(root: u64) -> Self {
+        Self {
+            root,
+            scopes: HashMap::from([(root, FocusScopeState::default())]),
+            events: None,
+            waker: None,
+        }
+    }
+
+    fn register(&mut self, scope: u64, id: u64) {
+        // A nested scope becomes a stop in its parent once it has something to focus.
+        if let Some(parent) = self.scopes.get(&scope).and_then(|state| state.parent) {
+            if self
+                .scopes
+                .get(&parent)
+                .is_some_and(|state| !state.order.contains(&scope))
+            {
+                self.register(parent, scope);
+            }
+        }
+        if let Some(state) = self.scopes.get_mut(&scope) {
+            state.order.push(id);
+            if !state.did_auto_focus && state.focused.is_none() {
+                state.focused = Some(id);
+                state.did_auto_focus = true;
+            }
+        }
+    }
+
+    // A scope is active if it is the root or if it's the focused stop of an active scope.
+    fn is_active(&self, scope: u64) -> bool {
+        match self.scopes.get(&scope).and_then(|state| state.parent) {
+            Some(parent) => {
+                self.scopes
+                    .get(&parent)
+                    .is_some_and(|state| state.focused == Some(scope))
+                    && self.is_active(parent)
+            }
+            None => scope == self.root,
+        }
+    }
+
+    fn is_focused(&self, scope: u64, id: u64) -> bool {
+        self.scopes
+            .get(&scope)
+            .is_some_and(|state| state.focused == Some(id))
+            && self.is_active(scope)
+    }
+
+    // Only the innermost active scope handles traversal.
+    fn innermost_active_scope(&self) -> u64 {
+        let mut scope = self.root;
+        while let Some(focused) = self.scopes.get(&scope).and_then(|state| state.focused) {
+            if !self.scopes.contains_key(&focused) {
+                break;
+            }
+            scope = focused;
+        }
+        scope
+    }
+
+    // Moves focus to the next or previous stop. Returns false if focus wasn't moved.
+    fn move_focus(&mut self, forward: bool) -> bool {
+        let mut scope = self.innermost_active_scope();
+        if forward
+            && self.scopes.get(&scope).is_some_and(|state| {
+                state
+                    .focused
+                    .is_some_and(|id| state.captures_tab.contains(&id))
+            })
+        {
+            return false;
+        }
+
+        // Moving past the first or last stop of a scope which doesn't trap focus moves on to the
+        // parent scope's adjacent stop. Otherwise, traversal wraps around.
+        let mut next = loop {
+            let Some(state) = self.scopes.get(&scope) else {
+                return false;
+            };
+            let n = state.order.len();
+            if n == 0 {
+                return false;
+            }
+            let current = state
+                .focused
+                .and_then(|id| state.order.iter().position(|&other| other == id));
+            let next = match (current, forward) {
+                (Some(i), true) => (i + 1 < n).then_some(i + 1),
+                (Some(i), false) => i.checked_sub(1),
+                (None, true) => Some(0),
+                (None, false) => Some(n - 1),
+            };
+            match (next, state.parent) {
+                (Some(i), _) => break state.order[i],
+                (None, Some(parent)) if !state.trap_focus => scope = parent,
+                (None, _) => break state.order[if forward { 0 } else { n - 1 }],
+            }
+        };
+
+        // Scopes which don't trap focus are entered at their first or last stop, depending on the
+        // direction. Scopes which do trap focus resume where they left off.
+        while let Some(state) = self.scopes.get_mut(&scope) {
+            state.focused = Some(next);
+            state.did_auto_focus = true;
+            match self.scopes.get(&next) {
+                Some(nested) if !nested.trap_focus && !nested.order.is_empty() => {
+                    scope = next;
+                    next = if forward {
+                        nested.order[0]
+                    } else {
+                        nested.order[nested.order.len() - 1]
+                    };
+                }
+                _ => break,
+            }
+        }
+        true
+    }
+
+    fn focus(&mut self, scope: u64, id: u64) {
+        if !self
+            .scopes
+            .get(&scope)
+            .is_some_and(|state| state.order.contains(&id))
+        {
+            return;
+        }
+        // Focus the component within its scope, then the scope within each of its ancestors.
+        let mut did_change = false;
+        let (mut scope, mut id) = (scope, id);
+        while let Some(state) = self.scopes.get_mut(&scope) {
+            if state.focused != Some(id) {
+                state.focused = Some(id);
+                did_change = true;
+            }
+            state.did_auto_focus = true;
+            match state.parent {
+                Some(parent) => (scope, id) = (parent, scope),
+                None => break,
+            }
+        }
+        if did_change {
+            self.wake();
+        }
+    }
+
+    fn blur(&mut self, scope: u64, id: u64) {
+        if let Some(state) = self.scopes.get_mut(&scope) {
+            if state.focused == Some(id) {
+                state.focused = None;
+                self.wake();
+            }
+        }
+    }
+
+    // Returns true if any scope's focused stop went away during the most recent render.
+    fn has_stale_focus(&self) -> bool {
+        self.scopes.values().any(|state| {
+            state
+                .focused
+                .is_some_and(|focused| !state.order.contains(&focused))
+        })
+    }
+
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// The shared state of a focus scope. The render loop provides one for the root of the tree, and
+/// [`FocusScope`](crate::components::FocusScope) provides one for each of its subtrees.
+#[derive(Clone)]
+pub(crate) struct FocusScopeContext {
+    tree: Arc<Mutex<FocusTree>>,
+    id: u64,
+}
+
+impl Default for FocusScopeContext {
+    fn default() -> Self {
+        let id = next_id();
+        Self {
+            tree: Arc::new(Mutex::new(FocusTree::new(id))),
+            id,
+        }
+    }
+}
+
+impl FocusScopeContext {
+    /// Creates a scope nested within this one. Until it is removed, the nested scope is one focus
+    /// stop within this scope.
+    pub(crate) fn new_nested(&self) -> Self {
+        let id = next_id();
+        self.tree.lock().unwrap().scopes.insert(
+            id,
+            FocusScopeState {
+                parent: Some(self.id),
+                ..Default::default()
+            },
+        );
+        Self {
+            tree: self.tree.clone(),
+            id,
+        }
+    }
+
+    /// Removes a nested scope. Must be invoked when the scope is unmounted.
+    pub(crate) fn remove(&self) {
+        let mut tree = self.tree.lock().unwrap();
+        tree.scopes.remove(&self.id);
+        tree.wake();
+    }
+
+    /// Must be invoked before each render of the components within the scope.
+    pub(crate) fn begin_render(&self, trap_focus: bool) {
+        let mut tree = self.tree.lock().unwrap();
+        let Some(state) = tree.scopes.get_mut(&self.id) else {
+            return;
+        };
+        state.trap_focus = trap_focus;
+        // If the focused component is gone, automatically focus the first one again.
+        if let Some(focused) = state.focused {
+            if !state.order.contains(&focused) {
+                state.focused = None;
+                state.did_auto_focus = false;
+            }
+        }
+        state.order.clear();
+        state.captures_tab.clear();
+    }
+
+    fn register(&self, id: u64) -> bool {
+        let mut tree = self.tree.lock().unwrap();
+        tree.register(self.id, id);
+        tree.is_focused(self.id, id)
+    }
+}
+
+struct UseFocusImpl {
+    id: u64,
+    scope: Option<FocusScopeContext>,
+    is_focused: bool,
+}
+
+impl UseFocusImpl {
+    fn new() -> Self {
+        Self {
+            id: next_id(),
+            scope: None,
+            is_focused: false,
+        }
+    }
+}
+
+impl Hook for UseFocusImpl {
+    fn poll_change(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let Some(scope) = &self.scope else {
+            return Poll::Pending;
+        };
+        let mut tree = scope.tree.lock().unwrap();
+
+        // The events are shared by every component in the render loop, so whichever component
+        // polls first handles them.
+        let mut did_move_focus = false;
+        while let Some(Poll::Ready(Some(event))) = tree
+            .events
+            .as_mut()
+            .map(|events| pin!(events).poll_next(cx))
+        {
+            if let TerminalEvent::Key(KeyEvent { code, kind, .. }) = event {
+                if kind != KeyEventKind::Release {
+                    match code {
+                        KeyCode::Tab => did_move_focus |= tree.move_focus(true),
+                        KeyCode::BackTab => did_move_focus |= tree.move_focus(false),
+                        _ => {}
+                    }
+                }
+            }
+        }
+        tree.waker = Some(cx.waker().clone());
+
+        if did_move_focus
+            || tree.has_stale_focus()
+            || tree.is_focused(scope.id, self.id) != self.is_focused
+        {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        if let Some(scope) = &self.scope {
+            let mut tree = scope.tree.lock().unwrap();
+            if tree.events.is_none() {
+                tree.events = updater.terminal_events();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use futures::stream::StreamExt;
+    use macro_rules_attribute::apply;
+    use smol_macros::test;
+
+    #[derive(Default, Props)]
+    struct FieldProps {
+        label: String,
+    }
+
+    #[component]
+    fn Field(mut hooks: Hooks, props: &FieldProps) -> impl Into<AnyElement<'static>> {
+        let focus = hooks.use_focus();
+        element! {
+            Text(content: format!("{}{}", if focus.is_focused() { ">" } else { " " }, props.label))
+        }
+    }
+
+    #[component]
+    fn MyComponent(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let mut exit_focus = hooks.use_state(|| false);
+
+        if exit_focus.get() {
+            system.exit();
+        }
+
+        element! {
+            View(flex_direction: FlexDirection::Column) {
+                Field(label: "a")
+                Field(label: "b")
+                Field(label: "c")
+                ExitField(on_focus: move |_| exit_focus.set(true))
+            }
+        }
+    }
+
+    #[derive(Default, Props)]
+    struct ExitFieldProps {
+        on_focus: HandlerMut<'static, ()>,
+    }
+
+    #[component]
+    fn ExitField(mut hooks: Hooks, props: &mut ExitFieldProps) -> impl Into<AnyElement<'static>> {
+        let focus = hooks.use_focus();
+        if focus.is_focused() {
+            let mut on_focus = props.on_focus.take();
+            on_focus(());
+        }
+        element!(Text(content: "d"))
+    }
+
+    #[apply(test!)]
+    async fn test_use_focus() {
+        let actual = element!(MyComponent)
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+                vec![
+                    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, KeyCode::Tab)),
+                    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Release, KeyCode::Tab)),
+                    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, KeyCode::BackTab)),
+                    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, KeyCode::BackTab)),
+                ],
+            )))
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(actual.first().unwrap(), ">a\n b\n c\nd\n");
+        assert_eq!(actual.last().unwrap(), " a\n b\n c\nd\n");
+    }
+
+    #[component]
+    fn ProgrammaticFocus(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let mut focus = hooks.use_focus();
+        let mut did_blur = hooks.use_state(|| false);
+
+        if !did_blur.get() {
+            focus.blur();
+            did_blur.set(true);
+        } else if !focus.is_focused() {
+            system.exit();
+        }
+
+        element! {
+            Text(content: if focus.is_focused() { "focused" } else { "blurred" })
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_use_focus_blur() {
+        let actual = element!(ProgrammaticFocus)
+            .mock_terminal_render_loop(MockTerminalConfig::default())
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(actual, vec!["focused\n", "blurred\n"]);
+    }
+
+    #[component]
+    fn Unmount(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let mut show_first = hooks.use_state(|| true);
+        let mut exit_focus = hooks.use_state(|| false);
+
+        hooks.use_future(async move { show_first.set(false) });
+
+        if exit_focus.get() {
+            system.exit();
+        }
+
+        element! {
+            View(flex_direction: FlexDirection::Column) {
+                #(show_first.get().then(|| element!(Field(label: "a"))))
+                ExitField(on_focus: move |_| exit_focus.set(true))
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_use_focus_unmount() {
+        // Once the focused component goes away, the next one should be focused automatically.
+        let actual = element!(Unmount)
+            .mock_terminal_render_loop(MockTerminalConfig::default())
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(actual.first().unwrap(), ">a\nd\n");
+        assert_eq!(actual.last().unwrap(), "d\n");
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{
+    hit_test::HitTestRegion, ComponentDrawer, ComponentUpdater, Hook, Hooks, TerminalEvent,
+    TerminalEvents,
+};
+use core::{
+    pin::{pin, Pin},
+    task::{Context, Poll},
+};
+use futures::stream::Stream;
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+/// `UseHover` is a hook that returns whether the mouse is currently over the component.
+///
+/// The component is considered hovered if it, or one of its descendants, is the topmost component
+/// under the mouse cursor. Mouse movement is only reported in fullscreen mode, so the component is
+/// never hovered otherwise.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// #[component]
+/// fn HoverHighlight(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+///     let hovered = hooks.use_hover();
+///
+///     element! {
+///         View(
+///             padding: 1,
+///             background_color: if hovered { Color::DarkGrey } else { Color::Reset },
+///         ) {
+///             Text(content: "Hover over me!")
+///         }
+///     }
+/// }
+/// ```
+pub trait UseHover: private::Sealed {
+    /// Returns `true` if the mouse is over the component.
+    fn use_hover(&mut self) -> bool;
+}
+
+impl UseHover for Hooks<'_, '_> {
+    fn use_hover(&mut self) -> bool {
+        self.use_hook(move || UseHoverImpl {
+            events: None,
+            hit_test_region: Default::default(),
+            hovered: false,
+            rendered_hovered: false,
+        })
+        .rendered()
+    }
+}
+
+struct UseHoverImpl {
+    events: Option<TerminalEvents>,
+    hit_test_region: HitTestRegion,
+    hovered: bool,
+    rendered_hovered: bool,
+}
+
+impl UseHoverImpl {
+    fn rendered(&mut self) -> bool {
+        self.rendered_hovered = self.hovered;
+        self.hovered
+    }
+}
+
+impl Hook for UseHoverImpl {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        while let Some(Poll::Ready(Some(event))) = self
+            .events
+            .as_mut()
+            .map(|events| pin!(events).poll_next(cx))
+        {
+            if let TerminalEvent::FullscreenMouse(event) = event {
+                self.hovered = self.hit_test_region.localize(event).is_some();
+            }
+        }
+        if self.hovered != self.rendered_hovered {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        if self.events.is_none() {
+            self.events = updater.terminal_events();
+        }
+    }
+
+    fn post_component_draw(&mut self, drawer: &mut ComponentDrawer) {
+        self.hit_test_region = drawer.hit_test_region();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use futures::stream::{self, StreamExt};
+    use macro_rules_attribute::apply;
+    use smol_macros::test;
+
+    #[derive(Default, Props)]
+    struct HoverBoxProps {
+        label: String,
+    }
+
+    #[component]
+    fn HoverBox(mut hooks: Hooks, props: &HoverBoxProps) -> impl Into<AnyElement<'static>> {
+        let hovered = hooks.use_hover();
+        element! {
+            View(width: 4, height: 1) {
+                Text(content: format!("{}{}", props.label, if hovered { "*" } else { " " }))
+            }
+        }
+    }
+
+    #[component]
+    fn MyComponent(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let mut moves = hooks.use_state(|| 0);
+        hooks.use_terminal_events(move |event| {
+            if let TerminalEvent::FullscreenMouse(_) = event {
+                moves += 1;
+            }
+        });
+
+        if moves.get() == 2 {
+            system.exit();
+        }
+
+        element! {
+            View(flex_direction: FlexDirection::Column) {
+                HoverBox(label: "a")
+                HoverBox(label: "b")
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_use_hover() {
+        let actual = element!(MyComponent)
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(stream::iter(vec![
+                TerminalEvent::FullscreenMouse(FullscreenMouseEvent::new(
+                    MouseEventKind::Moved,
+                    0,
+                    0,
+                )),
+                TerminalEvent::FullscreenMouse(FullscreenMouseEvent::new(
+                    MouseEventKind::Moved,
+                    1,
+                    1,
+                )),
+            ])))
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(actual.first().unwrap(), "a\nb\n");
+        assert_eq!(actual.last().unwrap(), "a\nb*\n");
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{hit_test::HitTester, ComponentUpdater, Hook, Hooks};
+use std::sync::{Arc, OnceLock};
+use taffy::NodeId;
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+/// `UseMouseCapture` is a hook that allows a component to capture the mouse.
+///
+/// While a component has captured the mouse, every mouse event is delivered to its
+/// [`use_local_terminal_events`](crate::hooks::UseTerminalEvents::use_local_terminal_events)
+/// handlers, even if the event occurs outside of the component, and no other component receives
+/// local mouse events. This is useful for drag interactions such as sliders, resizable panes, and
+/// scrollbar thumbs, which must keep tracking the pointer once it leaves the component.
+///
+/// Coordinates are still translated to component-local coordinates, but are clamped to zero for
+/// events above or to the left of the component.
+///
+/// The capture lasts until it is explicitly released, or until the component is removed from the
+/// tree.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// #[component]
+/// fn Slider(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+///     let mut capture = hooks.use_mouse_capture();
+///     let mut value = hooks.use_state(|| 0);
+///
+///     hooks.use_local_terminal_events(move |event| {
+///         if let TerminalEvent::FullscreenMouse(event) = event {
+///             match event.kind {
+///                 MouseEventKind::Down(_) => {
+///                     capture.capture();
+///                     value.set(event.column.min(19));
+///                 }
+///                 MouseEventKind::Drag(_) => value.set(event.column.min(19)),
+///                 MouseEventKind::Up(_) => capture.release(),
+///                 _ => {}
+///             }
+///         }
+///     });
+///
+///     element! {
+///         View(width: 20, height: 1) {
+///             Text(content: format!("{}●", " ".repeat(value.get() as usize)))
+///         }
+///     }
+/// }
+/// ```
+pub trait UseMouseCapture: private::Sealed {
+    /// Returns a handle which can be used to capture and release the mouse.
+    fn use_mouse_capture(&mut self) -> MouseCaptureHandle;
+}
+
+impl UseMouseCapture for Hooks<'_, '_> {
+    fn use_mouse_capture(&mut self) -> MouseCaptureHandle {
+        let hook = self.use_hook(UseMouseCaptureImpl::default);
+        MouseCaptureHandle {
+            target: hook.target.clone(),
+        }
+    }
+}
+
+/// A handle which can be used to capture and release the mouse. It is returned by
+/// [`UseMouseCapture::use_mouse_capture`].
+#[derive(Clone, Default)]
+pub struct MouseCaptureHandle {
+    target: Arc<OnceLock<(HitTester, NodeId)>>,
+}
+
+impl MouseCaptureHandle {
+    /// Routes all subsequent mouse events to the component until released. This takes the capture
+    /// away from any other component which had it.
+    pub fn capture(&mut self) {
+        if let Some((tester, node_id)) = self.target.get() {
+            tester.capture(*node_id);
+        }
+    }
+
+    /// Releases the mouse if it was captured by the component.
+    pub fn release(&mut self) {
+        if let Some((tester, node_id)) = self.target.get() {
+            tester.release(*node_id);
+        }
+    }
+
+    /// Returns `true` if the component has captured the mouse.
+    pub fn is_captured(&self) -> bool {
+        self.target
+            .get()
+            .is_some_and(|(tester, node_id)| tester.is_captured_by(*node_id))
+    }
+}
+
+#[derive(Default)]
+struct UseMouseCaptureImpl {
+    target: Arc<OnceLock<(HitTester, NodeId)>>,
+}
+
+impl Hook for UseMouseCaptureImpl {
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        if self.target.get().is_none() {
+            let _ = self.target.set(updater.hit_test_target());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crossterm::event::MouseButton;
+    use futures::stream::{self, StreamExt};
+    use macro_rules_attribute::apply;
+    use smol_macros::test;
+
+    #[component]
+    fn Slider(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let mut capture = hooks.use_mouse_capture();
+        let mut value = hooks.use_state(|| 0);
+        let mut released = hooks.use_state(|| false);
+
+        hooks.use_local_terminal_events(move |event| {
+            if let TerminalEvent::FullscreenMouse(event) = event {
+                match event.kind {
+                    MouseEventKind::Down(_) => {
+                        capture.capture();
+                        assert!(capture.is_captured());
+                        value.set(event.column);
+                    }
+                    MouseEventKind::Drag(_) => value.set(event.column),
+                    MouseEventKind::Up(_) => {
+                        capture.release();
+                        assert!(!capture.is_captured());
+                        released.set(true);
+                    }
+                    _ => {}
+                }
+            }
+        });
+
+        if released.get() {
+            system.exit();
+        }
+
+        element! {
+            View(width: 10, height: 1) {
+                Text(content: format!("value: {}", value))
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_use_mouse_capture() {
+        let actual = element! {
+            View(padding: 2) {
+                Slider
+            }
+        }
+        .mock_terminal_render_loop(MockTerminalConfig::with_events(stream::iter(vec![
+            TerminalEvent::FullscreenMouse(FullscreenMouseEvent::new(
+                MouseEventKind::Down(MouseButton::Left),
+                3,
+                2,
+            )),
+            // Both of these events are outside of the slider, but it has captured the mouse.
+            TerminalEvent::FullscreenMouse(FullscreenMouseEvent::new(
+                MouseEventKind::Drag(MouseButton::Left),
+                30,
+                9,
+            )),
+            TerminalEvent::FullscreenMouse(FullscreenMouseEvent::new(
+                MouseEventKind::Up(MouseButton::Left),
+                0,
+                0,
+            )),
+        ])))
+        .map(|c| c.to_string().trim().to_string())
+        .collect::<Vec<_>>()
+        .await;
+        assert_eq!(actual.last().unwrap(), "value: 28");
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{
+    hooks::{State, UseState},
+    ComponentDrawer, Hook, Hooks,
+};
+use taffy::Size;
+
+/// A crate-private hook which measures the size of the component.
+pub(crate) trait UseSize {
+    /// Returns the size of the component as of its most recent draw, or zero before it's first
+    /// drawn. The component is rendered again whenever its size changes.
+    fn use_size(&mut self) -> State<Size<u16>>;
+}
+
+impl UseSize for Hooks<'_, '_> {
+    fn use_size(&mut self) -> State<Size<u16>> {
+        let size = self.use_state(Size::default);
+        self.use_hook(move || UseSizeImpl { out: size }).out = size;
+        size
+    }
+}
+
+// Measures the component size in pre_component_draw and writes the result to a state.
+struct UseSizeImpl {
+    out: State<Size<u16>>,
+}
+
+impl Hook for UseSizeImpl {
+    fn pre_component_draw(&mut self, drawer: &mut ComponentDrawer) {
+        let size = drawer.size();
+        if self.out.try_get() != Some(size) {
+            self.out.set(size);
+        }
+    }
+}
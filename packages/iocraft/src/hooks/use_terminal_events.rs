@@ -1,10 +1,11 @@
-use crate::{ComponentUpdater, FullscreenMouseEvent, Hook, Hooks, TerminalEvent, TerminalEvents};
+use crate::{
+    hit_test::HitTestRegion, ComponentUpdater, Hook, Hooks, TerminalEvent, TerminalEvents,
+};
 use core::{
     pin::{pin, Pin},
     task::{Context, Poll},
 };
 use futures::stream::Stream;
-use taffy::{Point, Size};
 
 mod private {
     pub trait Sealed {}
@@ -101,8 +102,9 @@ pub trait UseTerminalEvents: private::Sealed {
     /// Defines a callback to be invoked whenever a terminal event occurs within a component.
     ///
     /// Unlike [`Self::use_terminal_events`], this hook will not be called for events such as mouse
-    /// events that occur outside of the component. Furthermore, coordinates will be translated to
-    /// component-local coordinates.
+    /// events that occur outside of the component. Mouse events are also not delivered if another
+    /// component is drawn on top of this one at the event's position. Furthermore, coordinates will
+    /// be translated to component-local coordinates.
     fn use_local_terminal_events<F>(&mut self, f: F)
     where
         F: FnMut(TerminalEvent) + Send + 'static;
@@ -115,7 +117,7 @@ impl UseTerminalEvents for Hooks<'_, '_> {
     {
         let h = self.use_hook(move || UseTerminalEventsImpl {
             events: None,
-            component_location: Default::default(),
+            hit_test_region: Default::default(),
             in_component: false,
             f: None,
         });
@@ -128,7 +130,7 @@ impl UseTerminalEvents for Hooks<'_, '_> {
     {
         let h = self.use_hook(move || UseTerminalEventsImpl {
             events: None,
-            component_location: Default::default(),
+            hit_test_region: Default::default(),
             in_component: true,
             f: None,
         });
@@ -138,7 +140,7 @@ impl UseTerminalEvents for Hooks<'_, '_> {
 
 struct UseTerminalEventsImpl {
     events: Option<TerminalEvents>,
-    component_location: (Point<i16>, Size<u16>),
+    hit_test_region: HitTestRegion,
     in_component: bool,
     f: Option<Box<dyn FnMut(TerminalEvent) + Send + 'static>>,
 }
@@ -151,24 +153,19 @@ impl Hook for UseTerminalEventsImpl {
             .map(|events| pin!(events).poll_next(cx))
         {
             if self.in_component {
-                let (location, size) = self.component_location;
                 match event {
                     TerminalEvent::FullscreenMouse(event) => {
-                        if event.row as i16 >= location.y && event.column as i16 >= location.x {
-                            let row = (event.row as i16 - location.y) as u16;
-                            let column = (event.column as i16 - location.x) as u16;
-                            if row < size.height && column < size.width {
-                                if let Some(f) = &mut self.f {
-                                    f(TerminalEvent::FullscreenMouse(FullscreenMouseEvent {
-                                        row,
-                                        column,
-                                        ..event
-                                    }));
-                                }
+                        if let Some(event) = self.hit_test_region.localize(event) {
+                            if let Some(f) = &mut self.f {
+                                f(TerminalEvent::FullscreenMouse(event));
                             }
                         }
                     }
-                    TerminalEvent::Key(_) | TerminalEvent::Resize(..) => {
+                    TerminalEvent::Key(_)
+                    | TerminalEvent::Resize(..)
+                    | TerminalEvent::Paste(_)
+                    | TerminalEvent::FocusGained
+                    | TerminalEvent::FocusLost => {
                         if let Some(f) = &mut self.f {
                             f(event);
                         }
@@ -188,7 +185,7 @@ impl Hook for UseTerminalEventsImpl {
     }
 
     fn post_component_draw(&mut self, drawer: &mut crate::ComponentDrawer) {
-        self.component_location = (drawer.canvas_position(), drawer.size());
+        self.hit_test_region = drawer.hit_test_region();
     }
 }
 
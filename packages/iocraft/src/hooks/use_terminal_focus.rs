@@ -0,0 +1,172 @@
+use crate::{
+    hooks::{UseContext, UseState, UseTerminalEvents},
+    ComponentUpdater, Hook, Hooks, SystemContext, TerminalEvent,
+};
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+/// `UseTerminalFocus` is a hook that returns whether the terminal window currently has focus.
+///
+/// This can be used to pause animations or dim the UI while the user is looking at another
+/// window. Terminals which don't support focus reporting never send focus events, so the
+/// terminal is assumed to be focused until told otherwise.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// #[component]
+/// fn FocusIndicator(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+///     let focused = hooks.use_terminal_focus();
+///
+///     element! {
+///         Text(
+///             color: if focused { Color::Green } else { Color::DarkGrey },
+///             content: if focused { "focused" } else { "unfocused" },
+///         )
+///     }
+/// }
+/// ```
+pub trait UseTerminalFocus: private::Sealed {
+    /// Returns `true` if the terminal window has focus.
+    fn use_terminal_focus(&mut self) -> bool;
+}
+
+impl UseTerminalFocus for Hooks<'_, '_> {
+    fn use_terminal_focus(&mut self) -> bool {
+        // The render loop keeps track of focus in the system context, so that components mounted
+        // after a focus event still see it. The events are only used to trigger a re-render.
+        let mut last_event_focused = self.use_state(|| true);
+        self.use_terminal_events(move |event| match event {
+            TerminalEvent::FocusGained => last_event_focused.set(true),
+            TerminalEvent::FocusLost => last_event_focused.set(false),
+            _ => {}
+        });
+        // The component may be holding the system context mutably, in which case the hook's own
+        // copy is used and corrected after the update.
+        let focused = self
+            .try_use_context::<SystemContext>()
+            .map(|system| system.terminal_focused());
+        let hook = self.use_hook(move || UseTerminalFocusImpl {
+            focused: focused.unwrap_or(true),
+            rendered: true,
+            changed: false,
+        });
+        if let Some(focused) = focused {
+            hook.focused = focused;
+        }
+        hook.rendered = hook.focused;
+        hook.focused
+    }
+}
+
+struct UseTerminalFocusImpl {
+    focused: bool,
+    rendered: bool,
+    changed: bool,
+}
+
+impl UseTerminalFocusImpl {
+    fn refresh(&mut self, updater: &ComponentUpdater) {
+        if let Some(system) = updater.get_context::<SystemContext>() {
+            self.focused = system.terminal_focused();
+        }
+    }
+}
+
+impl Hook for UseTerminalFocusImpl {
+    fn poll_change(mut self: Pin<&mut Self>, _cx: &mut Context) -> Poll<()> {
+        if std::mem::take(&mut self.changed) {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn pre_component_update(&mut self, updater: &mut ComponentUpdater) {
+        self.refresh(updater);
+    }
+
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        self.refresh(updater);
+        self.changed = self.focused != self.rendered;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use futures::stream::StreamExt;
+    use macro_rules_attribute::apply;
+    use smol_macros::test;
+
+    #[component]
+    fn MyComponent(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let focused = hooks.use_terminal_focus();
+
+        if !focused {
+            system.exit();
+        }
+
+        element! {
+            Text(content: if focused { "focused" } else { "unfocused" })
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_use_terminal_focus() {
+        let actual = element!(MyComponent)
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+                vec![TerminalEvent::FocusLost],
+            )))
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(actual, vec!["focused\n", "unfocused\n"]);
+    }
+
+    #[component]
+    fn LateComponent(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let focused = hooks.use_terminal_focus();
+        hooks.use_context_mut::<SystemContext>().exit();
+        element! {
+            Text(content: if focused { "focused" } else { "unfocused" })
+        }
+    }
+
+    #[component]
+    fn MountAfterFocusLost(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut show = hooks.use_state(|| false);
+        hooks.use_terminal_events(move |event| {
+            if let TerminalEvent::FocusLost = event {
+                show.set(true);
+            }
+        });
+        element! {
+            View {
+                #(show.get().then(|| element!(LateComponent)))
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_use_terminal_focus_after_mount() {
+        // A component mounted after the terminal lost focus should know about it.
+        let actual = element!(MountAfterFocusLost)
+            .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+                vec![TerminalEvent::FocusLost],
+            )))
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(actual, vec!["", "unfocused\n"]);
+    }
+}
@@ -7,7 +7,7 @@
 //! ## Features
 //!
 //! - Define your UI using a clean, highly readable syntax.
-//! - Organize your UI using flexbox layouts powered by [`taffy`](https://docs.rs/taffy/).
+//! - Organize your UI using flexbox and grid layouts powered by [`taffy`](https://docs.rs/taffy/).
 //! - Output colored and styled UIs to the terminal or ASCII output anywhere else.
 //! - Create animated or interactive elements with event handling and hooks.
 //! - Build fullscreen terminal applications with ease.
@@ -103,11 +103,15 @@
 // Those types will remain in their modules for the public API.
 
 mod any_key;
+mod bidi;
 mod canvas;
+mod clipboard;
+mod color_depth;
 mod component;
 mod context;
 mod element;
 mod handler;
+mod hit_test;
 mod hook;
 mod multimap;
 mod props;
@@ -115,11 +119,13 @@ mod render;
 pub(crate) mod segmented_string;
 mod strip_ansi;
 mod style;
+mod tabs;
 mod terminal;
 pub(crate) mod unicode_linebreak;
 
 mod flattened_exports {
     pub use crate::canvas::*;
+    pub use crate::color_depth::*;
     pub use crate::component::*;
     pub use crate::context::*;
     pub use crate::element::*;
@@ -135,7 +141,7 @@ mod flattened_exports {
 
 pub use flattened_exports::*;
 
-/// Re-exported [`taffy`] crate, which powers flexbox layout in `iocraft`.
+/// Re-exported [`taffy`] crate, which powers flexbox and grid layout in `iocraft`.
 ///
 /// This allows you to reference `taffy` types (e.g. [`taffy::Size`],
 /// [`taffy::AvailableSpace`]) in your own code without adding a separate
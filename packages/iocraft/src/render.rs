@@ -3,6 +3,8 @@ use crate::{
     component::{ComponentHelperExt, Components, InstantiatedComponent},
     context::{Context, ContextStack, SystemContext},
     element::ElementExt,
+    hit_test::{HitRegion, HitTestRegion, HitTester},
+    hooks::FocusScopeContext,
     multimap::AppendOnlyMultimap,
     props::AnyProps,
     terminal::{MockTerminalConfig, MockTerminalOutputStream, Terminal, TerminalEvents},
@@ -25,6 +27,7 @@ use taffy::{
 pub(crate) struct UpdateContext<'a, 'w> {
     terminal: Option<&'a mut Terminal<'w>>,
     layout_engine: &'a mut LayoutEngine,
+    hit_tester: &'a HitTester,
     did_clear_terminal_output: bool,
 }
 
@@ -88,6 +91,12 @@ impl<'a, 'b, 'c, 'w> ComponentUpdater<'a, 'b, 'c, 'w> {
         self.context.terminal.as_deref_mut()
     }
 
+    /// Returns the hit tester used to dispatch mouse events, along with the node of the current
+    /// component within it.
+    pub(crate) fn hit_test_target(&self) -> (HitTester, NodeId) {
+        (self.context.hit_tester.clone(), self.node_id)
+    }
+
     #[doc(hidden)]
     pub fn component_context_stack(&self) -> &ContextStack<'c> {
         self.component_context_stack
@@ -218,6 +227,8 @@ impl<'a, 'b, 'c, 'w> ComponentUpdater<'a, 'b, 'c, 'w> {
 struct DrawContext<'a> {
     layout_engine: &'a LayoutEngine,
     canvas: &'a mut Canvas,
+    hit_tester: &'a HitTester,
+    hit_regions: Vec<HitRegion>,
 }
 
 /// Provides information and operations that low level component implementations may need to
@@ -227,6 +238,7 @@ pub struct ComponentDrawer<'a> {
     node_position: Point<i16>,
     node_size: Size<u16>,
     clip_rect: Rect<u16>,
+    hit_region: usize,
     context: DrawContext<'a>,
 }
 
@@ -295,6 +307,63 @@ impl ComponentDrawer<'_> {
         self.node_size = old_node_size;
     }
 
+    /// Records a hit-testing region for the given component, then invokes the given closure to
+    /// draw the component and its children. If `opaque` is `false`, the component itself occupies
+    /// no area and can only be hit via its children.
+    pub(crate) fn with_hit_region<F>(&mut self, node_id: NodeId, opaque: bool, f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let rect = if opaque {
+            let clamp = |v: i32| v.clamp(0, u16::MAX as i32) as u16;
+            let left = clamp((self.node_position.x as i32).max(self.clip_rect.left as i32));
+            let top = clamp((self.node_position.y as i32).max(self.clip_rect.top as i32));
+            let right = clamp(
+                (self.node_position.x as i32 + self.node_size.width as i32)
+                    .min(self.clip_rect.right as i32),
+            );
+            let bottom = clamp(
+                (self.node_position.y as i32 + self.node_size.height as i32)
+                    .min(self.clip_rect.bottom as i32),
+            );
+            Rect {
+                left,
+                right: right.max(left),
+                top,
+                bottom: bottom.max(top),
+            }
+        } else {
+            Rect {
+                left: 0,
+                right: 0,
+                top: 0,
+                bottom: 0,
+            }
+        };
+        let index = self.context.hit_regions.len();
+        self.context.hit_regions.push(HitRegion {
+            node_id,
+            rect,
+            subtree_end: index,
+        });
+        let old_hit_region = self.hit_region;
+        self.hit_region = index;
+        f(self);
+        self.hit_region = old_hit_region;
+        self.context.hit_regions[index].subtree_end = self.context.hit_regions.len() - 1;
+    }
+
+    /// Returns the hit-testing region of the current component, which can be used to determine
+    /// whether mouse events are targeted at the component.
+    pub(crate) fn hit_test_region(&self) -> HitTestRegion {
+        HitTestRegion {
+            tester: self.context.hit_tester.clone(),
+            index: self.hit_region,
+            position: self.node_position,
+            size: self.node_size,
+        }
+    }
+
     /// Prepares to begin drawing a node's children by shrinking the clipping rectangle if necessary.
     pub(crate) fn with_clip_rect_for_children<F>(&mut self, f: F)
     where
@@ -352,6 +421,8 @@ struct Tree<'a> {
     root_component: InstantiatedComponent,
     root_component_props: AnyProps<'a>,
     system_context: SystemContext,
+    focus_scope: FocusScopeContext,
+    hit_tester: HitTester,
 }
 
 struct RenderOutput {
@@ -374,6 +445,8 @@ impl<'a> Tree<'a> {
             root_component: InstantiatedComponent::new(root_node_id, props.borrow(), helper),
             root_component_props: props,
             system_context: SystemContext::new(),
+            focus_scope: FocusScopeContext::default(),
+            hit_tester: HitTester::default(),
         }
     }
 
@@ -387,14 +460,21 @@ impl<'a> Tree<'a> {
             let mut context = UpdateContext {
                 terminal,
                 layout_engine: &mut self.layout_engine,
+                hit_tester: &self.hit_tester,
                 did_clear_terminal_output: false,
             };
             let mut component_context_stack = ContextStack::root(&mut self.system_context);
-            self.root_component.update(
-                &mut context,
-                &mut wrapper_child_node_ids,
-                &mut component_context_stack,
-                self.root_component_props.borrow(),
+            self.focus_scope.begin_render(false);
+            component_context_stack.with_context(
+                Some(Context::from_ref(&self.focus_scope)),
+                |component_context_stack| {
+                    self.root_component.update(
+                        &mut context,
+                        &mut wrapper_child_node_ids,
+                        component_context_stack,
+                        self.root_component_props.borrow(),
+                    );
+                },
             );
             context.did_clear_terminal_output
         };
@@ -448,12 +528,16 @@ impl<'a> Tree<'a> {
                 top: 0,
                 bottom: wrapper_layout.size.height as _,
             },
+            hit_region: 0,
             context: DrawContext {
                 layout_engine: &self.layout_engine,
                 canvas: &mut canvas,
+                hit_tester: &self.hit_tester,
+                hit_regions: Vec::new(),
             },
         };
         self.root_component.draw(&mut drawer);
+        self.hit_tester.set_regions(drawer.context.hit_regions);
         RenderOutput {
             canvas,
             did_clear_terminal_output,
@@ -466,6 +550,7 @@ impl<'a> Tree<'a> {
         loop {
             term.refresh_size();
             let terminal_size = term.size();
+            self.system_context.set_terminal_focused(term.has_focus());
             term.synchronized_update(|mut term| {
                 let output = self.render(terminal_size.map(|(w, _)| w as usize), Some(&mut term));
                 if output.did_clear_terminal_output || prev_canvas.as_ref() != Some(&output.canvas)
@@ -3,6 +3,7 @@ use core::{
     fmt::{self, Display},
     mem,
 };
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 /// A `SegmentedString` is a string consisting of multiple segments, which don't have to be
@@ -15,6 +16,17 @@ pub struct SegmentedString<'a> {
     segments: Vec<&'a str>,
 }
 
+/// The ellipsis inserted when text is truncated.
+pub const ELLIPSIS: &str = "\u{2026}";
+
+/// The position at which text is removed when it is truncated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TruncationPosition {
+    Start,
+    Middle,
+    End,
+}
+
 /// A `SegmentedStringLine` is a line of text after wrapping.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct SegmentedStringLine<'a> {
@@ -28,6 +40,79 @@ impl<'a> SegmentedStringLine<'a> {
         self.segments.push(segment);
     }
 
+    /// Truncates the line to the given width, replacing the removed text with an ellipsis.
+    ///
+    /// The ellipsis takes on the segment index of the first character it replaces.
+    pub fn truncate(&mut self, width: usize, position: TruncationPosition) {
+        if self.width <= width {
+            return;
+        }
+
+        let segments = mem::take(&mut self.segments);
+        self.width = 0;
+        let ellipsis_width = ELLIPSIS.width();
+        if width < ellipsis_width {
+            return;
+        }
+
+        let available = width - ellipsis_width;
+        let (head_width, tail_width) = match position {
+            TruncationPosition::Start => (0, available),
+            TruncationPosition::Middle => (available - available / 2, available / 2),
+            TruncationPosition::End => (available, 0),
+        };
+
+        // Find where the head ends: (segment, byte offset).
+        let mut head_end = (segments.len(), 0);
+        let mut w = 0;
+        'head: for (i, segment) in segments.iter().enumerate() {
+            for (idx, g) in segment.text.grapheme_indices(true) {
+                let char_width = g.width();
+                if w + char_width > head_width {
+                    head_end = (i, idx);
+                    break 'head;
+                }
+                w += char_width;
+            }
+        }
+
+        // Find where the tail starts: (segment, byte offset).
+        let mut tail_start = (segments.len(), 0);
+        let mut w = 0;
+        'tail: for (i, segment) in segments.iter().enumerate().rev() {
+            tail_start = (i, segment.text.len());
+            for (idx, g) in segment.text.grapheme_indices(true).rev() {
+                let char_width = g.width();
+                if w + char_width > tail_width {
+                    break 'tail;
+                }
+                w += char_width;
+                tail_start = (i, idx);
+            }
+        }
+
+        for (i, segment) in segments.iter().enumerate() {
+            if i < head_end.0 {
+                self.push_segment(segment.clone());
+            } else if i == head_end.0 {
+                if head_end.1 > 0 {
+                    self.push_segment(segment.substring(0, head_end.1));
+                }
+                self.push_segment(SegmentedStringLineSegment {
+                    text: ELLIPSIS,
+                    index: segment.index,
+                    offset: segment.offset + head_end.1,
+                    width: ellipsis_width,
+                });
+            }
+            if i == tail_start.0 && tail_start.1 < segment.text.len() {
+                self.push_segment(segment.substring(tail_start.1, segment.text.len()));
+            } else if i > tail_start.0 {
+                self.push_segment(segment.clone());
+            }
+        }
+    }
+
     /// Removes trailing whitespace from the line.
     pub fn trim_end(&mut self) {
         for i in (0..self.segments.len()).rev() {
@@ -205,11 +290,11 @@ impl<'a> SegmentedString<'a> {
                         // This segment is too wide, we need to forcefully break it
                         let mut w = 0;
                         let mut start_idx = 0;
-                        for (idx, c) in segment.text.char_indices() {
+                        for (idx, g) in segment.text.grapheme_indices(true) {
                             if idx >= trailing_whitespace_idx {
                                 break;
                             }
-                            let char_width = c.width().unwrap_or(0);
+                            let char_width = g.width();
                             if w > 0 && w + char_width > width {
                                 // We have a full line
                                 current_line.push_segment(segment.substring(start_idx, idx));
@@ -260,6 +345,53 @@ impl<'a> SegmentedString<'a> {
     }
 }
 
+impl SegmentedString<'_> {
+    /// Splits the string into lines of a given width, breaking between any two grapheme clusters
+    /// rather than only at line break opportunities.
+    pub fn break_anywhere(&self, width: usize) -> Vec<SegmentedStringLine<'_>> {
+        let mut lines = Vec::new();
+        for line in self.wrap(usize::MAX) {
+            let mut current_line = SegmentedStringLine::default();
+            for segment in line.segments {
+                let mut start_idx = 0;
+                let mut w = 0;
+                for (idx, g) in segment.text.grapheme_indices(true) {
+                    let char_width = g.width();
+                    let line_width = current_line.width + w;
+                    if char_width > 0 && line_width > 0 && line_width + char_width > width {
+                        if start_idx < idx {
+                            current_line.push_segment(segment.substring(start_idx, idx));
+                        }
+                        lines.push(mem::take(&mut current_line));
+                        start_idx = idx;
+                        w = 0;
+                    }
+                    w += char_width;
+                }
+                // Empty segments are kept since they mark the position of empty lines.
+                if start_idx < segment.text.len() || start_idx == 0 {
+                    current_line.push_segment(segment.substring(start_idx, segment.text.len()));
+                }
+            }
+            lines.push(current_line);
+        }
+        lines
+    }
+
+    /// Splits the string into lines without wrapping, truncating each line to the given width.
+    pub fn truncate(
+        &self,
+        width: usize,
+        position: TruncationPosition,
+    ) -> Vec<SegmentedStringLine<'_>> {
+        let mut lines = self.wrap(usize::MAX);
+        for line in &mut lines {
+            line.truncate(width, position);
+        }
+        lines
+    }
+}
+
 impl<'a> From<&'a str> for SegmentedString<'a> {
     fn from(text: &'a str) -> Self {
         [text].into_iter().collect()
@@ -409,4 +541,100 @@ mod tests {
             assert_eq!(lines, vec!["this is a ", "wrapping test"]);
         }
     }
+
+    #[test]
+    fn test_segmented_string_break_anywhere() {
+        let segmented_string: SegmentedString = ["foo ba", "r\n\nbaz", "e\u{301}qux\n"]
+            .into_iter()
+            .collect();
+        let lines = segmented_string.break_anywhere(4);
+        assert_eq!(
+            lines.iter().map(|l| l.to_string()).collect::<Vec<_>>(),
+            vec!["foo ", "bar", "", "baze\u{301}", "qux", ""]
+        );
+        assert_eq!(lines[1].segments.len(), 2);
+        assert_eq!(lines[3].width, 4);
+        assert_eq!(lines[5].segments[0].offset, 7);
+
+        let segmented_string = SegmentedString::from("abc");
+        let lines = segmented_string.break_anywhere(0);
+        assert_eq!(
+            lines.iter().map(|l| l.to_string()).collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
+    }
+
+    #[test]
+    fn test_segmented_string_grapheme_clusters() {
+        // Grapheme clusters are never split when breaking words.
+        let segmented_string =
+            SegmentedString::from("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{1f1fa}\u{1f1f8}");
+        assert_eq!(
+            segmented_string
+                .wrap(3)
+                .into_iter()
+                .map(|l| (l.to_string(), l.width))
+                .collect::<Vec<_>>(),
+            vec![
+                ("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}".to_string(), 2),
+                ("\u{1f1fa}\u{1f1f8}".to_string(), 2),
+            ]
+        );
+        assert_eq!(
+            segmented_string
+                .break_anywhere(3)
+                .into_iter()
+                .map(|l| l.to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}",
+                "\u{1f1fa}\u{1f1f8}"
+            ]
+        );
+        assert_eq!(
+            segmented_string.truncate(3, TruncationPosition::Start)[0].to_string(),
+            "…\u{1f1fa}\u{1f1f8}"
+        );
+    }
+
+    #[test]
+    fn test_segmented_string_truncate() {
+        let segmented_string: SegmentedString = ["foo", "bar\n", "bazqux"].into_iter().collect();
+
+        let lines = segmented_string.truncate(5, TruncationPosition::End);
+        assert_eq!(
+            lines.iter().map(|l| l.to_string()).collect::<Vec<_>>(),
+            vec!["foob…", "bazq…"]
+        );
+        assert_eq!(lines[0].width, 5);
+        assert_eq!(lines[0].segments[2].text, ELLIPSIS);
+        assert_eq!(lines[0].segments[2].index, 1);
+
+        let lines = segmented_string.truncate(5, TruncationPosition::Start);
+        assert_eq!(
+            lines.iter().map(|l| l.to_string()).collect::<Vec<_>>(),
+            vec!["…obar", "…zqux"]
+        );
+        assert_eq!(lines[0].segments[0].index, 0);
+
+        let lines = segmented_string.truncate(4, TruncationPosition::Middle);
+        assert_eq!(
+            lines.iter().map(|l| l.to_string()).collect::<Vec<_>>(),
+            vec!["fo…r", "ba…x"]
+        );
+
+        let lines = segmented_string.truncate(0, TruncationPosition::End);
+        assert_eq!(
+            lines.iter().map(|l| l.to_string()).collect::<Vec<_>>(),
+            vec!["", ""]
+        );
+
+        // Combining characters aren't separated from their base characters.
+        let segmented_string = SegmentedString::from("abcde\u{301}");
+        let lines = segmented_string.truncate(3, TruncationPosition::Start);
+        assert_eq!(lines[0].to_string(), "…de\u{301}");
+        let segmented_string = SegmentedString::from("abc\u{301}d");
+        let lines = segmented_string.truncate(2, TruncationPosition::Start);
+        assert_eq!(lines[0].to_string(), "…d");
+    }
 }
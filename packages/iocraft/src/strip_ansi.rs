@@ -26,7 +26,7 @@ pub const ANSI_REGEX_PATTERN: &str = concat!(
     "(?:\\x1B#[34568])"
 );
 
-static ANSI_REGEX: LazyLock<Regex> =
+pub(crate) static ANSI_REGEX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(ANSI_REGEX_PATTERN).expect("valid ANSI regex"));
 
 pub(crate) fn strip_ansi(string: &str) -> Cow<'_, str> {
@@ -2,14 +2,20 @@ use bitflags::bitflags;
 use iocraft_macros::with_layout_style_props;
 use taffy::{
     geometry,
-    style::{Dimension, LengthPercentage, LengthPercentageAuto},
+    style::{
+        Dimension, GridTrackRepetition, LengthPercentage, LengthPercentageAuto,
+        MaxTrackSizingFunction, MinTrackSizingFunction, NonRepeatedTrackSizingFunction,
+        TrackSizingFunction,
+    },
+    style_helpers::{TaffyGridLine, TaffyGridSpan},
     Rect, Style,
 };
 
 // Re-export basic enum types.
 pub use crossterm::style::Color;
 pub use taffy::style::{
-    AlignContent, AlignItems, Display, FlexDirection, FlexWrap, JustifyContent, Overflow, Position,
+    AlignContent, AlignItems, AlignSelf, Display, FlexDirection, FlexWrap, GridAutoFlow,
+    JustifyContent, JustifyItems, JustifySelf, Overflow, Position,
 };
 
 /// Defines a type that represents a percentage [0.0-100.0] and is convertible to any of the
@@ -207,6 +213,179 @@ impl From<FlexBasis> for Dimension {
     }
 }
 
+/// Defines the size of a grid track, i.e. a row or column of a grid.
+///
+/// See [the MDN documentation for grid-template-columns](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-template-columns).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum GridTrack {
+    /// Sizes the track based on its content and the available space. This is the default.
+    #[default]
+    Auto,
+    /// Sets an absolute size.
+    Length(u32),
+    /// Sets a percentage of the width or height of the grid container.
+    Percent(f32),
+    /// Takes a share of the remaining space, proportional to the given value. This is equivalent
+    /// to the `fr` unit in CSS.
+    Fraction(f32),
+    /// Sizes the track to the smallest size that fits its content.
+    MinContent,
+    /// Sizes the track to the largest size that its content could need.
+    MaxContent,
+    /// Sizes the track between a minimum and a maximum. This is equivalent to `minmax()` in CSS.
+    ///
+    /// A [`GridTrack::Fraction`] minimum is treated as [`GridTrack::Auto`]. Nested
+    /// [`GridTrack::MinMax`] and [`GridTrack::Repeat`] values are not supported.
+    MinMax(Box<GridTrack>, Box<GridTrack>),
+    /// Repeats the given tracks. This is equivalent to `repeat()` in CSS. Repetitions are only
+    /// supported in grid templates, and can't be nested.
+    Repeat(GridRepetition, Vec<GridTrack>),
+}
+
+impl GridTrack {
+    /// Creates a track which is sized between a minimum and a maximum. This is equivalent to
+    /// `minmax()` in CSS.
+    pub fn minmax(min: GridTrack, max: GridTrack) -> Self {
+        Self::MinMax(Box::new(min), Box::new(max))
+    }
+
+    /// Creates a repetition of the given tracks. This is equivalent to `repeat()` in CSS.
+    pub fn repeat<R: Into<GridRepetition>>(repetition: R, tracks: Vec<GridTrack>) -> Self {
+        Self::Repeat(repetition.into(), tracks)
+    }
+
+    fn min_sizing_function(&self) -> MinTrackSizingFunction {
+        match self {
+            Self::Length(l) => MinTrackSizingFunction::Fixed(LengthPercentage::Length(*l as _)),
+            Self::Percent(p) => MinTrackSizingFunction::Fixed(LengthPercentage::Percent(p / 100.0)),
+            Self::MinContent => MinTrackSizingFunction::MinContent,
+            Self::MaxContent => MinTrackSizingFunction::MaxContent,
+            Self::MinMax(min, _) => min.min_sizing_function(),
+            Self::Auto | Self::Fraction(_) | Self::Repeat(..) => MinTrackSizingFunction::Auto,
+        }
+    }
+
+    fn max_sizing_function(&self) -> MaxTrackSizingFunction {
+        match self {
+            Self::Length(l) => MaxTrackSizingFunction::Fixed(LengthPercentage::Length(*l as _)),
+            Self::Percent(p) => MaxTrackSizingFunction::Fixed(LengthPercentage::Percent(p / 100.0)),
+            Self::Fraction(f) => MaxTrackSizingFunction::Fraction(*f),
+            Self::MinContent => MaxTrackSizingFunction::MinContent,
+            Self::MaxContent => MaxTrackSizingFunction::MaxContent,
+            Self::MinMax(_, max) => max.max_sizing_function(),
+            Self::Auto | Self::Repeat(..) => MaxTrackSizingFunction::Auto,
+        }
+    }
+}
+
+impl From<&GridTrack> for NonRepeatedTrackSizingFunction {
+    fn from(track: &GridTrack) -> Self {
+        match track {
+            GridTrack::MinMax(min, max) => Self {
+                min: min.min_sizing_function(),
+                max: max.max_sizing_function(),
+            },
+            _ => Self {
+                min: track.min_sizing_function(),
+                max: track.max_sizing_function(),
+            },
+        }
+    }
+}
+
+impl From<&GridTrack> for TrackSizingFunction {
+    fn from(track: &GridTrack) -> Self {
+        match track {
+            GridTrack::Repeat(repetition, tracks) => Self::Repeat(
+                (*repetition).into(),
+                tracks.iter().map(Into::into).collect(),
+            ),
+            _ => Self::Single(track.into()),
+        }
+    }
+}
+
+/// Defines how many times the tracks of a [`GridTrack::Repeat`] are repeated.
+///
+/// See [the MDN documentation for repeat](https://developer.mozilla.org/en-US/docs/Web/CSS/repeat).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GridRepetition {
+    /// Repeats the tracks the given number of times.
+    Count(u16),
+    /// Repeats the tracks as many times as will fit in the container.
+    AutoFill,
+    /// Like [`GridRepetition::AutoFill`], but empty repeated tracks are collapsed.
+    AutoFit,
+}
+
+impl From<u16> for GridRepetition {
+    fn from(count: u16) -> Self {
+        Self::Count(count)
+    }
+}
+
+impl From<GridRepetition> for GridTrackRepetition {
+    fn from(r: GridRepetition) -> Self {
+        match r {
+            GridRepetition::Count(n) => GridTrackRepetition::Count(n),
+            GridRepetition::AutoFill => GridTrackRepetition::AutoFill,
+            GridRepetition::AutoFit => GridTrackRepetition::AutoFit,
+        }
+    }
+}
+
+/// Defines which rows or columns of a grid an item occupies.
+///
+/// Lines are numbered starting at 1. Negative numbers count backwards from the last line, so
+/// `-1` refers to the last line of the explicit grid.
+///
+/// As a shorthand, you can use an integer in the [`element!`](crate::element!) macro to place an
+/// item at a specific line.
+///
+/// See [the MDN documentation for grid-row](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-row).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GridPlacement {
+    /// Places the item automatically. This is the default.
+    #[default]
+    Auto,
+    /// Places the item in the track starting at the given line.
+    Line(i16),
+    /// Places the item automatically, spanning the given number of tracks.
+    Span(u16),
+    /// Places the item between the given start and end lines.
+    Lines(i16, i16),
+    /// Places the item starting at the given line, spanning the given number of tracks.
+    LineSpan(i16, u16),
+}
+
+impl From<i16> for GridPlacement {
+    fn from(line: i16) -> Self {
+        Self::Line(line)
+    }
+}
+
+impl From<i32> for GridPlacement {
+    fn from(line: i32) -> Self {
+        Self::Line(line as _)
+    }
+}
+
+impl From<GridPlacement> for geometry::Line<taffy::style::GridPlacement> {
+    fn from(p: GridPlacement) -> Self {
+        use taffy::style::GridPlacement as P;
+        let (start, end) = match p {
+            GridPlacement::Auto => (P::Auto, P::Auto),
+            GridPlacement::Line(line) => (P::from_line_index(line), P::Auto),
+            GridPlacement::Span(span) => (P::from_span(span), P::Auto),
+            GridPlacement::Lines(start, end) => {
+                (P::from_line_index(start), P::from_line_index(end))
+            }
+            GridPlacement::LineSpan(start, span) => (P::from_line_index(start), P::from_span(span)),
+        };
+        Self { start, end }
+    }
+}
+
 /// A weight which can be applied to text.
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub enum Weight {
@@ -219,6 +398,22 @@ pub enum Weight {
     Light,
 }
 
+/// The style of an underline.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum UnderlineStyle {
+    /// A single straight line. This is the default.
+    #[default]
+    Single,
+    /// Two straight lines.
+    Double,
+    /// A wavy line, as is often used to indicate spelling errors.
+    Curly,
+    /// A dotted line.
+    Dotted,
+    /// A dashed line.
+    Dashed,
+}
+
 bitflags! {
     /// Defines the edges of an element, e.g. for border styling.
     #[derive(Clone, Copy, Debug, Default, PartialEq)]
@@ -291,8 +486,18 @@ impl From<LayoutStyle> for Style {
             flex_grow: s.flex_grow,
             flex_shrink: s.flex_shrink.unwrap_or(1.0),
             align_items: s.align_items,
+            align_self: s.align_self,
             align_content: s.align_content,
             justify_content: s.justify_content,
+            justify_items: s.justify_items,
+            justify_self: s.justify_self,
+            grid_template_rows: s.grid_template_rows.iter().map(Into::into).collect(),
+            grid_template_columns: s.grid_template_columns.iter().map(Into::into).collect(),
+            grid_auto_rows: s.grid_auto_rows.iter().map(Into::into).collect(),
+            grid_auto_columns: s.grid_auto_columns.iter().map(Into::into).collect(),
+            grid_auto_flow: s.grid_auto_flow,
+            grid_row: s.grid_row.into(),
+            grid_column: s.grid_column.into(),
             ..Default::default()
         }
     }
@@ -0,0 +1,73 @@
+use std::borrow::Cow;
+use unicode_width::UnicodeWidthChar;
+
+/// The default distance between tab stops, in columns.
+pub(crate) const DEFAULT_TAB_WIDTH: usize = 8;
+
+/// Replaces tab characters with spaces, advancing to the next multiple of `tab_width`.
+///
+/// `column` is the column at which the text begins, relative to the first tab stop. It is updated
+/// to the column at which the text ends, so that text split into multiple pieces can be expanded
+/// consistently. Columns are reset at newlines. If `tab_width` is 0, tabs are removed.
+pub(crate) fn expand_tabs<'a>(text: &'a str, tab_width: usize, column: &mut usize) -> Cow<'a, str> {
+    if !text.contains('\t') {
+        *column = match text.rfind('\n') {
+            Some(idx) => text[idx + 1..]
+                .chars()
+                .map(|c| c.width().unwrap_or(0))
+                .sum(),
+            None => *column + text.chars().map(|c| c.width().unwrap_or(0)).sum::<usize>(),
+        };
+        return Cow::Borrowed(text);
+    }
+
+    let mut ret = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\t' => {
+                if tab_width > 0 {
+                    let n = tab_width - *column % tab_width;
+                    ret.extend(std::iter::repeat_n(' ', n));
+                    *column += n;
+                }
+            }
+            '\n' => {
+                ret.push(c);
+                *column = 0;
+            }
+            _ => {
+                ret.push(c);
+                *column += c.width().unwrap_or(0);
+            }
+        }
+    }
+    Cow::Owned(ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expanded(text: &str, tab_width: usize) -> String {
+        expand_tabs(text, tab_width, &mut 0).into_owned()
+    }
+
+    #[test]
+    fn test_expand_tabs() {
+        assert_eq!(expanded("foo", 8), "foo");
+        assert_eq!(expanded("\tfoo", 8), "        foo");
+        assert_eq!(expanded("a\tb\tc", 4), "a   b   c");
+        assert_eq!(expanded("abcd\te", 4), "abcd    e");
+        assert_eq!(expanded("a\tb\nab\tc", 4), "a   b\nab  c");
+        assert_eq!(expanded("一\tb", 4), "一  b");
+        assert_eq!(expanded("a\tb", 0), "ab");
+
+        let mut column = 0;
+        assert_eq!(expand_tabs("ab", 4, &mut column), "ab");
+        assert_eq!(column, 2);
+        assert_eq!(expand_tabs("c\td", 4, &mut column), "c d");
+        assert_eq!(column, 5);
+        assert_eq!(expand_tabs("e\nfg", 4, &mut column), "e\nfg");
+        assert_eq!(column, 2);
+    }
+}
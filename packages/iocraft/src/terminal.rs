@@ -1,4 +1,4 @@
-use crate::{canvas::Canvas, element::Output};
+use crate::{canvas::Canvas, color_depth::ColorDepth, element::Output};
 use crossterm::{
     cursor,
     event::{self, Event, EventStream},
@@ -85,6 +85,13 @@ pub enum TerminalEvent {
     FullscreenMouse(FullscreenMouseEvent),
     /// A resize event, fired when the terminal is resized.
     Resize(u16, u16),
+    /// A paste event, fired when text is pasted into the terminal. The entire pasted text is
+    /// delivered at once rather than as individual key events.
+    Paste(String),
+    /// A focus event, fired when the terminal window gains focus.
+    FocusGained,
+    /// A focus event, fired when the terminal window loses focus.
+    FocusLost,
 }
 
 struct TerminalEventsInner {
@@ -150,6 +157,7 @@ struct StdTerminal<'a> {
     alt: Box<dyn Write + Send + 'a>,
     fullscreen: bool,
     mouse_capture: bool,
+    color_depth: ColorDepth,
     raw_mode_enabled: bool,
     supports_keyboard_enhancement: bool,
     enabled_keyboard_enhancement: bool,
@@ -231,7 +239,7 @@ impl TerminalImpl for StdTerminal<'_> {
                 self.dest.queue(cursor::MoveTo(0, 0))?;
             }
             self.prev_canvas_height = canvas.height() as _;
-            canvas.write_ansi_without_final_newline(&mut *self.dest)?;
+            canvas.write_ansi_without_final_newline(&mut *self.dest, self.color_depth)?;
             return Ok(());
         };
 
@@ -245,7 +253,7 @@ impl TerminalImpl for StdTerminal<'_> {
                 self.clear_canvas()?;
                 self.prev_canvas_height = canvas.height() as _;
                 self.prev_size_on_write = self.size;
-                canvas.write_ansi_without_final_newline(&mut *self.dest)?;
+                canvas.write_ansi_without_final_newline(&mut *self.dest, self.color_depth)?;
                 return Ok(());
             }
 
@@ -258,7 +266,7 @@ impl TerminalImpl for StdTerminal<'_> {
                 }
                 self.dest.queue(cursor::MoveTo(0, top_row + y as u16))?;
                 if y < canvas.height() {
-                    canvas.write_ansi_row_without_newline(y, &mut *self.dest)?;
+                    canvas.write_ansi_row_without_newline(y, &mut *self.dest, self.color_depth)?;
                 } else {
                     self.dest
                         .queue(terminal::Clear(terminal::ClearType::CurrentLine))?;
@@ -291,7 +299,7 @@ impl TerminalImpl for StdTerminal<'_> {
                 if y < visible_start {
                     self.clear_canvas()?;
                     self.prev_canvas_height = canvas.height() as _;
-                    canvas.write_ansi_without_final_newline(&mut *self.dest)?;
+                    canvas.write_ansi_without_final_newline(&mut *self.dest, self.color_depth)?;
                     return Ok(());
                 }
             }
@@ -329,7 +337,7 @@ impl TerminalImpl for StdTerminal<'_> {
             current_y = y;
 
             if y < new_height {
-                canvas.write_ansi_row_without_newline(y, &mut *self.dest)?;
+                canvas.write_ansi_row_without_newline(y, &mut *self.dest, self.color_depth)?;
             } else {
                 self.dest
                     .queue(terminal::Clear(terminal::ClearType::CurrentLine))?;
@@ -380,8 +388,9 @@ impl TerminalImpl for StdTerminal<'_> {
                     Ok(Event::Resize(width, height)) => {
                         Some(Ok(TerminalEvent::Resize(width, height)))
                     }
-                    // Ignore crossterm events that iocraft does not expose.
-                    Ok(_) => None,
+                    Ok(Event::Paste(text)) => Some(Ok(TerminalEvent::Paste(text))),
+                    Ok(Event::FocusGained) => Some(Ok(TerminalEvent::FocusGained)),
+                    Ok(Event::FocusLost) => Some(Ok(TerminalEvent::FocusLost)),
                     Err(error) => Some(Err(error)),
                 }
             })
@@ -403,6 +412,7 @@ impl<'a> StdTerminal<'a> {
         alt: Box<dyn Write + Send + 'a>,
         fullscreen: bool,
         mouse_capture: bool,
+        color_depth: ColorDepth,
     ) -> io::Result<Self> {
         let input_is_terminal = stdin().is_terminal();
         // The probe blocks on a query response, and some terminals (e.g. WezTerm)
@@ -416,6 +426,7 @@ impl<'a> StdTerminal<'a> {
             input_is_terminal,
             fullscreen,
             mouse_capture,
+            color_depth,
             raw_mode_enabled: false,
             supports_keyboard_enhancement,
             enabled_keyboard_enhancement: false,
@@ -443,9 +454,13 @@ impl<'a> StdTerminal<'a> {
                 if self.mouse_capture {
                     self.dest.execute(event::EnableMouseCapture)?;
                 }
+                self.dest.execute(event::EnableBracketedPaste)?;
+                self.dest.execute(event::EnableFocusChange)?;
                 terminal::enable_raw_mode()?;
             } else {
                 terminal::disable_raw_mode()?;
+                self.dest.execute(event::DisableFocusChange)?;
+                self.dest.execute(event::DisableBracketedPaste)?;
                 if self.mouse_capture {
                     self.dest.execute(event::DisableMouseCapture)?;
                 }
@@ -578,6 +593,7 @@ pub(crate) struct Terminal<'a> {
     subscribers: Vec<Weak<Mutex<TerminalEventsInner>>>,
     received_ctrl_c: bool,
     ignore_ctrl_c: bool,
+    has_focus: bool,
 }
 
 impl<'a> Terminal<'a> {
@@ -587,6 +603,7 @@ impl<'a> Terminal<'a> {
         output: Output,
         fullscreen: bool,
         mouse_capture: bool,
+        color_depth: ColorDepth,
     ) -> io::Result<Self> {
         // dest is the render destination, alt is the other stream
         let (dest, alt) = match output {
@@ -594,12 +611,19 @@ impl<'a> Terminal<'a> {
             Output::Stderr => (stderr, stdout),
         };
         Ok(Self {
-            inner: Box::new(StdTerminal::new(dest, alt, fullscreen, mouse_capture)?),
+            inner: Box::new(StdTerminal::new(
+                dest,
+                alt,
+                fullscreen,
+                mouse_capture,
+                color_depth,
+            )?),
             output,
             event_stream: None,
             subscribers: Vec::new(),
             received_ctrl_c: false,
             ignore_ctrl_c: false,
+            has_focus: true,
         })
     }
 
@@ -639,6 +663,12 @@ impl<'a> Terminal<'a> {
         self.received_ctrl_c
     }
 
+    /// Returns whether the terminal window has focus, based on the focus events received so far.
+    /// Terminals which don't report focus are assumed to be focused.
+    pub fn has_focus(&self) -> bool {
+        self.has_focus
+    }
+
     /// Returns a mutable reference to the stdout handle.
     pub fn stdout(&mut self) -> &mut dyn Write {
         match self.output {
@@ -688,6 +718,11 @@ impl<'a> Terminal<'a> {
                             return Ok(());
                         }
                     }
+                    match event {
+                        TerminalEvent::FocusGained => self.has_focus = true,
+                        TerminalEvent::FocusLost => self.has_focus = false,
+                        _ => {}
+                    }
                     self.subscribers.retain(|subscriber| {
                         if let Some(subscriber) = subscriber.upgrade() {
                             let mut subscriber = subscriber.lock().unwrap();
@@ -734,6 +769,7 @@ impl Terminal<'static> {
                 subscribers: Vec::new(),
                 received_ctrl_c: false,
                 ignore_ctrl_c: false,
+                has_focus: true,
             },
             output_stream,
         )
@@ -815,6 +851,7 @@ mod tests {
             Output::Stdout,
             false,
             true,
+            ColorDepth::TrueColor,
         )
         .unwrap();
         assert!(!terminal.is_raw_mode_enabled());
@@ -834,7 +871,9 @@ mod tests {
             if i > 0 {
                 super::clear_canvas_inline(&mut buf, canvases[i - 1].height() as _).unwrap();
             }
-            canvas.write_ansi_without_final_newline(&mut buf).unwrap();
+            canvas
+                .write_ansi_without_final_newline(&mut buf, ColorDepth::TrueColor)
+                .unwrap();
         }
         let mut vt = avt::Vt::new(cols, rows);
         vt.feed_str(&String::from_utf8(buf).unwrap());
@@ -959,8 +998,9 @@ mod tests {
 
         let mut setup = Vec::new();
         write!(setup, "log\r\n").unwrap();
-        prev.write_ansi_without_final_newline(&mut setup).unwrap();
-        setup.extend_from_slice(&*diff_buf.lock().unwrap());
+        prev.write_ansi_without_final_newline(&mut setup, ColorDepth::TrueColor)
+            .unwrap();
+        setup.extend_from_slice(&diff_buf.lock().unwrap());
 
         let mut vt = avt::Vt::new(10, 5);
         vt.feed_str(&String::from_utf8(setup).unwrap());
@@ -991,10 +1031,12 @@ mod tests {
 
         let mut setup = Vec::new();
         write!(setup, "log\r\n").unwrap();
-        canvas.write_ansi_without_final_newline(&mut setup).unwrap();
+        canvas
+            .write_ansi_without_final_newline(&mut setup, ColorDepth::TrueColor)
+            .unwrap();
         write!(setup, "\r\ntail").unwrap();
         setup.queue(cursor::MoveTo(0, 0)).unwrap();
-        setup.extend_from_slice(&*clear_buf.lock().unwrap());
+        setup.extend_from_slice(&clear_buf.lock().unwrap());
 
         let mut vt = avt::Vt::new(10, 5);
         vt.feed_str(&String::from_utf8(setup).unwrap());
@@ -1016,6 +1058,7 @@ mod tests {
             alt: Box::new(io::sink()),
             fullscreen: true,
             mouse_capture: false,
+            color_depth: ColorDepth::TrueColor,
             raw_mode_enabled: false,
             supports_keyboard_enhancement: false,
             enabled_keyboard_enhancement: false,
@@ -1041,6 +1084,7 @@ mod tests {
             alt: Box::new(io::sink()),
             fullscreen: false,
             mouse_capture: false,
+            color_depth: ColorDepth::TrueColor,
             raw_mode_enabled: false,
             supports_keyboard_enhancement: false,
             enabled_keyboard_enhancement: false,
@@ -1060,7 +1104,8 @@ mod tests {
 
         let diff = diff_buf.lock().unwrap().clone();
         let mut setup = Vec::new();
-        prev.write_ansi_without_final_newline(&mut setup).unwrap();
+        prev.write_ansi_without_final_newline(&mut setup, ColorDepth::TrueColor)
+            .unwrap();
         setup.extend_from_slice(&diff);
 
         let mut vt = avt::Vt::new(term_size.0 as _, term_size.1 as _);
@@ -1088,8 +1133,9 @@ mod tests {
 
         // Build vt: render prev, then apply diff output.
         let mut setup = Vec::new();
-        prev.write_ansi_without_final_newline(&mut setup).unwrap();
-        setup.extend_from_slice(&*diff_buf.lock().unwrap());
+        prev.write_ansi_without_final_newline(&mut setup, ColorDepth::TrueColor)
+            .unwrap();
+        setup.extend_from_slice(&diff_buf.lock().unwrap());
 
         let mut vt = avt::Vt::new(10, 5);
         vt.feed_str(&String::from_utf8(setup).unwrap());
@@ -1120,8 +1166,9 @@ mod tests {
         term.write_canvas(Some(&prev), &next).unwrap();
 
         let mut setup = Vec::new();
-        prev.write_ansi_without_final_newline(&mut setup).unwrap();
-        setup.extend_from_slice(&*diff_buf.lock().unwrap());
+        prev.write_ansi_without_final_newline(&mut setup, ColorDepth::TrueColor)
+            .unwrap();
+        setup.extend_from_slice(&diff_buf.lock().unwrap());
 
         let mut vt = avt::Vt::new(10, 5);
         vt.feed_str(&String::from_utf8(setup).unwrap());
@@ -1157,8 +1204,9 @@ mod tests {
         term.write_canvas(Some(&prev), &next).unwrap();
 
         let mut setup = Vec::new();
-        prev.write_ansi_without_final_newline(&mut setup).unwrap();
-        setup.extend_from_slice(&*diff_buf.lock().unwrap());
+        prev.write_ansi_without_final_newline(&mut setup, ColorDepth::TrueColor)
+            .unwrap();
+        setup.extend_from_slice(&diff_buf.lock().unwrap());
 
         let mut vt = avt::Vt::new(10, 5);
         vt.feed_str(&String::from_utf8(setup).unwrap());
@@ -1231,8 +1279,9 @@ mod tests {
         for i in 0..vt_rows - 1 {
             write!(setup, "line{i}\r\n").unwrap();
         }
-        prev.write_ansi_without_final_newline(&mut setup).unwrap();
-        setup.extend_from_slice(&*diff_buf.lock().unwrap());
+        prev.write_ansi_without_final_newline(&mut setup, ColorDepth::TrueColor)
+            .unwrap();
+        setup.extend_from_slice(&diff_buf.lock().unwrap());
 
         let mut vt = avt::Vt::new(10, vt_rows);
         vt.feed_str(&String::from_utf8(setup).unwrap());
@@ -1317,8 +1366,9 @@ mod tests {
         term.write_canvas(Some(&prev), &next).unwrap();
 
         let mut setup = Vec::new();
-        prev.write_ansi_without_final_newline(&mut setup).unwrap();
-        setup.extend_from_slice(&*diff_buf.lock().unwrap());
+        prev.write_ansi_without_final_newline(&mut setup, ColorDepth::TrueColor)
+            .unwrap();
+        setup.extend_from_slice(&diff_buf.lock().unwrap());
 
         let mut vt = avt::Vt::new(10, 5);
         vt.feed_str(&String::from_utf8(setup).unwrap());
@@ -1339,7 +1389,7 @@ mod tests {
     #[test]
     fn test_fullscreen_diff_styled_text_preserved() {
         let underline_style = CanvasTextStyle {
-            underline: true,
+            underline_style: Some(UnderlineStyle::Single),
             color: Some(Color::Green),
             ..Default::default()
         };
@@ -1361,8 +1411,9 @@ mod tests {
         term.write_canvas(Some(&prev), &next).unwrap();
 
         let mut setup = Vec::new();
-        prev.write_ansi_without_final_newline(&mut setup).unwrap();
-        setup.extend_from_slice(&*diff_buf.lock().unwrap());
+        prev.write_ansi_without_final_newline(&mut setup, ColorDepth::TrueColor)
+            .unwrap();
+        setup.extend_from_slice(&diff_buf.lock().unwrap());
 
         let mut vt = avt::Vt::new(10, 5);
         vt.feed_str(&String::from_utf8(setup).unwrap());
@@ -1506,8 +1557,9 @@ mod tests {
         term.write_canvas(Some(&c2), &c3).unwrap();
 
         let mut setup = Vec::new();
-        c1.write_ansi_without_final_newline(&mut setup).unwrap();
-        setup.extend_from_slice(&*buf.lock().unwrap());
+        c1.write_ansi_without_final_newline(&mut setup, ColorDepth::TrueColor)
+            .unwrap();
+        setup.extend_from_slice(&buf.lock().unwrap());
 
         let mut vt = avt::Vt::new(10, 6);
         vt.feed_str(&String::from_utf8(setup).unwrap());
@@ -1549,8 +1601,9 @@ mod tests {
         term.write_canvas(Some(&c2), &c3).unwrap();
 
         let mut setup = Vec::new();
-        c1.write_ansi_without_final_newline(&mut setup).unwrap();
-        setup.extend_from_slice(&*buf.lock().unwrap());
+        c1.write_ansi_without_final_newline(&mut setup, ColorDepth::TrueColor)
+            .unwrap();
+        setup.extend_from_slice(&buf.lock().unwrap());
 
         let mut vt = avt::Vt::new(10, 6);
         vt.feed_str(&String::from_utf8(setup).unwrap());
@@ -1573,6 +1626,7 @@ mod tests {
                 Output::Stdout,
                 false,
                 true,
+                ColorDepth::TrueColor,
             )
             .unwrap();
             let canvas = Canvas::new(10, 1);
@@ -1626,8 +1680,9 @@ mod tests {
         // Replay: write prev canvas as the baseline already on screen,
         // then apply the diff on top.
         let mut setup = Vec::new();
-        prev.write_ansi_without_final_newline(&mut setup).unwrap();
-        setup.extend_from_slice(&*buf.lock().unwrap());
+        prev.write_ansi_without_final_newline(&mut setup, ColorDepth::TrueColor)
+            .unwrap();
+        setup.extend_from_slice(&buf.lock().unwrap());
 
         let mut vt = avt::Vt::new(width, height + 2);
         vt.feed_str(&String::from_utf8(setup).unwrap());
@@ -1663,8 +1718,9 @@ mod tests {
         term.write_canvas(Some(&prev), &next).unwrap();
 
         let mut setup = Vec::new();
-        prev.write_ansi_without_final_newline(&mut setup).unwrap();
-        setup.extend_from_slice(&*buf.lock().unwrap());
+        prev.write_ansi_without_final_newline(&mut setup, ColorDepth::TrueColor)
+            .unwrap();
+        setup.extend_from_slice(&buf.lock().unwrap());
 
         let mut vt = avt::Vt::new(width, height + 4);
         vt.feed_str(&String::from_utf8(setup).unwrap());